@@ -10,6 +10,8 @@ CREATE TABLE IF NOT EXISTS sflow.from_kafka
     agentSubId UInt32,
     datagramSequenceNumber UInt32,
     uptime UInt32,
+    listenerTag String,
+    peerAddr FixedString(16),
     sampleSequenceNumber UInt32,
     sourceId UInt32,
     samplingRate UInt32,
@@ -24,7 +26,46 @@ CREATE TABLE IF NOT EXISTS sflow.from_kafka
     srcPort UInt32,
     dstPort UInt32,
     tcpFlags UInt32,
-    tos UInt32
+    tos UInt32,
+    srcMac UInt64,
+    dstMac UInt64,
+    etype UInt32,
+    vlanId UInt32,
+    innerVlanId UInt32,
+    srcVlan UInt32,
+    srcPriority UInt32,
+    dstVlan UInt32,
+    dstPriority UInt32,
+    nextHop FixedString(16),
+    srcMaskLen UInt32,
+    dstMaskLen UInt32,
+    bgpNextHop FixedString(16),
+    srcAs UInt32,
+    dstAs UInt32,
+    srcPeerAs UInt32,
+    dstPeerAs UInt32,
+    asPath Array(UInt32),
+    communities Array(UInt32),
+    localPref UInt32,
+    srcCountry String,
+    dstCountry String,
+    srcCity String,
+    dstCity String,
+    srcAsn UInt32,
+    dstAsn UInt32,
+    srcAsOrg String,
+    dstAsOrg String,
+    agentHostname String,
+    agentSite String,
+    agentRole String,
+    inputInterfaceName String,
+    inputInterfaceDescription String,
+    inputInterfaceSpeed UInt64,
+    inputInterfaceBoundary UInt8,
+    outputInterfaceName String,
+    outputInterfaceDescription String,
+    outputInterfaceSpeed UInt64,
+    outputInterfaceBoundary UInt8
 )
 ENGINE = Kafka()
 SETTINGS
@@ -45,13 +86,49 @@ CREATE TABLE IF NOT EXISTS sflow.flows
     sequence_num UInt32,
     sampling_rate UInt64,
     sampler_address IPv6,
+    listener_tag LowCardinality(String),
     sampler_port UInt16,
+    peer_address IPv6,
+    src_mac UInt64,
+    dst_mac UInt64,
+    vlan_id UInt32,
+    inner_vlan_id UInt32,
     src_addr IPv6,
     dst_addr IPv6,
     src_port UInt32,
     dst_port UInt32,
     protocol UInt32,
     etype UInt32,
+    src_vlan UInt32,
+    dst_vlan UInt32,
+    next_hop IPv6,
+    src_net_mask UInt32,
+    dst_net_mask UInt32,
+    bgp_next_hop IPv6,
+    src_as UInt32,
+    dst_as UInt32,
+    as_path Array(UInt32),
+    bgp_communities Array(UInt32),
+    local_pref UInt32,
+    src_country LowCardinality(String),
+    dst_country LowCardinality(String),
+    src_city LowCardinality(String),
+    dst_city LowCardinality(String),
+    src_asn UInt32,
+    dst_asn UInt32,
+    src_as_org LowCardinality(String),
+    dst_as_org LowCardinality(String),
+    sampler_hostname LowCardinality(String),
+    sampler_site LowCardinality(String),
+    sampler_role LowCardinality(String),
+    in_if_name LowCardinality(String),
+    in_if_description LowCardinality(String),
+    in_if_speed UInt64,
+    in_if_boundary Enum8('undefined' = 0, 'internal' = 1, 'external' = 2),
+    out_if_name LowCardinality(String),
+    out_if_description LowCardinality(String),
+    out_if_speed UInt64,
+    out_if_boundary Enum8('undefined' = 0, 'internal' = 1, 'external' = 2),
     packet_length UInt32,
     bytes UInt64,
     packets UInt64
@@ -78,7 +155,15 @@ AS SELECT
     datagramSequenceNumber AS sequence_num,
     toUInt64(samplingRate) AS sampling_rate,
     toIPv6(agentAddr) AS sampler_address,
+    listenerTag AS listener_tag,
     agentPort AS sampler_port,
+    toIPv6(peerAddr) AS peer_address,
+
+    -- Extract link layer
+    srcMac AS src_mac,
+    dstMac AS dst_mac,
+    vlanId AS vlan_id,
+    innerVlanId AS inner_vlan_id,
 
     -- Extract IPs (already IPv6 format)
     toIPv6(srcIp) AS src_addr,
@@ -88,7 +173,43 @@ AS SELECT
     srcPort AS src_port,
     dstPort AS dst_port,
     protocol AS protocol,
-    0 AS etype,
+    etype AS etype,
+
+    -- Extract extended switch, router and gateway data
+    srcVlan AS src_vlan,
+    dstVlan AS dst_vlan,
+    toIPv6(nextHop) AS next_hop,
+    srcMaskLen AS src_net_mask,
+    dstMaskLen AS dst_net_mask,
+    toIPv6(bgpNextHop) AS bgp_next_hop,
+    srcAs AS src_as,
+    dstAs AS dst_as,
+    asPath AS as_path,
+    communities AS bgp_communities,
+    localPref AS local_pref,
+
+    -- GeoIP enrichment
+    srcCountry AS src_country,
+    dstCountry AS dst_country,
+    srcCity AS src_city,
+    dstCity AS dst_city,
+    srcAsn AS src_asn,
+    dstAsn AS dst_asn,
+    srcAsOrg AS src_as_org,
+    dstAsOrg AS dst_as_org,
+
+    -- Agent and interface metadata
+    agentHostname AS sampler_hostname,
+    agentSite AS sampler_site,
+    agentRole AS sampler_role,
+    inputInterfaceName AS in_if_name,
+    inputInterfaceDescription AS in_if_description,
+    inputInterfaceSpeed AS in_if_speed,
+    inputInterfaceBoundary AS in_if_boundary,
+    outputInterfaceName AS out_if_name,
+    outputInterfaceDescription AS out_if_description,
+    outputInterfaceSpeed AS out_if_speed,
+    outputInterfaceBoundary AS out_if_boundary,
 
     -- Raw packet data
     length AS packet_length,
@@ -96,3 +217,289 @@ AS SELECT
     1 AS packets
 FROM sflow.from_kafka
 WHERE samplingRate > 0;
+
+-- Create Kafka consumer table for flat sFlow counter records
+CREATE TABLE IF NOT EXISTS sflow.counters_from_kafka
+(
+    timeReceivedNs UInt64,
+    agentAddr FixedString(16),
+    agentPort UInt16,
+    agentSubId UInt32,
+    datagramSequenceNumber UInt32,
+    uptime UInt32,
+    listenerTag String,
+    sampleSequenceNumber UInt32,
+    sourceId UInt32,
+    ifIndex UInt32,
+    ifType UInt32,
+    ifSpeed UInt64,
+    ifDirection UInt32,
+    ifStatus UInt32,
+    ifInOctets UInt64,
+    ifInUcastPkts UInt32,
+    ifInMulticastPkts UInt32,
+    ifInBroadcastPkts UInt32,
+    ifInDiscards UInt32,
+    ifInErrors UInt32,
+    ifInUnknownProtos UInt32,
+    ifOutOctets UInt64,
+    ifOutUcastPkts UInt32,
+    ifOutMulticastPkts UInt32,
+    ifOutBroadcastPkts UInt32,
+    ifOutDiscards UInt32,
+    ifOutErrors UInt32,
+    ifPromiscuousMode UInt32,
+    dot3StatsAlignmentErrors UInt32,
+    dot3StatsFcsErrors UInt32,
+    dot3StatsSingleCollisionFrames UInt32,
+    dot3StatsMultipleCollisionFrames UInt32,
+    dot3StatsSqeTestErrors UInt32,
+    dot3StatsDeferredTransmissions UInt32,
+    dot3StatsLateCollisions UInt32,
+    dot3StatsExcessiveCollisions UInt32,
+    dot3StatsInternalMacTransmitErrors UInt32,
+    dot3StatsCarrierSenseErrors UInt32,
+    dot3StatsFrameTooLongs UInt32,
+    dot3StatsInternalMacReceiveErrors UInt32,
+    dot3StatsSymbolErrors UInt32
+)
+ENGINE = Kafka()
+SETTINGS
+    kafka_broker_list = '10.0.0.100:9092',
+    kafka_topic_list = 'pesto-sflow-counters',
+    kafka_group_name = 'clickhouse-pesto-counters-group',
+    kafka_format = 'CapnProto',
+    kafka_schema = 'sflow:SFlowCounterRecord',
+    kafka_num_consumers = 1,
+    kafka_max_block_size = 1048576;
+
+-- Create storage table for sFlow interface counters
+CREATE TABLE IF NOT EXISTS sflow.counters
+(
+    date Date,
+    time_inserted_ns DateTime64(9),
+    time_received_ns DateTime64(9),
+    sequence_num UInt32,
+    sampler_address IPv6,
+    listener_tag LowCardinality(String),
+    if_index UInt32,
+    if_type UInt32,
+    if_speed UInt64,
+    if_status UInt32,
+    if_in_octets UInt64,
+    if_in_ucast_pkts UInt32,
+    if_in_discards UInt32,
+    if_in_errors UInt32,
+    if_out_octets UInt64,
+    if_out_ucast_pkts UInt32,
+    if_out_discards UInt32,
+    if_out_errors UInt32
+)
+ENGINE = MergeTree()
+PARTITION BY date
+ORDER BY (time_received_ns, sampler_address, if_index)
+TTL date + INTERVAL 7 DAY DELETE;
+
+-- Create materialized view to store interface counters
+CREATE MATERIALIZED VIEW IF NOT EXISTS sflow.counters_from_kafka_mv TO sflow.counters
+AS SELECT
+    toDate(timeReceivedNs / 1000000000) AS date,
+    now() AS time_inserted_ns,
+    toDateTime64(timeReceivedNs / 1000000000, 9) AS time_received_ns,
+    datagramSequenceNumber AS sequence_num,
+    toIPv6(agentAddr) AS sampler_address,
+    listenerTag AS listener_tag,
+    ifIndex AS if_index,
+    ifType AS if_type,
+    ifSpeed AS if_speed,
+    ifStatus AS if_status,
+    ifInOctets AS if_in_octets,
+    ifInUcastPkts AS if_in_ucast_pkts,
+    ifInDiscards AS if_in_discards,
+    ifInErrors AS if_in_errors,
+    ifOutOctets AS if_out_octets,
+    ifOutUcastPkts AS if_out_ucast_pkts,
+    ifOutDiscards AS if_out_discards,
+    ifOutErrors AS if_out_errors
+FROM sflow.counters_from_kafka;
+
+-- Create Kafka consumer table for flat sFlow discarded packet records
+CREATE TABLE IF NOT EXISTS sflow.drops_from_kafka
+(
+    timeReceivedNs UInt64,
+    agentAddr FixedString(16),
+    agentPort UInt16,
+    agentSubId UInt32,
+    datagramSequenceNumber UInt32,
+    uptime UInt32,
+    listenerTag String,
+    sampleSequenceNumber UInt32,
+    sourceId UInt32,
+    drops UInt32,
+    inputInterface UInt32,
+    outputInterface UInt32,
+    reason UInt32,
+    length UInt32,
+    protocol UInt32,
+    srcIp FixedString(16),
+    dstIp FixedString(16),
+    srcPort UInt32,
+    dstPort UInt32,
+    tcpFlags UInt32,
+    tos UInt32
+)
+ENGINE = Kafka()
+SETTINGS
+    kafka_broker_list = '10.0.0.100:9092',
+    kafka_topic_list = 'pesto-sflow-drops',
+    kafka_group_name = 'clickhouse-pesto-drops-group',
+    kafka_format = 'CapnProto',
+    kafka_schema = 'sflow:SFlowDropRecord',
+    kafka_num_consumers = 1,
+    kafka_max_block_size = 1048576;
+
+-- Create storage table for sFlow discarded packets
+CREATE TABLE IF NOT EXISTS sflow.drops
+(
+    date Date,
+    time_inserted_ns DateTime64(9),
+    time_received_ns DateTime64(9),
+    sequence_num UInt32,
+    sampler_address IPv6,
+    listener_tag LowCardinality(String),
+    in_if UInt32,
+    out_if UInt32,
+    reason UInt32,
+    src_addr IPv6,
+    dst_addr IPv6,
+    src_port UInt32,
+    dst_port UInt32,
+    protocol UInt32,
+    packet_length UInt32
+)
+ENGINE = MergeTree()
+PARTITION BY date
+ORDER BY (time_received_ns, sampler_address, reason)
+TTL date + INTERVAL 7 DAY DELETE;
+
+-- Create materialized view to store discarded packets
+CREATE MATERIALIZED VIEW IF NOT EXISTS sflow.drops_from_kafka_mv TO sflow.drops
+AS SELECT
+    toDate(timeReceivedNs / 1000000000) AS date,
+    now() AS time_inserted_ns,
+    toDateTime64(timeReceivedNs / 1000000000, 9) AS time_received_ns,
+    datagramSequenceNumber AS sequence_num,
+    toIPv6(agentAddr) AS sampler_address,
+    listenerTag AS listener_tag,
+    inputInterface AS in_if,
+    outputInterface AS out_if,
+    reason AS reason,
+    toIPv6(srcIp) AS src_addr,
+    toIPv6(dstIp) AS dst_addr,
+    srcPort AS src_port,
+    dstPort AS dst_port,
+    protocol AS protocol,
+    length AS packet_length
+FROM sflow.drops_from_kafka;
+
+-- Create Kafka engine table for NetFlow and IPFIX records
+CREATE TABLE IF NOT EXISTS sflow.netflows_from_kafka
+(
+    timeReceivedNs UInt64,
+    exporterAddr FixedString(16),
+    exporterPort UInt16,
+    version UInt16,
+    sequenceNumber UInt32,
+    observationDomainId UInt32,
+    exportTime UInt32,
+    flowStartMs UInt64,
+    flowEndMs UInt64,
+    bytes UInt64,
+    packets UInt64,
+    samplingRate UInt32,
+    srcIp FixedString(16),
+    dstIp FixedString(16),
+    srcPort UInt32,
+    dstPort UInt32,
+    protocol UInt32,
+    tos UInt32,
+    tcpFlags UInt32,
+    inputInterface UInt32,
+    outputInterface UInt32,
+    srcMac UInt64,
+    dstMac UInt64,
+    etype UInt32,
+    vlanId UInt32,
+    nextHop FixedString(16),
+    srcMaskLen UInt32,
+    dstMaskLen UInt32,
+    srcAs UInt32,
+    dstAs UInt32
+)
+ENGINE = Kafka()
+SETTINGS
+    kafka_broker_list = '10.0.0.100:9092',
+    kafka_topic_list = 'pesto-netflow',
+    kafka_group_name = 'clickhouse-pesto-netflow-group',
+    kafka_format = 'CapnProto',
+    kafka_schema = 'sflow:NetFlowRecord',
+    kafka_num_consumers = 1,
+    kafka_max_block_size = 1048576;
+
+-- Create storage table for NetFlow and IPFIX flows
+CREATE TABLE IF NOT EXISTS sflow.netflows
+(
+    date Date,
+    time_inserted_ns DateTime64(9),
+    time_received_ns DateTime64(9),
+    time_flow_start_ms DateTime64(3),
+    time_flow_end_ms DateTime64(3),
+    version UInt16,
+    sequence_num UInt32,
+    exporter_address IPv6,
+    observation_domain_id UInt32,
+    sampling_rate UInt32,
+    in_if UInt32,
+    out_if UInt32,
+    src_addr IPv6,
+    dst_addr IPv6,
+    src_port UInt32,
+    dst_port UInt32,
+    protocol UInt32,
+    tcp_flags UInt32,
+    src_as UInt32,
+    dst_as UInt32,
+    bytes UInt64,
+    packets UInt64
+)
+ENGINE = MergeTree()
+PARTITION BY date
+ORDER BY (time_received_ns, exporter_address)
+TTL date + INTERVAL 7 DAY DELETE;
+
+-- Create materialized view to store NetFlow and IPFIX flows
+CREATE MATERIALIZED VIEW IF NOT EXISTS sflow.netflows_from_kafka_mv TO sflow.netflows
+AS SELECT
+    toDate(timeReceivedNs / 1000000000) AS date,
+    now() AS time_inserted_ns,
+    toDateTime64(timeReceivedNs / 1000000000, 9) AS time_received_ns,
+    toDateTime64(flowStartMs / 1000, 3) AS time_flow_start_ms,
+    toDateTime64(flowEndMs / 1000, 3) AS time_flow_end_ms,
+    version AS version,
+    sequenceNumber AS sequence_num,
+    toIPv6(exporterAddr) AS exporter_address,
+    observationDomainId AS observation_domain_id,
+    samplingRate AS sampling_rate,
+    inputInterface AS in_if,
+    outputInterface AS out_if,
+    toIPv6(srcIp) AS src_addr,
+    toIPv6(dstIp) AS dst_addr,
+    srcPort AS src_port,
+    dstPort AS dst_port,
+    protocol AS protocol,
+    tcpFlags AS tcp_flags,
+    srcAs AS src_as,
+    dstAs AS dst_as,
+    bytes AS bytes,
+    packets AS packets
+FROM sflow.netflows_from_kafka;
@@ -0,0 +1,161 @@
+#!/usr/bin/env python3
+"""Generate the GeoIP test databases (MaxMind DB format) used by the integration tests.
+
+The networks cover the addresses of tests/sflow.bin (3.3.3.3 and 4.4.4.4) and
+the IPv6 documentation prefix. The countries, cities and AS are made up.
+
+Usage: ./generate.py (writes city.mmdb and asn.mmdb next to this script)
+"""
+import ipaddress
+import os
+import struct
+
+BUILD_EPOCH = 1767225600  # 2026-01-01
+
+CITIES = {
+    "3.3.3.0/24": ("US", "United States", "Seattle"),
+    "4.4.4.0/24": ("FR", "France", "Paris"),
+    "2001:db8::/32": ("DE", "Germany", "Berlin"),
+}
+
+ASNS = {
+    "3.3.3.0/24": (64496, "Example Source Network"),
+    "4.4.4.0/24": (64497, "Example Destination Network"),
+    "2001:db8::/32": (64498, "Example IPv6 Network"),
+}
+
+
+def control(type_id, size):
+    """Control byte(s) of a data field, with the extended type and size bytes."""
+    if size < 29:
+        head, extra = size, b""
+    elif size < 285:
+        head, extra = 29, bytes([size - 29])
+    elif size < 65821:
+        head, extra = 30, struct.pack(">H", size - 285)
+    else:
+        head, extra = 31, struct.pack(">I", size - 65821)[1:]
+    if type_id <= 7:
+        return bytes([(type_id << 5) | head]) + extra
+    return bytes([head, type_id - 7]) + extra
+
+
+def encode(value):
+    if isinstance(value, bool):
+        return control(14, int(value))
+    if isinstance(value, str):
+        data = value.encode()
+        return control(2, len(data)) + data
+    if isinstance(value, tuple):
+        # (type, int) for the unsigned integers
+        type_id, number = value
+        width = {5: 2, 6: 4, 9: 8}[type_id]
+        data = number.to_bytes(width, "big").lstrip(b"\0")
+        return control(type_id, len(data)) + data
+    if isinstance(value, list):
+        return control(11, len(value)) + b"".join(encode(item) for item in value)
+    if isinstance(value, dict):
+        return control(7, len(value)) + b"".join(
+            encode(key) + encode(item) for key, item in value.items()
+        )
+    raise TypeError(value)
+
+
+def uint16(number):
+    return (5, number)
+
+
+def uint32(number):
+    return (6, number)
+
+
+def uint64(number):
+    return (9, number)
+
+
+def write_database(path, database_type, records):
+    # Binary trie over the 128 bits of the IPv6 addresses, IPv4 networks in ::/96
+    root = [None, None]
+    data = b""
+    for network, record in records.items():
+        network = ipaddress.ip_network(network)
+        bits = int(network.network_address)
+        prefix_len = network.prefixlen
+        if network.version == 4:
+            prefix_len += 96
+        offset = len(data)
+        data += encode(record)
+
+        node = root
+        for depth in range(prefix_len - 1):
+            bit = (bits >> (127 - depth)) & 1
+            if node[bit] is None:
+                node[bit] = [None, None]
+            node = node[bit]
+        node[(bits >> (128 - prefix_len)) & 1] = ("data", offset)
+
+    # Number the nodes breadth first, the root is node 0
+    nodes = [root]
+    index = 0
+    while index < len(nodes):
+        for child in nodes[index]:
+            if isinstance(child, list):
+                nodes.append(child)
+        index += 1
+    numbers = {id(node): number for number, node in enumerate(nodes)}
+    node_count = len(nodes)
+
+    def record_value(child):
+        if child is None:
+            return node_count
+        if isinstance(child, tuple):
+            return node_count + 16 + child[1]
+        return numbers[id(child)]
+
+    tree = b"".join(
+        record_value(left).to_bytes(3, "big") + record_value(right).to_bytes(3, "big")
+        for left, right in nodes
+    )
+    metadata = {
+        "binary_format_major_version": uint16(2),
+        "binary_format_minor_version": uint16(0),
+        "build_epoch": uint64(BUILD_EPOCH),
+        "database_type": database_type,
+        "description": {"en": "Pesto integration test database"},
+        "ip_version": uint16(6),
+        "languages": ["en"],
+        "node_count": uint32(node_count),
+        "record_size": uint16(24),
+    }
+    with open(path, "wb") as f:
+        f.write(tree + b"\0" * 16 + data + b"\xab\xcd\xefMaxMind.com" + encode(metadata))
+
+
+def main():
+    directory = os.path.dirname(os.path.abspath(__file__))
+    write_database(
+        os.path.join(directory, "city.mmdb"),
+        "GeoIP2-City",
+        {
+            network: {
+                "city": {"names": {"en": city}},
+                "country": {"iso_code": iso_code, "names": {"en": country}},
+            }
+            for network, (iso_code, country, city) in CITIES.items()
+        },
+    )
+    write_database(
+        os.path.join(directory, "asn.mmdb"),
+        "GeoLite2-ASN",
+        {
+            network: {
+                "autonomous_system_number": uint32(asn),
+                "autonomous_system_organization": organization,
+            }
+            for network, (asn, organization) in ASNS.items()
+        },
+    )
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,172 @@
+#!/bin/bash
+# Check the precedence of the configuration layers:
+# command line flags > PESTO_* environment variables > configuration file > defaults
+#
+# Usage: ./tests/config.sh [path/to/pesto]
+set -u
+
+PESTO="${1:-../target/debug/pesto}"
+WORK_DIR="$(mktemp -d)"
+trap 'rm -rf "$WORK_DIR"' EXIT
+
+echo "s3cr3t-from-file" > "$WORK_DIR/sasl-password"
+
+cat > "$WORK_DIR/pesto.toml" <<TOML
+metrics_address = "127.0.0.1:0"
+
+[sflow]
+address = "127.0.0.1:0"
+
+[kafka]
+disable = true
+topic = "topic-from-file"
+counters_topic = "counters-from-file"
+drops-topic = "drops-from-file"
+
+[kafka.auth]
+protocol = "SASL_PLAINTEXT"
+sasl_password = "inline-from-file"
+sasl_password_file = "$WORK_DIR/sasl-password"
+TOML
+
+# Print the configuration at trace level, then stop pesto
+run_pesto() {
+    timeout 2 "$PESTO" -vv "$@" 2>&1 | grep -m1 "AppConfig"
+}
+
+FAILED=0
+check() {
+    local description="$1" expected="$2" output="$3"
+    if echo "$output" | grep -qF -- "$expected"; then
+        echo "✓ $description"
+    else
+        echo "✗ $description: expected $expected"
+        FAILED=1
+    fi
+}
+check_absent() {
+    local description="$1" unexpected="$2" output="$3"
+    if echo "$output" | grep -qF -- "$unexpected"; then
+        echo "✗ $description: found $unexpected"
+        FAILED=1
+    else
+        echo "✓ $description"
+    fi
+}
+
+echo "=== Configuration precedence ==="
+OUTPUT=$(PESTO_KAFKA_COUNTERS_TOPIC=counters-from-env PESTO_KAFKA_DROPS_TOPIC=drops-from-env \
+    run_pesto --config "$WORK_DIR/pesto.toml" --kafka-drops-topic drops-from-cli)
+
+if [ -z "$OUTPUT" ]; then
+    echo "✗ pesto did not print its configuration"
+    exit 1
+fi
+
+check "configuration file overrides defaults" 'topic: "topic-from-file"' "$OUTPUT"
+check "environment overrides configuration file" 'counters_topic: "counters-from-env"' "$OUTPUT"
+check "flags override environment" 'drops_topic: "drops-from-cli"' "$OUTPUT"
+check "defaults are kept" 'batch_wait_time: 1000' "$OUTPUT"
+check "nested sections are joined" 'auth_protocol: "SASL_PLAINTEXT"' "$OUTPUT"
+check_absent "secrets file is redacted" "s3cr3t-from-file" "$OUTPUT"
+check_absent "inline secrets are redacted" "inline-from-file" "$OUTPUT"
+
+echo ""
+echo "=== sFlow listeners ==="
+OUTPUT=$(PESTO_SFLOW_ADDRESS="127.0.0.1:0;tag=edge;topic=flows-edge,127.0.0.1:0" \
+    run_pesto --config "$WORK_DIR/pesto.toml")
+check "listeners carry their tag and topic" 'tag: "edge", topic: Some("flows-edge")' "$OUTPUT"
+check "listeners default to no tag and topic" 'tag: "", topic: None' "$OUTPUT"
+
+echo ""
+echo "=== sFlow agent ACL ==="
+OUTPUT=$(run_pesto --config "$WORK_DIR/pesto.toml" --sflow-allow 192.0.2.1,198.51.100.7/24 \
+    --sflow-deny 2001:db8::/32)
+check "single addresses are host prefixes" 'allow: [192.0.2.1/32, 198.51.100.0/24]' "$OUTPUT"
+check "IPv6 prefixes are accepted" 'deny: [2001:db8::/32]' "$OUTPUT"
+
+echo ""
+echo "=== NetFlow exporter ACL ==="
+OUTPUT=$(run_pesto --config "$WORK_DIR/pesto.toml" --netflow-allow 192.0.2.0/24 \
+    --netflow-deny 192.0.2.128/25)
+check "exporter prefixes are kept apart from the sFlow ones" \
+    'NetFlowConfig { hosts: [], allow: [192.0.2.0/24], deny: [192.0.2.128/25] }' "$OUTPUT"
+
+echo ""
+echo "=== Filter rules ==="
+OUTPUT=$(run_pesto --config "$WORK_DIR/pesto.toml" \
+    --filter 'dns=protocol == udp and port == 53,backup=vlan in 100-199;topic=flows-edge')
+check "rules carry their expression" 'name: "dns", topic: None, expression: "protocol == udp and port == 53"' "$OUTPUT"
+check "rules can be limited to a topic" 'name: "backup", topic: Some("flows-edge")' "$OUTPUT"
+
+echo ""
+echo "=== Anonymization ==="
+head -c 32 /dev/urandom > "$WORK_DIR/cryptopan.key"
+OUTPUT=$(run_pesto --config "$WORK_DIR/pesto.toml" --anonymize-key-file "$WORK_DIR/cryptopan.key" \
+    --anonymize-exempt 192.0.2.0/24)
+check "anonymization key is redacted" 'Anonymizer { key: "<redacted>", agent: false, exempt: [192.0.2.0/24] }' "$OUTPUT"
+
+echo ""
+echo "=== Invalid configuration ==="
+echo 'kafka_unknown = 1' > "$WORK_DIR/invalid.toml"
+if "$PESTO" --config "$WORK_DIR/invalid.toml" 2>&1 | grep -q "Unknown setting"; then
+    echo "✓ unknown settings are rejected"
+else
+    echo "✗ unknown settings are accepted"
+    FAILED=1
+fi
+
+if "$PESTO" --config "$WORK_DIR/pesto.toml" --kafka-auth-sasl-password-file "$WORK_DIR/missing" 2>&1 \
+    | grep -q "Failed to read secret file"; then
+    echo "✓ missing secrets files are rejected"
+else
+    echo "✗ missing secrets files are accepted"
+    FAILED=1
+fi
+
+if "$PESTO" --sflow-address "127.0.0.1:0;vrf=blue" 2>&1 | grep -q "Invalid sFlow listener option"; then
+    echo "✓ unknown listener options are rejected"
+else
+    echo "✗ unknown listener options are accepted"
+    FAILED=1
+fi
+
+if "$PESTO" --sflow-allow "192.0.2.0/33" 2>&1 | grep -q "Invalid prefix"; then
+    echo "✓ invalid ACL prefixes are rejected"
+else
+    echo "✗ invalid ACL prefixes are accepted"
+    FAILED=1
+fi
+
+if "$PESTO" --filter "ssh=dst_port = 22" 2>&1 | grep -q "Invalid filter rule"; then
+    echo "✓ invalid filter expressions are rejected"
+else
+    echo "✗ invalid filter expressions are accepted"
+    FAILED=1
+fi
+
+echo "not a key" > "$WORK_DIR/invalid.key"
+if "$PESTO" --anonymize-key-file "$WORK_DIR/invalid.key" 2>&1 | grep -q "Invalid anonymization key file"; then
+    echo "✓ invalid anonymization keys are rejected"
+else
+    echo "✗ invalid anonymization keys are accepted"
+    FAILED=1
+fi
+
+GEOIP_DIR="$(dirname "$0")/../geoip"
+if "$PESTO" --geoip-asn-database "$GEOIP_DIR/city.mmdb" 2>&1 | grep -q "Unexpected GeoIP database type"; then
+    echo "✓ GeoIP databases of the wrong type are rejected"
+else
+    echo "✗ GeoIP databases of the wrong type are accepted"
+    FAILED=1
+fi
+
+echo "agent,hostname" > "$WORK_DIR/metadata.csv"
+if "$PESTO" --metadata-file "$WORK_DIR/metadata.csv" 2>&1 | grep -q "Unsupported metadata file format"; then
+    echo "✓ metadata files of unknown formats are rejected"
+else
+    echo "✗ metadata files of unknown formats are accepted"
+    FAILED=1
+fi
+
+exit $FAILED
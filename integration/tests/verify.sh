@@ -12,7 +12,7 @@ sleep 10
 echo "1. Checking Pesto metrics..."
 DATAGRAMS=$(curl -s http://127.0.0.1:8080/metrics | grep 'pesto_sflow_datagrams_total{status="success"}' | awk '{print $2}')
 SAMPLES=$(curl -s http://127.0.0.1:8080/metrics | grep 'pesto_sflow_samples_total' | awk '{print $2}')
-RECORDS=$(curl -s http://127.0.0.1:8080/metrics | grep 'pesto_sflow_records_total' | awk '{print $2}')
+RECORDS=$(curl -s http://127.0.0.1:8080/metrics | grep 'pesto_sflow_records_total{type="flow"}' | awk '{print $2}')
 KAFKA_SUCCESS=$(curl -s http://127.0.0.1:8080/metrics | grep 'pesto_kafka_messages_total{status="success"}' | awk '{print $2}')
 
 echo "   Datagrams received: ${DATAGRAMS:-0}"
@@ -32,11 +32,23 @@ if [ "$FLOWS" -gt 0 ]; then
     echo ""
     echo "3. Flow records:"
     docker exec integration-clickhouse-1 clickhouse-client --query "SELECT time_received_ns, sampler_address, src_addr, dst_addr, src_port, dst_port, protocol, bytes, packets FROM sflow.flows LIMIT 3 FORMAT Vertical"
+
+    echo ""
+    echo "4. GeoIP enrichment (test databases in geoip/):"
+    docker exec integration-clickhouse-1 clickhouse-client --query "SELECT src_country, src_city, src_asn, src_as_org, dst_country, dst_city, dst_asn, dst_as_org FROM sflow.flows LIMIT 1 FORMAT Vertical"
+    ENRICHED=$(docker exec integration-clickhouse-1 clickhouse-client --query "SELECT COUNT(*) FROM sflow.flows WHERE src_country = 'US' AND src_asn = 64496 AND dst_country = 'FR' AND dst_asn = 64497" 2>/dev/null || echo "0")
+    echo "   Enriched flows: $ENRICHED"
+
+    echo ""
+    echo "5. Agent metadata (config/pesto/metadata.yaml):"
+    docker exec integration-clickhouse-1 clickhouse-client --query "SELECT sampler_address, sampler_hostname, sampler_site, sampler_role, in_if_name, out_if_name FROM sflow.flows LIMIT 1 FORMAT Vertical"
+    DESCRIBED=$(docker exec integration-clickhouse-1 clickhouse-client --query "SELECT COUNT(*) FROM sflow.flows WHERE sampler_hostname = 'edge1.test' AND sampler_site = 'test1'" 2>/dev/null || echo "0")
+    echo "   Flows with agent metadata: $DESCRIBED"
 fi
 
 echo ""
 echo "=== Test Summary ==="
-if [ "${DATAGRAMS:-0}" -gt 0 ] && [ "${FLOWS:-0}" -gt 0 ]; then
+if [ "${DATAGRAMS:-0}" -gt 0 ] && [ "${FLOWS:-0}" -gt 0 ] && [ "${ENRICHED:-0}" -gt 0 ] && [ "${DESCRIBED:-0}" -gt 0 ]; then
     echo "✅ SUCCESS: Data flowing from sFlow producer → Pesto → Kafka → ClickHouse"
     exit 0
 else
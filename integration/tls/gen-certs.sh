@@ -0,0 +1,33 @@
+#!/bin/bash
+# Generate a self-signed CA, a broker certificate and a client certificate
+# for the mutual TLS integration environment (compose.tls.yml)
+set -e
+
+CERTS_DIR="$(dirname "$0")/certs"
+BROKER_IP="10.0.0.100"
+DAYS=365
+
+mkdir -p "$CERTS_DIR"
+cd "$CERTS_DIR"
+
+# Certificate authority
+openssl req -x509 -newkey rsa:2048 -nodes -days "$DAYS" \
+    -keyout ca.key -out ca.crt -subj "/CN=pesto-integration-ca"
+
+# Broker certificate, valid for the broker IP address
+openssl req -newkey rsa:2048 -nodes \
+    -keyout broker.key -out broker.csr -subj "/CN=redpanda"
+openssl x509 -req -in broker.csr -CA ca.crt -CAkey ca.key -CAcreateserial \
+    -days "$DAYS" -out broker.crt \
+    -extfile <(printf "subjectAltName=IP:%s,DNS:redpanda" "$BROKER_IP")
+
+# Client certificate for pesto
+openssl req -newkey rsa:2048 -nodes \
+    -keyout client.key -out client.csr -subj "/CN=pesto"
+openssl x509 -req -in client.csr -CA ca.crt -CAkey ca.key -CAcreateserial \
+    -days "$DAYS" -out client.crt
+
+rm -f broker.csr client.csr
+chmod 644 ./*.key ./*.crt
+
+echo "Certificates written to $CERTS_DIR"
@@ -0,0 +1,187 @@
+use ipnet::IpNet;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use crate::config::SFlowConfig;
+
+// Idle buckets are forgotten after this, they are full again anyway
+const BUCKET_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Reason of a rejected sFlow datagram or NetFlow packet, the label of the
+/// rejects metric
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reject {
+    /// UDP source address in the deny list
+    PeerDenied,
+    /// UDP source address not in the allow list
+    PeerNotAllowed,
+    /// Datagram agent address in the deny list
+    AgentDenied,
+    /// Datagram agent address not in the allow list
+    AgentNotAllowed,
+    /// Agent over its datagram rate limit
+    RateLimited,
+}
+
+impl Reject {
+    pub fn label(self) -> &'static str {
+        match self {
+            Reject::PeerDenied => "peer_denied",
+            Reject::PeerNotAllowed => "peer_not_allowed",
+            Reject::AgentDenied => "agent_denied",
+            Reject::AgentNotAllowed => "agent_not_allowed",
+            Reject::RateLimited => "rate_limited",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Verdict {
+    Allowed,
+    Denied,
+    NotAllowed,
+}
+
+// The deny list wins over the allow list, and an empty allow list allows everything
+fn verdict(allow: &[IpNet], deny: &[IpNet], addr: IpAddr) -> Verdict {
+    // IPv4 peers of a dual-stack socket are IPv4-mapped IPv6 addresses
+    let addr = addr.to_canonical();
+    if deny.iter().any(|prefix| prefix.contains(&addr)) {
+        return Verdict::Denied;
+    }
+    if !allow.is_empty() && !allow.iter().any(|prefix| prefix.contains(&addr)) {
+        return Verdict::NotAllowed;
+    }
+    Verdict::Allowed
+}
+
+/// Check the UDP source address of a datagram against the allow and deny lists
+/// of its protocol
+pub fn check_peer(allow: &[IpNet], deny: &[IpNet], peer: IpAddr) -> Result<(), Reject> {
+    match verdict(allow, deny, peer) {
+        Verdict::Allowed => Ok(()),
+        Verdict::Denied => Err(Reject::PeerDenied),
+        Verdict::NotAllowed => Err(Reject::PeerNotAllowed),
+    }
+}
+
+/// Check the agent address of a datagram against the ACL, unknown agents are
+/// only checked by their UDP source address
+pub fn check_agent(config: &SFlowConfig, agent: Option<IpAddr>) -> Result<(), Reject> {
+    let Some(agent) = agent else {
+        return Ok(());
+    };
+    match verdict(&config.allow, &config.deny, agent) {
+        Verdict::Allowed => Ok(()),
+        Verdict::Denied => Err(Reject::AgentDenied),
+        Verdict::NotAllowed => Err(Reject::AgentNotAllowed),
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token bucket per agent, limiting the datagrams per second of each agent
+///
+/// Each sFlow worker has its own limiter, an agent sending to several
+/// listeners gets its rate on each of them.
+#[derive(Default)]
+pub struct RateLimiter {
+    buckets: HashMap<IpAddr, Bucket>,
+    pruned: Option<Instant>,
+}
+
+impl RateLimiter {
+    /// Take a token from the bucket of the agent
+    pub fn check(&mut self, config: &SFlowConfig, agent: IpAddr) -> Result<(), Reject> {
+        let Some(rate) = config.agent_rate_limit else {
+            return Ok(());
+        };
+        let rate = rate as f64;
+        let burst = config.agent_rate_burst.map_or(rate, |burst| burst as f64);
+
+        let now = Instant::now();
+        self.prune(now);
+
+        let bucket = self.buckets.entry(agent).or_insert(Bucket {
+            tokens: burst,
+            updated: now,
+        });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(burst);
+        bucket.updated = now;
+
+        if bucket.tokens < 1.0 {
+            return Err(Reject::RateLimited);
+        }
+        bucket.tokens -= 1.0;
+        Ok(())
+    }
+
+    // Forget the agents gone silent, to bound the buckets to the active agents
+    fn prune(&mut self, now: Instant) {
+        match self.pruned {
+            Some(pruned) if now.duration_since(pruned) < BUCKET_IDLE_TIMEOUT => {}
+            _ => {
+                self.buckets
+                    .retain(|_, bucket| now.duration_since(bucket.updated) < BUCKET_IDLE_TIMEOUT);
+                self.pruned = Some(now);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+
+    #[tokio::test]
+    async fn protocol_lists() {
+        let config = config::from_args(&[
+            "--sflow-allow",
+            "198.51.100.0/24",
+            "--netflow-allow",
+            "192.0.2.0/24",
+            "--netflow-deny",
+            "192.0.2.128/25",
+        ])
+        .await
+        .unwrap();
+        let netflow = |peer: &str| {
+            check_peer(
+                &config.netflow.allow,
+                &config.netflow.deny,
+                peer.parse().unwrap(),
+            )
+        };
+        let sflow = |peer: &str| {
+            check_peer(
+                &config.sflow.allow,
+                &config.sflow.deny,
+                peer.parse().unwrap(),
+            )
+        };
+
+        assert_eq!(netflow("192.0.2.1"), Ok(()));
+        assert_eq!(netflow("::ffff:192.0.2.1"), Ok(()));
+        assert_eq!(netflow("192.0.2.129"), Err(Reject::PeerDenied));
+        assert_eq!(netflow("198.51.100.1"), Err(Reject::PeerNotAllowed));
+        assert_eq!(sflow("198.51.100.1"), Ok(()));
+        assert_eq!(sflow("192.0.2.1"), Err(Reject::PeerNotAllowed));
+    }
+
+    #[tokio::test]
+    async fn empty_lists() {
+        let config = config::from_args(&[]).await.unwrap();
+        let peer = "203.0.113.1".parse().unwrap();
+        assert_eq!(
+            check_peer(&config.netflow.allow, &config.netflow.deny, peer),
+            Ok(())
+        );
+    }
+}
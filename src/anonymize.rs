@@ -0,0 +1,243 @@
+use aes::cipher::{BlockEncrypt, KeyInit};
+use aes::Aes128;
+use anyhow::{Context, Result};
+use ipnet::IpNet;
+use sflow_parser::models::Address;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::path::Path;
+
+/// Prefix-preserving IP address anonymization (Crypto-PAn)
+///
+/// Addresses sharing a prefix keep sharing a prefix of the same length once
+/// anonymized. The 32 bytes key is the AES-128 key followed by the pad, so the
+/// same key always gives the same addresses. IPv4 addresses, IPv4-mapped ones
+/// included, are anonymized as IPv4 addresses, and IPv6 addresses on their 128
+/// bits.
+#[derive(Clone)]
+pub struct Anonymizer {
+    key: [u8; 32],
+    cipher: Aes128,
+    pad: u128,
+    pub agent: bool,
+    exempt: Vec<IpNet>,
+}
+
+impl Anonymizer {
+    pub fn new(key: [u8; 32], agent: bool, exempt: Vec<IpNet>) -> Self {
+        let cipher = Aes128::new_from_slice(&key[..16]).expect("AES-128 key of 16 bytes");
+        let mut pad = [0u8; 16];
+        pad.copy_from_slice(&key[16..]);
+        cipher.encrypt_block((&mut pad).into());
+
+        Anonymizer {
+            key,
+            cipher,
+            pad: u128::from_be_bytes(pad),
+            agent,
+            exempt,
+        }
+    }
+
+    /// Read the key from a file, 32 raw bytes or 64 hexadecimal digits
+    ///
+    /// A file of hexadecimal digits only is always read as hexadecimal, so that
+    /// a 32 digits key is rejected rather than taken as 32 raw bytes.
+    pub fn from_key_file(path: &Path, agent: bool, exempt: Vec<IpNet>) -> Result<Self> {
+        let content = std::fs::read(path)
+            .with_context(|| format!("Failed to read anonymization key file {}", path.display()))?;
+        let hex = content.trim_ascii();
+        let key = if !hex.is_empty() && hex.iter().all(u8::is_ascii_hexdigit) {
+            parse_hex(hex)
+        } else if content.len() == 32 {
+            Some(content)
+        } else {
+            None
+        };
+        match key.and_then(|key| key.try_into().ok()) {
+            Some(key) => Ok(Anonymizer::new(key, agent, exempt)),
+            None => anyhow::bail!(
+                "Invalid anonymization key file {}: expected 32 bytes or 64 hexadecimal digits",
+                path.display()
+            ),
+        }
+    }
+
+    /// Anonymized address, unless exempted or unspecified
+    pub fn ip(&self, ip: IpAddr) -> IpAddr {
+        let ip = ip.to_canonical();
+        if ip.is_unspecified() || self.exempt.iter().any(|prefix| prefix.contains(&ip)) {
+            return ip;
+        }
+        match ip {
+            IpAddr::V4(ipv4) => {
+                let bits = (u32::from(ipv4) as u128) << 96;
+                let anonymized = (self.anonymize(bits, 32) >> 96) as u32;
+                IpAddr::V4(Ipv4Addr::from(anonymized))
+            }
+            IpAddr::V6(ipv6) => IpAddr::V6(Ipv6Addr::from(self.anonymize(u128::from(ipv6), 128))),
+        }
+    }
+
+    /// Anonymized address of the records, IPv4 mapped to IPv6
+    pub fn ipv6(&self, ip: Ipv6Addr) -> Ipv6Addr {
+        match self.ip(IpAddr::V6(ip)) {
+            IpAddr::V4(ipv4) => ipv4.to_ipv6_mapped(),
+            IpAddr::V6(ipv6) => ipv6,
+        }
+    }
+
+    /// Anonymized agent address, if the agent address is anonymized
+    pub fn agent_address(&self, address: &Address) -> Address {
+        let ip = match address {
+            Address::IPv4(ipv4) if self.agent => self.ip(IpAddr::V4(*ipv4)),
+            Address::IPv6(ipv6) if self.agent => self.ip(IpAddr::V6(*ipv6)),
+            _ => return address.clone(),
+        };
+        match ip {
+            IpAddr::V4(ipv4) => Address::IPv4(ipv4),
+            IpAddr::V6(ipv6) => Address::IPv6(ipv6),
+        }
+    }
+
+    // Each bit of the address is flipped by the first bit of the encrypted
+    // block made of the address bits before it, followed by the pad
+    fn anonymize(&self, addr: u128, bits: u32) -> u128 {
+        let mut otp = 0u128;
+        for position in 0..bits {
+            let mask = u128::MAX.checked_shl(128 - position).unwrap_or(0);
+            let mut block = ((addr & mask) | (self.pad & !mask)).to_be_bytes();
+            self.cipher.encrypt_block((&mut block).into());
+            otp |= ((block[0] >> 7) as u128) << (127 - position);
+        }
+        addr ^ otp
+    }
+}
+
+impl PartialEq for Anonymizer {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key && self.agent == other.agent && self.exempt == other.exempt
+    }
+}
+
+impl fmt::Debug for Anonymizer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Anonymizer")
+            .field("key", &"<redacted>")
+            .field("agent", &self.agent)
+            .field("exempt", &self.exempt)
+            .finish()
+    }
+}
+
+fn parse_hex(hex: &[u8]) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    hex.chunks(2)
+        .map(|pair| u8::from_str_radix(std::str::from_utf8(pair).ok()?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Key of the Crypto-PAn reference implementation sample
+    const KEY: [u8; 32] = [
+        21, 34, 23, 141, 51, 164, 207, 128, 19, 10, 91, 22, 73, 144, 125, 16, 216, 152, 143, 131,
+        121, 121, 101, 39, 98, 87, 76, 45, 42, 132, 34, 2,
+    ];
+
+    fn anonymize(anonymizer: &Anonymizer, ip: &str) -> IpAddr {
+        anonymizer.ip(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn reference_vectors() {
+        let anonymizer = Anonymizer::new(KEY, false, Vec::new());
+        for (ip, anonymized) in [
+            ("128.11.68.132", "135.242.180.132"),
+            ("129.118.74.4", "134.136.186.123"),
+            ("192.102.249.13", "252.138.62.131"),
+        ] {
+            assert_eq!(
+                anonymize(&anonymizer, ip),
+                anonymized.parse::<IpAddr>().unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn prefix_preserving() {
+        let anonymizer = Anonymizer::new(KEY, false, Vec::new());
+        let common_prefix = |a: IpAddr, b: IpAddr| match (a, b) {
+            (IpAddr::V4(a), IpAddr::V4(b)) => (u32::from(a) ^ u32::from(b)).leading_zeros(),
+            (IpAddr::V6(a), IpAddr::V6(b)) => (u128::from(a) ^ u128::from(b)).leading_zeros(),
+            _ => panic!("address families differ"),
+        };
+        for (a, b) in [
+            ("10.1.2.3", "10.1.2.200"),
+            ("10.1.2.3", "10.200.0.1"),
+            ("10.1.2.3", "192.0.2.1"),
+            ("2001:db8::1", "2001:db8::ffff"),
+            ("2001:db8::1", "2001:db9::1"),
+        ] {
+            let (a, b) = (a.parse().unwrap(), b.parse().unwrap());
+            let (anonymized_a, anonymized_b) = (anonymizer.ip(a), anonymizer.ip(b));
+            assert_ne!(anonymized_a, a);
+            assert_eq!(
+                common_prefix(a, b),
+                common_prefix(anonymized_a, anonymized_b)
+            );
+        }
+    }
+
+    #[test]
+    fn ipv4_mapped() {
+        let anonymizer = Anonymizer::new(KEY, false, Vec::new());
+        let mapped: Ipv6Addr = "::ffff:128.11.68.132".parse().unwrap();
+        assert_eq!(
+            anonymizer.ipv6(mapped),
+            "::ffff:135.242.180.132".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(
+            anonymize(&anonymizer, "::ffff:128.11.68.132"),
+            "135.242.180.132".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn exempt_and_unspecified() {
+        let exempt = vec!["192.0.2.0/24".parse().unwrap()];
+        let anonymizer = Anonymizer::new(KEY, false, exempt);
+        assert_eq!(
+            anonymize(&anonymizer, "::ffff:192.0.2.1"),
+            "192.0.2.1".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(
+            anonymize(&anonymizer, "0.0.0.0"),
+            "0.0.0.0".parse::<IpAddr>().unwrap()
+        );
+    }
+
+    #[test]
+    fn key_files() {
+        let path =
+            std::env::temp_dir().join(format!("pesto-anonymize-test-{}", std::process::id()));
+        let read = |content: &[u8]| {
+            std::fs::write(&path, content).unwrap();
+            Anonymizer::from_key_file(&path, false, Vec::new())
+        };
+
+        let hex: String = KEY.iter().map(|byte| format!("{:02x}", byte)).collect();
+        assert_eq!(read(format!("{}\n", hex).as_bytes()).unwrap().key, KEY);
+        assert_eq!(read(&KEY).unwrap().key, KEY);
+        // A 32 digits hexadecimal key is truncated, not 32 raw bytes
+        assert!(read(&hex.as_bytes()[..32]).is_err());
+        assert!(read(&hex.as_bytes()[..63]).is_err());
+        assert!(read(&KEY[..31]).is_err());
+        assert!(read(b"").is_err());
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,200 @@
+use metrics::histogram;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::net::IpAddr;
+use tracing::warn;
+
+use crate::serializer::SerializedRecord;
+
+/// Type of the records of a Kafka message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecordType {
+    Flow,
+    Counter,
+    Drop,
+    NetFlow,
+}
+
+impl RecordType {
+    /// Label of the metrics
+    pub fn label(self) -> &'static str {
+        match self {
+            RecordType::Flow => "flow",
+            RecordType::Counter => "counter",
+            RecordType::Drop => "drop",
+            RecordType::NetFlow => "netflow",
+        }
+    }
+
+    /// Cap'n Proto struct of the records in schemas/sflow.capnp
+    pub fn schema(self) -> &'static str {
+        match self {
+            RecordType::Flow => "SFlowFlowRecord",
+            RecordType::Counter => "SFlowCounterRecord",
+            RecordType::Drop => "SFlowDropRecord",
+            RecordType::NetFlow => "NetFlowRecord",
+        }
+    }
+
+    /// Code of the record type in the spool entries
+    pub fn code(self) -> u8 {
+        match self {
+            RecordType::Flow => 0,
+            RecordType::Counter => 1,
+            RecordType::Drop => 2,
+            RecordType::NetFlow => 3,
+        }
+    }
+
+    pub fn from_code(code: u8) -> Option<Self> {
+        match code {
+            0 => Some(RecordType::Flow),
+            1 => Some(RecordType::Counter),
+            2 => Some(RecordType::Drop),
+            3 => Some(RecordType::NetFlow),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for RecordType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// Serialized records sealed into a single Kafka message
+#[derive(Debug, Clone)]
+pub struct BatchMessage {
+    pub record_type: RecordType,
+    pub topic: String,
+    pub key: Vec<u8>,
+    pub payload: Vec<u8>,
+    pub n_records: u64,
+    pub min_time_received_ns: i64,
+    pub max_time_received_ns: i64,
+    // Set only if all the records come from this agent
+    pub agent_addr: Option<IpAddr>,
+}
+
+struct OpenBatch {
+    payload: Vec<u8>,
+    n_records: u64,
+    min_time_received_ns: i64,
+    max_time_received_ns: i64,
+    agent_addr: Option<IpAddr>,
+}
+
+impl OpenBatch {
+    fn new(time_received_ns: i64, agent_addr: Option<IpAddr>) -> Self {
+        OpenBatch {
+            payload: Vec::new(),
+            n_records: 0,
+            min_time_received_ns: time_received_ns,
+            max_time_received_ns: time_received_ns,
+            agent_addr,
+        }
+    }
+}
+
+/// Accumulates serialized records for a Kafka topic
+///
+/// Records are batched by message key, so that a batch goes to a single partition.
+/// A batch is sealed as soon as the next record would overflow `max_bytes`,
+/// and the record starts the next batch, so no record is ever dropped.
+pub struct Batcher {
+    record_type: RecordType,
+    topic: String,
+    max_bytes: usize,
+    open: HashMap<Vec<u8>, OpenBatch>,
+    sealed: VecDeque<BatchMessage>,
+}
+
+impl Batcher {
+    pub fn new(record_type: RecordType, topic: &str, max_bytes: usize) -> Self {
+        Batcher {
+            record_type,
+            topic: topic.to_string(),
+            max_bytes,
+            open: HashMap::new(),
+            sealed: VecDeque::new(),
+        }
+    }
+
+    pub fn extend(&mut self, records: Vec<SerializedRecord>) {
+        for record in records {
+            self.push(record);
+        }
+    }
+
+    pub fn push(&mut self, record: SerializedRecord) {
+        let SerializedRecord {
+            key,
+            payload,
+            time_received_ns,
+            agent_addr,
+        } = record;
+
+        // Max message size check
+        let overflow = self.open.get(&key).is_some_and(|batch| {
+            !batch.payload.is_empty() && batch.payload.len() + payload.len() > self.max_bytes
+        });
+        if overflow {
+            self.seal(&key);
+        }
+
+        if payload.len() > self.max_bytes {
+            warn!(
+                "sFlow {} record of {} bytes exceeds the message max bytes ({})",
+                self.record_type,
+                payload.len(),
+                self.max_bytes
+            );
+        }
+
+        let batch = self
+            .open
+            .entry(key)
+            .or_insert_with(|| OpenBatch::new(time_received_ns, agent_addr));
+        batch.payload.extend_from_slice(&payload);
+        batch.n_records += 1;
+        batch.min_time_received_ns = batch.min_time_received_ns.min(time_received_ns);
+        batch.max_time_received_ns = batch.max_time_received_ns.max(time_received_ns);
+        if batch.agent_addr != agent_addr {
+            batch.agent_addr = None;
+        }
+    }
+
+    /// Seal the current batches, if any, regardless of their size
+    pub fn flush(&mut self) {
+        let keys: Vec<Vec<u8>> = self.open.keys().cloned().collect();
+        for key in keys {
+            self.seal(&key);
+        }
+    }
+
+    /// Next sealed batch, in order
+    pub fn pop(&mut self) -> Option<BatchMessage> {
+        self.sealed.pop_front()
+    }
+
+    fn seal(&mut self, key: &[u8]) {
+        let Some((key, batch)) = self.open.remove_entry(key) else {
+            return;
+        };
+
+        histogram!("pesto_kafka_batch_records", "type" => self.record_type.label())
+            .record(batch.n_records as f64);
+
+        self.sealed.push_back(BatchMessage {
+            record_type: self.record_type,
+            topic: self.topic.clone(),
+            key,
+            payload: batch.payload,
+            n_records: batch.n_records,
+            min_time_received_ns: batch.min_time_received_ns,
+            max_time_received_ns: batch.max_time_received_ns,
+            agent_addr: batch.agent_addr,
+        });
+    }
+}
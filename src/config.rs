@@ -1,43 +1,265 @@
-use anyhow::Result;
-use clap::Parser;
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Command, CommandFactory, FromArgMatches, Parser, ValueEnum};
 use clap_verbosity_flag::{InfoLevel, Verbosity};
-use metrics_exporter_prometheus::PrometheusBuilder;
-use std::net::SocketAddr;
+use ipnet::IpNet;
+use metrics_exporter_prometheus::{Matcher, PrometheusBuilder};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 use tokio::net::lookup_host;
 
-#[derive(Debug, Clone)]
+use crate::anonymize::Anonymizer;
+use crate::filter::{parse_rule, Rule};
+
+// Settings are taken from these layers, from the highest to the lowest precedence:
+//   1. command line flags (e.g. --kafka-brokers)
+//   2. environment variables, named after the flag (e.g. PESTO_KAFKA_BROKERS)
+//   3. configuration file (--config or PESTO_CONFIG), TOML or YAML
+//   4. built-in defaults
+//
+// Configuration file keys are the flag names, and sections are joined to the key
+// with an underscore: `brokers` in the `[kafka]` section is --kafka-brokers.
+//
+// Secrets can also be read from a file, with the setting suffixed by `_file`
+// (e.g. --kafka-auth-sasl-password-file). The secret comes from the layer with
+// the highest precedence, and the file wins within a layer.
+
+// Arguments not configurable through the environment or the configuration file
+const RESERVED_ARGS: [&str; 4] = ["help", "version", "verbose", "quiet"];
+
+/// String hidden from the Debug output, for passwords and keys
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("\"<redacted>\"")
+    }
+}
+
+impl FromStr for Secret {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Secret(s.to_string()))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct AppConfig {
+    pub addresses: Addresses,
+    pub metrics_address: SocketAddr,
+    pub admin_address: Option<SocketAddr>,
     pub sflow: SFlowConfig,
+    pub netflow: NetFlowConfig,
+    pub filters: Vec<Rule>,
+    pub anonymizer: Option<Anonymizer>,
+    pub geoip: GeoIpConfig,
+    pub metadata_file: Option<PathBuf>,
     pub kafka: KafkaConfig,
 }
 
-#[derive(Debug, Clone)]
+/// Listener and broker addresses as configured, before their resolution
+#[derive(Debug, Clone, PartialEq)]
+pub struct Addresses {
+    pub metrics: String,
+    pub admin: Option<String>,
+    pub sflow: Vec<String>,
+    pub netflow: Vec<String>,
+    pub kafka_brokers: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct SFlowConfig {
+    pub listeners: Vec<SFlowListener>,
+    pub workers: usize,
+    pub recv_batch_size: usize,
+    pub recv_buffer_bytes: Option<usize>,
+    pub kernel_timestamps: bool,
+    pub allow: Vec<IpNet>,
+    pub deny: Vec<IpNet>,
+    pub agent_rate_limit: Option<u32>,
+    pub agent_rate_burst: Option<u32>,
+    pub substitute_agent_address: bool,
+}
+
+/// sFlow listener, its records carry the tag and may go to their own topic
+#[derive(Debug, Clone, PartialEq)]
+pub struct SFlowListener {
     pub host: SocketAddr,
+    pub tag: String,
+    // Overrides the Kafka topic of the flow records
+    pub topic: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetFlowConfig {
+    pub hosts: Vec<SocketAddr>,
+    pub allow: Vec<IpNet>,
+    pub deny: Vec<IpNet>,
+}
+
+/// MaxMind databases of the flow records enrichment, reloaded when they change
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeoIpConfig {
+    pub city_database: Option<PathBuf>,
+    pub asn_database: Option<PathBuf>,
+}
+
+/// Kafka message key of the records, records with the same key share a partition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum KeyStrategy {
+    /// Empty key, messages are spread over the partitions
+    None,
+    /// Agent address
+    Agent,
+    /// Agent address and sub-agent ID
+    AgentSubAgent,
+    /// Hash of the flow 5-tuple, counter records fall back to the agent address
+    FiveTuple,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct KafkaConfig {
     pub disable: bool,
     pub brokers: Vec<SocketAddr>,
     pub topic: String,
+    pub counters_topic: String,
+    pub drops_topic: String,
+    pub netflow_topic: String,
+    pub key: KeyStrategy,
     pub auth_protocol: String,
     pub auth_sasl_username: String,
-    pub auth_sasl_password: String,
+    pub auth_sasl_password: Secret,
     pub auth_sasl_mechanism: String,
+    pub auth_ssl_ca_location: Option<PathBuf>,
+    pub auth_ssl_certificate_location: Option<PathBuf>,
+    pub auth_ssl_key_location: Option<PathBuf>,
+    pub auth_ssl_key_password: Option<Secret>,
+    pub auth_ssl_verify_hostname: bool,
     pub message_max_bytes: usize,
     pub message_timeout_ms: usize,
     pub batch_wait_time: u64,
     pub batch_wait_interval: u64,
     pub mpsc_buffer_size: usize,
+    pub retry_max_attempts: u32,
+    pub retry_backoff_ms: u64,
+    pub retry_backoff_max_ms: u64,
+    pub retry_queue_size: usize,
+    pub fallback_dir: Option<PathBuf>,
+    pub spool_dir: Option<PathBuf>,
+    pub spool_max_bytes: u64,
+    pub spool_segment_bytes: u64,
+    pub spool_replay_window: usize,
 }
 
 #[derive(Parser, Debug)]
-#[command(author, version, about = "Pesto sFlow v5 collector", long_about = None)]
+#[command(author, version, about = "Pesto sFlow v5, NetFlow and IPFIX collector", long_about = None)]
 pub struct Cli {
-    /// sFlow listener address (IP or FQDN)
-    #[arg(long, default_value = "0.0.0.0:6343")]
-    pub sflow_address: String,
+    /// Configuration file (TOML or YAML), overridden by the environment and the flags
+    #[arg(long)]
+    pub config: Option<PathBuf>,
+
+    /// sFlow listener addresses (comma-separated, IP or FQDN), each optionally
+    /// followed by `;tag=TAG` and `;topic=TOPIC` for the flow records
+    #[arg(long, value_delimiter(','), default_value = "0.0.0.0:6343")]
+    pub sflow_address: Vec<String>,
+
+    /// sFlow workers per listener, each with its own SO_REUSEPORT socket
+    #[arg(long, default_value_t = 1)]
+    pub sflow_workers: usize,
+
+    /// sFlow datagrams received per recvmmsg call (Linux only)
+    #[arg(long, default_value_t = 32)]
+    pub sflow_recv_batch_size: usize,
+
+    /// sFlow socket receive buffer size (SO_RCVBUF), the system default if unset
+    #[arg(long)]
+    pub sflow_recv_buffer_bytes: Option<usize>,
+
+    /// Timestamp the sFlow records with the kernel receive time (SO_TIMESTAMPNS, Linux only)
+    #[arg(long)]
+    pub sflow_kernel_timestamps: bool,
+
+    /// sFlow agent prefixes allowed (comma-separated CIDR), matched against the UDP
+    /// source and the agent address, everything is allowed if empty
+    #[arg(long, value_delimiter(','), value_parser = parse_prefix)]
+    pub sflow_allow: Vec<IpNet>,
+
+    /// sFlow agent prefixes denied (comma-separated CIDR), matched against the UDP
+    /// source and the agent address, wins over the allow list
+    #[arg(long, value_delimiter(','), value_parser = parse_prefix)]
+    pub sflow_deny: Vec<IpNet>,
+
+    /// sFlow datagrams per second accepted from each agent, unlimited if unset
+    #[arg(long)]
+    pub sflow_agent_rate_limit: Option<u32>,
+
+    /// sFlow datagrams accepted in a burst from each agent, the rate limit if unset
+    #[arg(long)]
+    pub sflow_agent_rate_burst: Option<u32>,
+
+    /// Replace unknown and private sFlow agent addresses by the UDP source address
+    #[arg(long)]
+    pub sflow_substitute_agent_address: bool,
+
+    /// NetFlow v5/v9 and IPFIX listener addresses (comma-separated, IP or FQDN), disabled if empty
+    #[arg(long, value_delimiter(','))]
+    pub netflow_address: Vec<String>,
+
+    /// NetFlow and IPFIX exporter prefixes allowed (comma-separated CIDR), matched
+    /// against the UDP source, everything is allowed if empty
+    #[arg(long, value_delimiter(','), value_parser = parse_prefix)]
+    pub netflow_allow: Vec<IpNet>,
+
+    /// NetFlow and IPFIX exporter prefixes denied (comma-separated CIDR), matched
+    /// against the UDP source, wins over the allow list
+    #[arg(long, value_delimiter(','), value_parser = parse_prefix)]
+    pub netflow_deny: Vec<IpNet>,
+
+    /// Flow record filter rules (comma-separated), each `NAME=EXPRESSION` dropping the
+    /// matching sFlow and NetFlow flow records, optionally followed by `;topic=TOPIC`
+    #[arg(long, value_delimiter(','), value_parser = parse_rule)]
+    pub filter: Vec<Rule>,
+
+    /// Crypto-PAn key file (32 raw bytes or 64 hexadecimal digits), anonymizes the flow
+    /// source and destination addresses if set
+    #[arg(long)]
+    pub anonymize_key_file: Option<PathBuf>,
+
+    /// Anonymize the agent and exporter addresses too
+    #[arg(long)]
+    pub anonymize_agent: bool,
+
+    /// Prefixes left as is by the anonymization (comma-separated CIDR)
+    #[arg(long, value_delimiter(','), value_parser = parse_prefix)]
+    pub anonymize_exempt: Vec<IpNet>,
+
+    /// GeoIP2 or GeoLite2 City database, adds the country and the city of the
+    /// flow source and destination addresses
+    #[arg(long)]
+    pub geoip_city_database: Option<PathBuf>,
+
+    /// GeoIP2 or GeoLite2 ASN database, adds the AS number and organization of
+    /// the flow source and destination addresses
+    #[arg(long)]
+    pub geoip_asn_database: Option<PathBuf>,
+
+    /// Agent and interface metadata file (YAML), adds the agent hostname, site and
+    /// role and the interface names, descriptions, speeds and boundaries to the flow
+    /// records, reloaded when it changes
+    #[arg(long)]
+    pub metadata_file: Option<PathBuf>,
 
     /// Kafka brokers (comma-separated list of address:port)
     #[arg(long, value_delimiter(','), default_value = "localhost:9092")]
@@ -51,7 +273,24 @@ pub struct Cli {
     #[arg(long, default_value = "pesto-sflow")]
     pub kafka_topic: String,
 
-    /// Kafka Authentication Protocol (e.g., PLAINTEXT, SASL_PLAINTEXT)
+    /// Kafka producer topic for counter records
+    #[arg(long, default_value = "pesto-sflow-counters")]
+    pub kafka_counters_topic: String,
+
+    /// Kafka producer topic for discarded packet records
+    #[arg(long, default_value = "pesto-sflow-drops")]
+    pub kafka_drops_topic: String,
+
+    /// Kafka producer topic for NetFlow and IPFIX records
+    #[arg(long, default_value = "pesto-netflow")]
+    pub kafka_netflow_topic: String,
+
+    /// Kafka message key strategy, records are batched by key so `five-tuple`
+    /// produces about one message per flow record
+    #[arg(long, value_enum, default_value_t = KeyStrategy::None)]
+    pub kafka_key: KeyStrategy,
+
+    /// Kafka Authentication Protocol (PLAINTEXT, SASL_PLAINTEXT, SSL or SASL_SSL)
     #[arg(long, default_value = "PLAINTEXT")]
     pub kafka_auth_protocol: String,
 
@@ -60,13 +299,46 @@ pub struct Cli {
     pub kafka_auth_sasl_username: String,
 
     /// Kafka Authentication SASL Password
-    #[arg(long, default_value = "pesto")]
-    pub kafka_auth_sasl_password: String,
+    #[arg(
+        long,
+        default_value = "pesto",
+        hide_default_value = true,
+        hide_env_values = true
+    )]
+    pub kafka_auth_sasl_password: Secret,
+
+    /// Kafka Authentication SASL Password file
+    #[arg(long)]
+    pub kafka_auth_sasl_password_file: Option<PathBuf>,
 
     /// Kafka Authentication SASL Mechanism (e.g., PLAIN, SCRAM-SHA-512)
     #[arg(long, default_value = "SCRAM-SHA-512")]
     pub kafka_auth_sasl_mechanism: String,
 
+    /// Kafka Authentication SSL CA bundle (PEM), defaults to the system CA store
+    #[arg(long)]
+    pub kafka_auth_ssl_ca_location: Option<PathBuf>,
+
+    /// Kafka Authentication SSL client certificate (PEM), for mutual TLS
+    #[arg(long)]
+    pub kafka_auth_ssl_certificate_location: Option<PathBuf>,
+
+    /// Kafka Authentication SSL client private key (PEM), for mutual TLS
+    #[arg(long)]
+    pub kafka_auth_ssl_key_location: Option<PathBuf>,
+
+    /// Kafka Authentication SSL client private key password
+    #[arg(long, hide_default_value = true, hide_env_values = true)]
+    pub kafka_auth_ssl_key_password: Option<Secret>,
+
+    /// Kafka Authentication SSL client private key password file
+    #[arg(long)]
+    pub kafka_auth_ssl_key_password_file: Option<PathBuf>,
+
+    /// Disable Kafka Authentication SSL broker hostname verification
+    #[arg(long)]
+    pub kafka_auth_ssl_disable_hostname_verification: bool,
+
     /// Kafka message max bytes
     #[arg(long, default_value_t = 990000)]
     pub kafka_message_max_bytes: usize,
@@ -87,10 +359,51 @@ pub struct Cli {
     #[arg(long, default_value_t = 100000)]
     pub kafka_mpsc_buffer_size: usize,
 
+    /// Kafka producer max retries of a failed batch, retried batches are sent after
+    /// newer ones so the records of a message key may arrive out of order
+    #[arg(long, default_value_t = 5)]
+    pub kafka_retry_max_attempts: u32,
+
+    /// Kafka producer initial retry backoff (ms), doubled on each attempt
+    #[arg(long, default_value_t = 500)]
+    pub kafka_retry_backoff_ms: u64,
+
+    /// Kafka producer max retry backoff (ms)
+    #[arg(long, default_value_t = 30000)]
+    pub kafka_retry_backoff_max_ms: u64,
+
+    /// Kafka producer retry queue size (batches)
+    #[arg(long, default_value_t = 1000)]
+    pub kafka_retry_queue_size: usize,
+
+    /// Directory where batches are written when they cannot be delivered to Kafka
+    #[arg(long)]
+    pub kafka_fallback_dir: Option<PathBuf>,
+
+    /// Directory where batches are spooled while Kafka is unreachable, replaces the retry queue
+    #[arg(long)]
+    pub kafka_spool_dir: Option<PathBuf>,
+
+    /// Kafka spool max size (bytes)
+    #[arg(long, default_value_t = 1073741824)]
+    pub kafka_spool_max_bytes: u64,
+
+    /// Kafka spool segment size (bytes)
+    #[arg(long, default_value_t = 67108864)]
+    pub kafka_spool_segment_bytes: u64,
+
+    /// Kafka spool replay max batches in flight, sent in order to each partition
+    #[arg(long, default_value_t = 32)]
+    pub kafka_spool_replay_window: usize,
+
     /// Metrics listener address (IP or FQDN) for Prometheus endpoint
     #[arg(long, default_value = "0.0.0.0:8080")]
     pub metrics_address: String,
 
+    /// Admin listener address (IP or FQDN), `POST /reload` reloads the configuration
+    #[arg(long)]
+    pub admin_address: Option<String>,
+
     /// Set the verbosity level
     #[command(flatten)]
     pub verbose: Verbosity<InfoLevel>,
@@ -109,7 +422,17 @@ fn set_logging(cli: &Cli) -> Result<()> {
 }
 
 fn set_metrics(metrics_address: SocketAddr) {
-    let prom_builder = PrometheusBuilder::new();
+    let prom_builder = PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full("pesto_kafka_batch_records".to_string()),
+            &[1.0, 10.0, 100.0, 1000.0, 10000.0, 100000.0],
+        )
+        .expect("Failed to set Prometheus histogram buckets")
+        .set_buckets_for_metric(
+            Matcher::Full("pesto_sflow_receive_delay_seconds".to_string()),
+            &[0.0001, 0.001, 0.01, 0.1, 1.0, 10.0],
+        )
+        .expect("Failed to set Prometheus histogram buckets");
     prom_builder
         .with_http_listener(metrics_address)
         .install()
@@ -120,6 +443,58 @@ fn set_metrics(metrics_address: SocketAddr) {
         "pesto_kafka_messages_total",
         "Total number of Kafka messages produced"
     );
+    metrics::describe_counter!(
+        "pesto_kafka_retries_total",
+        "Total number of Kafka message delivery retries"
+    );
+    metrics::describe_gauge!(
+        "pesto_kafka_retry_queue_batches",
+        "Number of Kafka messages waiting to be retried"
+    );
+    metrics::describe_counter!(
+        "pesto_kafka_fallback_batches_total",
+        "Total number of undeliverable Kafka messages by fallback status (success, failure or dropped)"
+    );
+    metrics::describe_gauge!(
+        "pesto_kafka_spool_bytes",
+        "Size of the Kafka messages waiting in the spool, in bytes"
+    );
+    metrics::describe_gauge!(
+        "pesto_kafka_spool_segments",
+        "Number of spool segment files"
+    );
+    metrics::describe_counter!(
+        "pesto_kafka_spool_batches_total",
+        "Total number of spooled Kafka messages by status (spooled, replayed, dropped or failure)"
+    );
+    metrics::describe_counter!(
+        "pesto_kafka_spool_replayed_bytes_total",
+        "Total number of spool bytes replayed to Kafka"
+    );
+    metrics::describe_counter!(
+        "pesto_kafka_spool_quarantined_segments_total",
+        "Total number of spool segments set aside after an unreadable entry"
+    );
+    metrics::describe_histogram!(
+        "pesto_kafka_batch_records",
+        "Number of records per Kafka message by type (flow, counter, drop or netflow)"
+    );
+
+    // Filter metrics
+    metrics::describe_counter!(
+        "pesto_filtered_records_total",
+        "Total number of flow records dropped by the filter rules by rule and topic"
+    );
+    metrics::describe_counter!(
+        "pesto_enrichment_reloads_total",
+        "Total number of enrichment file reloads by source and status"
+    );
+
+    // Configuration metrics
+    metrics::describe_counter!(
+        "pesto_config_reloads_total",
+        "Total number of configuration reloads by status (success or failure)"
+    );
 
     // Statistics metrics
     metrics::describe_counter!(
@@ -132,14 +507,197 @@ fn set_metrics(metrics_address: SocketAddr) {
     );
     metrics::describe_counter!(
         "pesto_sflow_samples_received_total",
-        "Total number of sFlow samples received by type (flow or counter)"
+        "Total number of sFlow samples received by type (flow, counter or discarded)"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_headers_unsupported_total",
+        "Total number of sampled headers not decoded by header protocol"
     );
     metrics::describe_counter!(
         "pesto_sflow_records_total",
-        "Total number of sFlow records transmitted"
+        "Total number of records transmitted by type (flow, counter, drop or netflow)"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_worker_datagrams_total",
+        "Total number of sFlow datagrams received by listener and worker"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_worker_bytes_total",
+        "Total number of sFlow bytes received by listener and worker"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_socket_drops_total",
+        "Total number of sFlow datagrams dropped by the kernel on a full socket receive buffer"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_sequence_events_total",
+        "Total number of sFlow sequence gaps, duplicates, reorders and resets by agent and kind"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_sequence_missing_total",
+        "Total number of sFlow datagrams or samples missing from the sequence gaps by agent and kind"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_rejected_datagrams_total",
+        "Total number of sFlow datagrams rejected by the agent ACL or rate limit by reason"
+    );
+    metrics::describe_counter!(
+        "pesto_sflow_agent_address_mismatch_total",
+        "Total number of accepted sFlow datagrams whose agent address differs from the UDP source address by kind (unknown, private or public)"
+    );
+    metrics::describe_histogram!(
+        "pesto_sflow_receive_delay_seconds",
+        "Delay between the kernel receive timestamp and the sFlow worker, with kernel timestamps"
+    );
+    metrics::describe_counter!(
+        "pesto_netflow_packets_total",
+        "Total number of NetFlow and IPFIX packets received by version and status"
+    );
+    metrics::describe_counter!(
+        "pesto_netflow_records_received_total",
+        "Total number of NetFlow and IPFIX flow data records received"
+    );
+    metrics::describe_gauge!(
+        "pesto_netflow_templates",
+        "Number of NetFlow v9 and IPFIX templates cached"
+    );
+    metrics::describe_counter!(
+        "pesto_netflow_missing_template_total",
+        "Total number of NetFlow v9 and IPFIX data sets skipped for an unknown template"
+    );
+    metrics::describe_counter!(
+        "pesto_netflow_templates_dropped_total",
+        "Total number of NetFlow v9 and IPFIX templates dropped over the per-exporter limit"
+    );
+    metrics::describe_counter!(
+        "pesto_netflow_rejected_packets_total",
+        "Total number of NetFlow and IPFIX packets rejected by the exporter ACL by reason"
     );
 }
 
+// Path of the configuration file, needed before parsing the flags
+// since the file provides their defaults
+fn config_path() -> Option<PathBuf> {
+    let mut args = std::env::args_os().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--config" {
+            return args.next().map(PathBuf::from);
+        }
+        if let Some(path) = arg.to_str().and_then(|arg| arg.strip_prefix("--config=")) {
+            return Some(PathBuf::from(path));
+        }
+    }
+    std::env::var_os("PESTO_CONFIG").map(PathBuf::from)
+}
+
+// Settings of a configuration file, by argument ID
+fn read_config_file(path: &Path) -> Result<HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read configuration file {}", path.display()))?;
+
+    let value: Value = match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => toml::from_str(&content)?,
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&content)?,
+        _ => anyhow::bail!(
+            "Unsupported configuration file format: {} (expected .toml, .yaml or .yml)",
+            path.display()
+        ),
+    };
+    if !value.is_object() {
+        anyhow::bail!("Configuration file {} is not a table", path.display());
+    }
+
+    let mut settings = HashMap::new();
+    flatten_settings(&value, "", &mut settings)?;
+    Ok(settings)
+}
+
+// Join the sections and the keys into argument IDs, lists are comma-separated
+fn flatten_settings(
+    value: &Value,
+    prefix: &str,
+    settings: &mut HashMap<String, String>,
+) -> Result<()> {
+    match value {
+        Value::Object(table) => {
+            for (key, value) in table {
+                let key = key.replace('-', "_");
+                let id = match prefix {
+                    "" => key,
+                    _ => format!("{}_{}", prefix, key),
+                };
+                flatten_settings(value, &id, settings)?;
+            }
+        }
+        Value::Array(values) => {
+            let values = values
+                .iter()
+                .map(|value| setting_value(prefix, value))
+                .collect::<Result<Vec<_>>>()?;
+            settings.insert(prefix.to_string(), values.join(","));
+        }
+        Value::Null => {}
+        _ => {
+            settings.insert(prefix.to_string(), setting_value(prefix, value)?);
+        }
+    }
+    Ok(())
+}
+
+fn setting_value(id: &str, value: &Value) -> Result<String> {
+    match value {
+        Value::String(value) => Ok(value.clone()),
+        Value::Number(value) => Ok(value.to_string()),
+        Value::Bool(value) => Ok(value.to_string()),
+        _ => anyhow::bail!("Invalid value for setting {}: {}", id, value),
+    }
+}
+
+// Command line parser with the environment variables and the configuration file settings
+fn command(settings: HashMap<String, String>) -> Result<Command> {
+    let mut command = Cli::command().mut_args(|arg| {
+        if RESERVED_ARGS.contains(&arg.get_id().as_str()) {
+            return arg;
+        }
+        let env = format!("PESTO_{}", arg.get_id().as_str().to_uppercase());
+        arg.env(env)
+    });
+
+    // Configuration file settings replace the built-in defaults
+    for (id, value) in settings {
+        let known = command
+            .get_arguments()
+            .any(|arg| arg.get_id() == id.as_str());
+        if !known || id == "config" || RESERVED_ARGS.contains(&id.as_str()) {
+            anyhow::bail!("Unknown setting in configuration file: {}", id);
+        }
+        command = command.mut_arg(&id, |arg| arg.default_value(value));
+    }
+    Ok(command)
+}
+
+// Secret from its setting or from the file of its `_file` setting,
+// whichever comes from the layer with the highest precedence
+fn resolve_secret(
+    matches: &ArgMatches,
+    id: &str,
+    value: Option<Secret>,
+    file: Option<PathBuf>,
+) -> Result<Option<Secret>> {
+    let Some(file) = file else {
+        return Ok(value);
+    };
+    if value.is_some() && matches.value_source(id) > matches.value_source(&format!("{}_file", id)) {
+        return Ok(value);
+    }
+
+    let secret = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read secret file {}", file.display()))?;
+    Ok(Some(Secret(
+        secret.trim_end_matches(['\r', '\n']).to_string(),
+    )))
+}
+
 pub async fn resolve_address(address: String) -> Result<SocketAddr> {
     match lookup_host(&address).await?.next() {
         Some(addr) => Ok(addr),
@@ -147,47 +705,504 @@ pub async fn resolve_address(address: String) -> Result<SocketAddr> {
     }
 }
 
+// Prefix in CIDR notation, or a single address
+pub fn parse_prefix(prefix: &str) -> Result<IpNet> {
+    let prefix = prefix.trim();
+    if let Ok(addr) = prefix.parse::<IpAddr>() {
+        return Ok(IpNet::from(addr));
+    }
+    prefix
+        .parse::<IpNet>()
+        .map(|prefix| prefix.trunc())
+        .with_context(|| format!("Invalid prefix: {}", prefix))
+}
+
+// sFlow listener, `ADDRESS[;tag=TAG][;topic=TOPIC]`
+async fn parse_sflow_listener(listener: &str) -> Result<SFlowListener> {
+    let mut parts = listener.split(';');
+    let address = parts.next().unwrap_or_default().trim();
+    let mut tag = String::new();
+    let mut topic = None;
+    for option in parts {
+        match option.trim().split_once('=') {
+            Some(("tag", value)) => tag = value.to_string(),
+            Some(("topic", value)) if !value.is_empty() => topic = Some(value.to_string()),
+            _ => anyhow::bail!("Invalid sFlow listener option '{}' in {}", option, listener),
+        }
+    }
+
+    Ok(SFlowListener {
+        host: resolve_address(address.to_string()).await?,
+        tag,
+        topic,
+    })
+}
+
+// Command line, environment and configuration file settings
+fn parse_cli() -> Result<(Cli, ArgMatches)> {
+    let settings = match config_path() {
+        Some(path) => read_config_file(&path)?,
+        None => HashMap::new(),
+    };
+    let matches = command(settings)?.try_get_matches()?;
+    let cli = Cli::from_arg_matches(&matches)?;
+    Ok((cli, matches))
+}
+
+// The arguments read the PESTO_* environment variables when the command is
+// built, the tests setting one hold this lock until they restore it
+#[cfg(test)]
+static ENV_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+#[cfg(test)]
+fn test_command(settings: HashMap<String, String>) -> Result<Command> {
+    let _env = ENV_LOCK
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    command(settings)
+}
+
+/// Configuration of the given command line arguments, without the
+/// configuration file
+#[cfg(test)]
+pub async fn from_args(args: &[&str]) -> Result<AppConfig> {
+    let args = std::iter::once("pesto").chain(args.iter().copied());
+    let matches = test_command(HashMap::new())?.try_get_matches_from(args)?;
+    let cli = Cli::from_arg_matches(&matches)?;
+    app_config(cli, &matches, None).await
+}
+
 pub async fn configure() -> Result<AppConfig> {
-    let cli = Cli::parse();
+    let (cli, matches) = match parse_cli() {
+        Ok(parsed) => parsed,
+        // Help, version and invalid flags
+        Err(err) => match err.downcast::<clap::Error>() {
+            Ok(err) => err.exit(),
+            Err(err) => return Err(err),
+        },
+    };
 
     // Set up tracing
     set_logging(&cli).map_err(|e| anyhow::anyhow!("Failed to set up logging: {}", e))?;
 
-    // Resolve addresses
-    let (sflow_addr, metrics_addr) = tokio::try_join!(
-        resolve_address(cli.sflow_address),
-        resolve_address(cli.metrics_address)
-    )
-    .map_err(|e| anyhow::anyhow!("Failed during initial address resolution: {}", e))?;
-
-    let resolved_kafka_brokers = if !cli.kafka_disable {
-        let mut brokers = Vec::new();
-        for broker in cli.kafka_brokers {
-            brokers.push(resolve_address(broker).await?);
+    let cfg = app_config(cli, &matches, None).await?;
+
+    // Set up metrics
+    set_metrics(cfg.metrics_address);
+
+    Ok(cfg)
+}
+
+/// Read the configuration again, for a hot reload
+///
+/// Flags and environment variables are those of the process, so only the
+/// configuration file and the secrets files can change. The addresses are not
+/// resolved again, they keep those of the current configuration.
+pub async fn reload(current: &AppConfig) -> Result<AppConfig> {
+    let (cli, matches) = parse_cli()?;
+    app_config(cli, &matches, Some(current)).await
+}
+
+/// Check that a reloaded configuration only changes reloadable settings
+///
+/// Reloadable settings are the sFlow agent ACL, rate limit and address
+/// substitution, the NetFlow exporter ACL, the filter rules, the anonymization, and the Kafka topics,
+/// message key, message max bytes and batch wait time and interval. Everything
+/// else requires a restart.
+pub fn check_reload(current: &AppConfig, new: &AppConfig) -> Result<()> {
+    let mut changed = Vec::new();
+    macro_rules! check {
+        ($($field:ident).+) => {
+            if current.$($field).+ != new.$($field).+ {
+                changed.push(stringify!($($field).+));
+            }
+        };
+    }
+
+    check!(addresses.metrics);
+    check!(addresses.admin);
+    check!(addresses.sflow);
+    check!(sflow.workers);
+    check!(sflow.recv_batch_size);
+    check!(sflow.recv_buffer_bytes);
+    check!(sflow.kernel_timestamps);
+    check!(addresses.netflow);
+    check!(geoip.city_database);
+    check!(geoip.asn_database);
+    check!(metadata_file);
+    check!(kafka.disable);
+    check!(addresses.kafka_brokers);
+    check!(kafka.auth_protocol);
+    check!(kafka.auth_sasl_username);
+    check!(kafka.auth_sasl_password);
+    check!(kafka.auth_sasl_mechanism);
+    check!(kafka.auth_ssl_ca_location);
+    check!(kafka.auth_ssl_certificate_location);
+    check!(kafka.auth_ssl_key_location);
+    check!(kafka.auth_ssl_key_password);
+    check!(kafka.auth_ssl_verify_hostname);
+    check!(kafka.message_timeout_ms);
+    check!(kafka.mpsc_buffer_size);
+    check!(kafka.retry_max_attempts);
+    check!(kafka.retry_backoff_ms);
+    check!(kafka.retry_backoff_max_ms);
+    check!(kafka.retry_queue_size);
+    check!(kafka.fallback_dir);
+    check!(kafka.spool_dir);
+    check!(kafka.spool_max_bytes);
+    check!(kafka.spool_segment_bytes);
+    check!(kafka.spool_replay_window);
+
+    if !changed.is_empty() {
+        anyhow::bail!(
+            "Settings not reloadable, restart to apply them: {}",
+            changed.join(", ")
+        );
+    }
+    Ok(())
+}
+
+// Addresses resolved at startup
+struct Resolved {
+    metrics_address: SocketAddr,
+    admin_address: Option<SocketAddr>,
+    sflow_listeners: Vec<SFlowListener>,
+    netflow_hosts: Vec<SocketAddr>,
+    kafka_brokers: Vec<SocketAddr>,
+}
+
+async fn resolve_addresses(addresses: &Addresses, kafka_disable: bool) -> Result<Resolved> {
+    let metrics_address = resolve_address(addresses.metrics.clone())
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed during initial address resolution: {}", e))?;
+
+    let admin_address = match &addresses.admin {
+        Some(address) => Some(resolve_address(address.clone()).await?),
+        None => None,
+    };
+
+    let mut sflow_listeners = Vec::new();
+    for listener in &addresses.sflow {
+        sflow_listeners.push(parse_sflow_listener(listener).await?);
+    }
+
+    let mut netflow_hosts = Vec::new();
+    for address in &addresses.netflow {
+        netflow_hosts.push(resolve_address(address.clone()).await?);
+    }
+
+    let mut kafka_brokers = Vec::new();
+    if !kafka_disable {
+        for broker in &addresses.kafka_brokers {
+            kafka_brokers.push(resolve_address(broker.clone()).await?);
         }
-        brokers
-    } else {
-        Vec::new()
+    }
+
+    Ok(Resolved {
+        metrics_address,
+        admin_address,
+        sflow_listeners,
+        netflow_hosts,
+        kafka_brokers,
+    })
+}
+
+// The addresses of a reloaded configuration are those of the current one:
+// they are not reloadable, `check_reload` rejects the changed ones
+async fn app_config(
+    cli: Cli,
+    matches: &ArgMatches,
+    current: Option<&AppConfig>,
+) -> Result<AppConfig> {
+    // Read the secrets files
+    let auth_sasl_password = resolve_secret(
+        matches,
+        "kafka_auth_sasl_password",
+        Some(cli.kafka_auth_sasl_password.clone()),
+        cli.kafka_auth_sasl_password_file.clone(),
+    )?
+    .unwrap_or_default();
+    let auth_ssl_key_password = resolve_secret(
+        matches,
+        "kafka_auth_ssl_key_password",
+        cli.kafka_auth_ssl_key_password.clone(),
+        cli.kafka_auth_ssl_key_password_file.clone(),
+    )?;
+
+    if cli.sflow_workers == 0 {
+        anyhow::bail!("At least one sFlow worker is needed");
+    }
+    if cli.sflow_agent_rate_limit == Some(0) || cli.sflow_agent_rate_burst == Some(0) {
+        anyhow::bail!("The sFlow agent rate limit and burst must be positive");
+    }
+
+    // Resolve addresses
+    let addresses = Addresses {
+        metrics: cli.metrics_address,
+        admin: cli.admin_address,
+        sflow: cli.sflow_address,
+        netflow: cli.netflow_address,
+        kafka_brokers: cli.kafka_brokers,
+    };
+    let resolved = match current {
+        Some(current) => Resolved {
+            metrics_address: current.metrics_address,
+            admin_address: current.admin_address,
+            sflow_listeners: current.sflow.listeners.clone(),
+            netflow_hosts: current.netflow.hosts.clone(),
+            kafka_brokers: current.kafka.brokers.clone(),
+        },
+        None => resolve_addresses(&addresses, cli.kafka_disable).await?,
     };
 
-    // Set up metrics
-    set_metrics(metrics_addr);
+    let anonymizer = match &cli.anonymize_key_file {
+        Some(path) => Some(Anonymizer::from_key_file(
+            path,
+            cli.anonymize_agent,
+            cli.anonymize_exempt,
+        )?),
+        None => None,
+    };
 
     Ok(AppConfig {
-        sflow: SFlowConfig { host: sflow_addr },
+        addresses,
+        metrics_address: resolved.metrics_address,
+        admin_address: resolved.admin_address,
+        sflow: SFlowConfig {
+            listeners: resolved.sflow_listeners,
+            workers: cli.sflow_workers,
+            recv_batch_size: cli.sflow_recv_batch_size,
+            recv_buffer_bytes: cli.sflow_recv_buffer_bytes,
+            kernel_timestamps: cli.sflow_kernel_timestamps,
+            allow: cli.sflow_allow,
+            deny: cli.sflow_deny,
+            agent_rate_limit: cli.sflow_agent_rate_limit,
+            agent_rate_burst: cli.sflow_agent_rate_burst,
+            substitute_agent_address: cli.sflow_substitute_agent_address,
+        },
+        netflow: NetFlowConfig {
+            hosts: resolved.netflow_hosts,
+            allow: cli.netflow_allow,
+            deny: cli.netflow_deny,
+        },
+        filters: cli.filter,
+        anonymizer,
+        geoip: GeoIpConfig {
+            city_database: cli.geoip_city_database,
+            asn_database: cli.geoip_asn_database,
+        },
+        metadata_file: cli.metadata_file,
         kafka: KafkaConfig {
             disable: cli.kafka_disable,
-            brokers: resolved_kafka_brokers,
+            brokers: resolved.kafka_brokers,
             topic: cli.kafka_topic,
+            counters_topic: cli.kafka_counters_topic,
+            drops_topic: cli.kafka_drops_topic,
+            netflow_topic: cli.kafka_netflow_topic,
+            key: cli.kafka_key,
             auth_protocol: cli.kafka_auth_protocol,
             auth_sasl_username: cli.kafka_auth_sasl_username,
-            auth_sasl_password: cli.kafka_auth_sasl_password,
+            auth_sasl_password,
             auth_sasl_mechanism: cli.kafka_auth_sasl_mechanism,
+            auth_ssl_ca_location: cli.kafka_auth_ssl_ca_location,
+            auth_ssl_certificate_location: cli.kafka_auth_ssl_certificate_location,
+            auth_ssl_key_location: cli.kafka_auth_ssl_key_location,
+            auth_ssl_key_password,
+            auth_ssl_verify_hostname: !cli.kafka_auth_ssl_disable_hostname_verification,
             message_max_bytes: cli.kafka_message_max_bytes,
             message_timeout_ms: cli.kafka_message_timeout_ms,
             batch_wait_time: cli.kafka_batch_wait_time,
             batch_wait_interval: cli.kafka_batch_wait_interval,
             mpsc_buffer_size: cli.kafka_mpsc_buffer_size,
+            retry_max_attempts: cli.kafka_retry_max_attempts,
+            retry_backoff_ms: cli.kafka_retry_backoff_ms,
+            retry_backoff_max_ms: cli.kafka_retry_backoff_max_ms,
+            retry_queue_size: cli.kafka_retry_queue_size,
+            fallback_dir: cli.kafka_fallback_dir,
+            spool_dir: cli.kafka_spool_dir,
+            spool_max_bytes: cli.kafka_spool_max_bytes,
+            spool_segment_bytes: cli.kafka_spool_segment_bytes,
+            spool_replay_window: cli.kafka_spool_replay_window,
         },
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn settings(settings: &[(&str, &str)]) -> HashMap<String, String> {
+        settings
+            .iter()
+            .map(|(id, value)| (id.to_string(), value.to_string()))
+            .collect()
+    }
+
+    fn parse_with(command: Command, args: &[&str]) -> Result<(Cli, ArgMatches)> {
+        let args = std::iter::once("pesto").chain(args.iter().copied());
+        let matches = command.try_get_matches_from(args)?;
+        let cli = Cli::from_arg_matches(&matches)?;
+        Ok((cli, matches))
+    }
+
+    fn parse(settings: &[(&str, &str)], args: &[&str]) -> Result<(Cli, ArgMatches)> {
+        parse_with(test_command(self::settings(settings))?, args)
+    }
+
+    #[test]
+    fn flatten() {
+        let value = json!({
+            "metrics_address": "0.0.0.0:8080",
+            "sflow": {
+                "address": ["0.0.0.0:6343;tag=v4", "[::]:6343;tag=v6"],
+                "agent-rate-limit": 500,
+                "substitute_agent_address": true,
+            },
+            "kafka": {"auth": {"protocol": "SSL"}, "topic": null},
+        });
+        let mut settings = HashMap::new();
+        flatten_settings(&value, "", &mut settings).unwrap();
+
+        assert_eq!(settings["metrics_address"], "0.0.0.0:8080");
+        assert_eq!(
+            settings["sflow_address"],
+            "0.0.0.0:6343;tag=v4,[::]:6343;tag=v6"
+        );
+        assert_eq!(settings["sflow_agent_rate_limit"], "500");
+        assert_eq!(settings["sflow_substitute_agent_address"], "true");
+        assert_eq!(settings["kafka_auth_protocol"], "SSL");
+        assert!(!settings.contains_key("kafka_topic"));
+
+        let nested = json!({"sflow": {"address": [{"host": "0.0.0.0"}]}});
+        assert!(flatten_settings(&nested, "", &mut HashMap::new()).is_err());
+    }
+
+    #[test]
+    fn unknown_settings() {
+        assert!(parse(&[("kafka_brokers", "broker1:9092")], &[]).is_ok());
+        for id in ["kafka_broker", "config", "help", "verbose"] {
+            let err = parse(&[(id, "value")], &[]).unwrap_err();
+            assert!(err.to_string().contains("Unknown setting"), "{}", id);
+        }
+    }
+
+    #[test]
+    fn precedence() {
+        let settings = settings(&[
+            ("kafka_drops_topic", "file-drops"),
+            ("kafka_counters_topic", "file-counters"),
+            ("sflow_allow", "10.0.0.0/8,2001:db8::/32"),
+        ]);
+
+        // Set the variable only while the command reads it, no other test
+        // builds one meanwhile
+        let command = {
+            let _env = ENV_LOCK
+                .lock()
+                .unwrap_or_else(std::sync::PoisonError::into_inner);
+            let name = "PESTO_KAFKA_DROPS_TOPIC";
+            let previous = std::env::var_os(name);
+            std::env::set_var(name, "env-drops");
+            let command = command(settings);
+            match previous {
+                Some(value) => std::env::set_var(name, value),
+                None => std::env::remove_var(name),
+            }
+            command.unwrap()
+        };
+
+        let (cli, _) = parse_with(command.clone(), &[]).unwrap();
+        assert_eq!(cli.kafka_counters_topic, "file-counters");
+        assert_eq!(cli.kafka_drops_topic, "env-drops");
+        assert_eq!(cli.sflow_allow.len(), 2);
+
+        let (cli, _) = parse_with(command, &["--kafka-drops-topic", "flag-drops"]).unwrap();
+        assert_eq!(cli.kafka_drops_topic, "flag-drops");
+    }
+
+    #[test]
+    fn secret_files() {
+        let path = std::env::temp_dir().join(format!("pesto-config-test-{}", std::process::id()));
+        std::fs::write(&path, "from-file\n").unwrap();
+        let file = path.to_str().unwrap();
+        let resolve = |settings: &[(&str, &str)], args: &[&str]| {
+            let (cli, matches) = parse(settings, args).unwrap();
+            resolve_secret(
+                &matches,
+                "kafka_auth_sasl_password",
+                Some(cli.kafka_auth_sasl_password),
+                cli.kafka_auth_sasl_password_file,
+            )
+            .unwrap()
+            .map(|secret| secret.expose().to_string())
+        };
+        let password_file = ("kafka_auth_sasl_password_file", file);
+
+        // The file wins over the default and the same layer, a higher layer wins over the file
+        assert_eq!(resolve(&[password_file], &[]).as_deref(), Some("from-file"));
+        assert_eq!(
+            resolve(
+                &[password_file, ("kafka_auth_sasl_password", "from-config")],
+                &[]
+            )
+            .as_deref(),
+            Some("from-file")
+        );
+        assert_eq!(
+            resolve(
+                &[password_file],
+                &["--kafka-auth-sasl-password", "from-flag"]
+            )
+            .as_deref(),
+            Some("from-flag")
+        );
+        assert_eq!(
+            resolve(
+                &[("kafka_auth_sasl_password", "from-config")],
+                &["--kafka-auth-sasl-password-file", file]
+            )
+            .as_deref(),
+            Some("from-file")
+        );
+        assert_eq!(resolve(&[], &[]).as_deref(), Some("pesto"));
+        let _ = std::fs::remove_file(&path);
+    }
+
+    async fn reloaded(
+        current: &AppConfig,
+        settings: &[(&str, &str)],
+        args: &[&str],
+    ) -> Result<AppConfig> {
+        let (cli, matches) = parse(settings, args)?;
+        app_config(cli, &matches, Some(current)).await
+    }
+
+    #[tokio::test]
+    async fn reload_addresses() {
+        let current = from_args(&["--kafka-disable"]).await.unwrap();
+
+        let new = reloaded(
+            &current,
+            &[("kafka_topic", "reloaded")],
+            &["--kafka-disable"],
+        )
+        .await
+        .unwrap();
+        assert!(check_reload(&current, &new).is_ok());
+        assert_eq!(new.kafka.topic, "reloaded");
+
+        // Changed addresses are compared as configured, without resolving them
+        let settings = [
+            ("sflow_address", "collector.invalid:6343"),
+            ("kafka_brokers", "broker.invalid:9092"),
+        ];
+        let new = reloaded(&current, &settings, &["--kafka-disable"])
+            .await
+            .unwrap();
+        assert_eq!(new.sflow.listeners, current.sflow.listeners);
+        let err = check_reload(&current, &new).unwrap_err().to_string();
+        assert!(err.contains("addresses.sflow"), "{}", err);
+        assert!(err.contains("addresses.kafka_brokers"), "{}", err);
+    }
+}
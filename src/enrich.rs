@@ -0,0 +1,160 @@
+use anyhow::Result;
+use metrics::counter;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::config::AppConfig;
+use crate::geoip::{self, GeoIp};
+use crate::metadata::Metadata;
+
+// How often the enrichment files are checked for changes
+const RELOAD_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Data added to the flow records, from files reloaded when they change
+#[derive(Clone, Default)]
+pub struct Enrichment {
+    pub geoip: GeoIp,
+    pub metadata: Arc<Metadata>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Source {
+    GeoIpCity,
+    GeoIpAsn,
+    Metadata,
+}
+
+impl Source {
+    fn label(self) -> &'static str {
+        match self {
+            Source::GeoIpCity => "geoip_city",
+            Source::GeoIpAsn => "geoip_asn",
+            Source::Metadata => "metadata",
+        }
+    }
+}
+
+// Enrichment files of the configuration
+fn sources(config: &AppConfig) -> Vec<(Source, PathBuf)> {
+    let mut sources = Vec::new();
+    if let Some(path) = &config.geoip.city_database {
+        sources.push((Source::GeoIpCity, path.clone()));
+    }
+    if let Some(path) = &config.geoip.asn_database {
+        sources.push((Source::GeoIpAsn, path.clone()));
+    }
+    if let Some(path) = &config.metadata_file {
+        sources.push((Source::Metadata, path.clone()));
+    }
+    sources
+}
+
+fn load_source(enrichment: &mut Enrichment, source: Source, path: &Path) -> Result<()> {
+    match source {
+        Source::GeoIpCity => enrichment.geoip.city = Some(geoip::open(path, &["City", "Country"])?),
+        Source::GeoIpAsn => enrichment.geoip.asn = Some(geoip::open(path, &["ASN"])?),
+        Source::Metadata => enrichment.metadata = Arc::new(Metadata::from_file(path)?),
+    }
+    Ok(())
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|meta| meta.modified())
+        .ok()
+}
+
+/// Read the enrichment files, at startup
+pub fn load(config: &AppConfig) -> Result<Enrichment> {
+    let mut enrichment = Enrichment::default();
+    for (source, path) in sources(config) {
+        load_source(&mut enrichment, source, &path)?;
+        debug!("loaded {} from {}", source.label(), path.display());
+    }
+    Ok(enrichment)
+}
+
+// Replace the enrichment of a file, unless it fails to load
+//
+// The file is read and parsed on a blocking thread, a GeoIP database takes
+// tens of megabytes.
+async fn reload(tx: &watch::Sender<Arc<Enrichment>>, source: Source, path: &Path) -> Result<()> {
+    let mut enrichment = Enrichment::clone(&tx.borrow());
+    let path = path.to_path_buf();
+    let enrichment = tokio::task::spawn_blocking(move || {
+        load_source(&mut enrichment, source, &path)?;
+        Ok::<_, anyhow::Error>(enrichment)
+    })
+    .await??;
+    tx.send_replace(Arc::new(enrichment));
+    Ok(())
+}
+
+/// Reload the enrichment files when they change on disk
+///
+/// A file that fails to load is retried on the next check, the running tasks
+/// keep its previous content in the meantime.
+pub async fn handle(config: Arc<AppConfig>, tx: watch::Sender<Arc<Enrichment>>) -> Result<()> {
+    let sources = sources(&config);
+    let mut modified_times: Vec<_> = sources.iter().map(|(_, path)| modified(path)).collect();
+
+    let mut interval = tokio::time::interval(RELOAD_INTERVAL);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        for ((source, path), modified_time) in sources.iter().zip(modified_times.iter_mut()) {
+            let current = modified(path);
+            if current == *modified_time {
+                continue;
+            }
+
+            let metric_name = "pesto_enrichment_reloads_total";
+            match reload(&tx, *source, path).await {
+                Ok(()) => {
+                    *modified_time = current;
+                    counter!(metric_name, "source" => source.label(), "status" => "success")
+                        .increment(1);
+                    info!("reloaded {} from {}", source.label(), path.display());
+                }
+                Err(err) => {
+                    counter!(metric_name, "source" => source.label(), "status" => "failure")
+                        .increment(1);
+                    error!("{} not reloaded: {:#}", source.label(), err);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn invalid_reload() {
+        let path =
+            std::env::temp_dir().join(format!("pesto-enrich-test-{}.yaml", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"{"agents": [{"address": "192.0.2.1", "hostname": "edge1"}]}"#,
+        )
+        .unwrap();
+        let (tx, rx) = watch::channel(Arc::new(Enrichment::default()));
+        reload(&tx, Source::Metadata, &path).await.unwrap();
+        let agent = Some("192.0.2.1".parse().unwrap());
+        assert_eq!(rx.borrow().metadata.agent(agent).unwrap().hostname, "edge1");
+
+        // The previous metadata stays in use
+        std::fs::write(
+            &path,
+            r#"{"agents": [{"address": "192.0.2.1", "host": "edge2"}]}"#,
+        )
+        .unwrap();
+        assert!(reload(&tx, Source::Metadata, &path).await.is_err());
+        assert_eq!(rx.borrow().metadata.agent(agent).unwrap().hostname, "edge1");
+        let _ = std::fs::remove_file(&path);
+    }
+}
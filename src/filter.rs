@@ -0,0 +1,531 @@
+use anyhow::Result;
+use ipnet::IpNet;
+use metrics::counter;
+use std::fmt;
+use std::net::IpAddr;
+
+use crate::config::parse_prefix;
+
+// Filter rules drop the flow records matching their expression, before they
+// are sent to Kafka. A rule is `NAME=EXPRESSION[;topic=TOPIC]`, and only
+// applies to the flow records of TOPIC if set.
+//
+// Expressions compare the fields of the flow records, and are combined with
+// `and`, `or`, `not` and parentheses:
+//
+//   src in 10.0.0.0/8 and dst_port == 22
+//   vlan in 100-199 or sampling_rate == 0
+//   protocol == udp and not (port == 53 or port == 123)
+//
+// Address fields (src, dst, agent) take a prefix or an address with `in`,
+// `==` and `!=`. Numeric fields take a number with `==`, `!=`, `<`, `<=`,
+// `>` and `>=`, or a range with `in`. `port` and `interface` match if either
+// the source or the destination side matches.
+
+/// Fields of a flow record that filter expressions can match
+#[derive(Debug, Clone, Copy)]
+pub struct FlowFields {
+    pub agent: Option<IpAddr>,
+    pub src: IpAddr,
+    pub dst: IpAddr,
+    pub src_port: u32,
+    pub dst_port: u32,
+    pub protocol: u32,
+    pub input_interface: u32,
+    pub output_interface: u32,
+    pub vlan: u32,
+    pub sampling_rate: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AddrField {
+    Src,
+    Dst,
+    Agent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NumField {
+    SrcPort,
+    DstPort,
+    Port,
+    Protocol,
+    InputInterface,
+    OutputInterface,
+    Interface,
+    Vlan,
+    SamplingRate,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cmp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    In,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Not(Box<Expr>),
+    Addr {
+        field: AddrField,
+        negate: bool,
+        prefix: IpNet,
+    },
+    Num {
+        field: NumField,
+        cmp: Cmp,
+        low: u32,
+        high: u32,
+    },
+}
+
+impl Expr {
+    fn matches(&self, fields: &FlowFields) -> bool {
+        match self {
+            Expr::And(left, right) => left.matches(fields) && right.matches(fields),
+            Expr::Or(left, right) => left.matches(fields) || right.matches(fields),
+            Expr::Not(expr) => !expr.matches(fields),
+            Expr::Addr {
+                field,
+                negate,
+                prefix,
+            } => {
+                let addr = match field {
+                    AddrField::Src => Some(fields.src),
+                    AddrField::Dst => Some(fields.dst),
+                    AddrField::Agent => fields.agent,
+                };
+                // Unknown agents are in no prefix
+                let contained = addr.is_some_and(|addr| prefix.contains(&addr.to_canonical()));
+                contained != *negate
+            }
+            Expr::Num {
+                field,
+                cmp,
+                low,
+                high,
+            } => {
+                let compare = |value: u32| match cmp {
+                    Cmp::Eq => value == *low,
+                    Cmp::Ne => value != *low,
+                    Cmp::Lt => value < *low,
+                    Cmp::Le => value <= *low,
+                    Cmp::Gt => value > *low,
+                    Cmp::Ge => value >= *low,
+                    Cmp::In => (*low..=*high).contains(&value),
+                };
+                match field {
+                    NumField::SrcPort => compare(fields.src_port),
+                    NumField::DstPort => compare(fields.dst_port),
+                    NumField::Port => compare(fields.src_port) || compare(fields.dst_port),
+                    NumField::Protocol => compare(fields.protocol),
+                    NumField::InputInterface => compare(fields.input_interface),
+                    NumField::OutputInterface => compare(fields.output_interface),
+                    NumField::Interface => {
+                        compare(fields.input_interface) || compare(fields.output_interface)
+                    }
+                    NumField::Vlan => compare(fields.vlan),
+                    NumField::SamplingRate => compare(fields.sampling_rate),
+                }
+            }
+        }
+    }
+}
+
+// Recursive descent parser, `or` binds looser than `and`, which binds looser than `not`
+struct Parser<'a> {
+    tokens: Vec<&'a str>,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(expression: &'a str) -> Self {
+        Parser {
+            tokens: tokenize(expression),
+            position: 0,
+        }
+    }
+
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.position).copied()
+    }
+
+    fn next(&mut self) -> Result<&'a str> {
+        let token = self
+            .peek()
+            .ok_or_else(|| anyhow::anyhow!("unexpected end of expression"))?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn parse(mut self) -> Result<Expr> {
+        let expr = self.or()?;
+        if let Some(token) = self.peek() {
+            anyhow::bail!("unexpected '{}'", token);
+        }
+        Ok(expr)
+    }
+
+    fn or(&mut self) -> Result<Expr> {
+        let mut expr = self.and()?;
+        while self.peek() == Some("or") {
+            self.position += 1;
+            expr = Expr::Or(Box::new(expr), Box::new(self.and()?));
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr> {
+        let mut expr = self.not()?;
+        while self.peek() == Some("and") {
+            self.position += 1;
+            expr = Expr::And(Box::new(expr), Box::new(self.not()?));
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr> {
+        if self.peek() == Some("not") {
+            self.position += 1;
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr> {
+        if self.peek() == Some("(") {
+            self.position += 1;
+            let expr = self.or()?;
+            match self.next()? {
+                ")" => return Ok(expr),
+                token => anyhow::bail!("expected ')', found '{}'", token),
+            }
+        }
+        self.condition()
+    }
+
+    fn condition(&mut self) -> Result<Expr> {
+        let field = self.next()?;
+        let cmp = match self.next()? {
+            "==" => Cmp::Eq,
+            "!=" => Cmp::Ne,
+            "<" => Cmp::Lt,
+            "<=" => Cmp::Le,
+            ">" => Cmp::Gt,
+            ">=" => Cmp::Ge,
+            "in" => Cmp::In,
+            token => anyhow::bail!("expected a comparison after '{}', found '{}'", field, token),
+        };
+        let value = self.next()?;
+
+        let addr_field = match field {
+            "src" => Some(AddrField::Src),
+            "dst" => Some(AddrField::Dst),
+            "agent" => Some(AddrField::Agent),
+            _ => None,
+        };
+        if let Some(field) = addr_field {
+            let negate = match cmp {
+                Cmp::Eq | Cmp::In => false,
+                Cmp::Ne => true,
+                _ => anyhow::bail!("address fields only take 'in', '==' and '!='"),
+            };
+            let prefix = parse_prefix(value)?;
+            return Ok(Expr::Addr {
+                field,
+                negate,
+                prefix,
+            });
+        }
+
+        let field = match field {
+            "src_port" => NumField::SrcPort,
+            "dst_port" => NumField::DstPort,
+            "port" => NumField::Port,
+            "protocol" => NumField::Protocol,
+            "in_if" => NumField::InputInterface,
+            "out_if" => NumField::OutputInterface,
+            "interface" => NumField::Interface,
+            "vlan" => NumField::Vlan,
+            "sampling_rate" => NumField::SamplingRate,
+            _ => anyhow::bail!("unknown field '{}'", field),
+        };
+        let (low, high) = match (cmp, value.split_once('-')) {
+            (Cmp::In, Some((low, high))) => (parse_number(field, low)?, parse_number(field, high)?),
+            (Cmp::In, None) => anyhow::bail!("expected a range LOW-HIGH, found '{}'", value),
+            (_, _) => {
+                let value = parse_number(field, value)?;
+                (value, value)
+            }
+        };
+        if low > high {
+            anyhow::bail!("empty range '{}'", value);
+        }
+        Ok(Expr::Num {
+            field,
+            cmp,
+            low,
+            high,
+        })
+    }
+}
+
+fn parse_number(field: NumField, value: &str) -> Result<u32> {
+    if field == NumField::Protocol {
+        let protocol = match value {
+            "icmp" => Some(1),
+            "tcp" => Some(6),
+            "udp" => Some(17),
+            "gre" => Some(47),
+            "esp" => Some(50),
+            "icmpv6" => Some(58),
+            "sctp" => Some(132),
+            _ => None,
+        };
+        if let Some(protocol) = protocol {
+            return Ok(protocol);
+        }
+    }
+    value
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid number '{}'", value))
+}
+
+// Words, parentheses and comparison operators
+fn tokenize(expression: &str) -> Vec<&str> {
+    let is_operator = |c: char| matches!(c, '=' | '!' | '<' | '>');
+    let mut tokens = Vec::new();
+    let mut rest = expression.trim_start();
+    while let Some(c) = rest.chars().next() {
+        let end = if c == '(' || c == ')' {
+            1
+        } else if is_operator(c) {
+            rest.find(|c| !is_operator(c)).unwrap_or(rest.len())
+        } else {
+            rest.find(|c: char| c.is_whitespace() || c == '(' || c == ')' || is_operator(c))
+                .unwrap_or(rest.len())
+        };
+        tokens.push(&rest[..end]);
+        rest = rest[end..].trim_start();
+    }
+    tokens
+}
+
+/// Named filter rule, dropping the flow records matching its expression
+#[derive(Clone, PartialEq)]
+pub struct Rule {
+    pub name: String,
+    // Only filters the flow records of this topic if set
+    pub topic: Option<String>,
+    expression: String,
+    expr: Expr,
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rule")
+            .field("name", &self.name)
+            .field("topic", &self.topic)
+            .field("expression", &self.expression)
+            .finish()
+    }
+}
+
+/// Filter rule, `NAME=EXPRESSION[;topic=TOPIC]`
+pub fn parse_rule(rule: &str) -> Result<Rule> {
+    let mut parts = rule.split(';');
+    let (name, expression) = parts
+        .next()
+        .unwrap_or_default()
+        .split_once('=')
+        .ok_or_else(|| anyhow::anyhow!("Invalid filter rule {}: expected NAME=EXPRESSION", rule))?;
+    let name = name.trim();
+    if name.is_empty() {
+        anyhow::bail!("Invalid filter rule {}: empty name", rule);
+    }
+
+    let mut topic = None;
+    for option in parts {
+        match option.trim().split_once('=') {
+            Some(("topic", value)) if !value.is_empty() => topic = Some(value.to_string()),
+            _ => anyhow::bail!("Invalid filter rule option '{}' in {}", option, rule),
+        }
+    }
+
+    let expr = Parser::new(expression)
+        .parse()
+        .map_err(|e| anyhow::anyhow!("Invalid filter rule {}: {}", name, e))?;
+    Ok(Rule {
+        name: name.to_string(),
+        topic,
+        expression: expression.trim().to_string(),
+        expr,
+    })
+}
+
+/// Filter rules of a Kafka topic
+#[derive(Clone, Copy)]
+pub struct Filter<'a> {
+    rules: &'a [Rule],
+    topic: &'a str,
+}
+
+impl<'a> Filter<'a> {
+    pub fn new(rules: &'a [Rule], topic: &'a str) -> Self {
+        Filter { rules, topic }
+    }
+
+    /// Whether a rule drops the flow record, counted under the first matching rule
+    pub fn drops(&self, fields: &FlowFields) -> bool {
+        let rule = self.rules.iter().find(|rule| {
+            rule.topic
+                .as_deref()
+                .is_none_or(|topic| topic == self.topic)
+                && rule.expr.matches(fields)
+        });
+        match rule {
+            Some(rule) => {
+                counter!(
+                    "pesto_filtered_records_total",
+                    "rule" => rule.name.clone(),
+                    "topic" => self.topic.to_string()
+                )
+                .increment(1);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // UDP DNS query from 10.1.1.1 to 192.0.2.53, VLAN 150
+    fn fields() -> FlowFields {
+        FlowFields {
+            agent: Some("192.0.2.1".parse().unwrap()),
+            src: "10.1.1.1".parse().unwrap(),
+            dst: "::ffff:192.0.2.53".parse().unwrap(),
+            src_port: 40000,
+            dst_port: 53,
+            protocol: 17,
+            input_interface: 1,
+            output_interface: 2,
+            vlan: 150,
+            sampling_rate: 1000,
+        }
+    }
+
+    fn matches(expression: &str) -> bool {
+        parse_rule(&format!("test={}", expression))
+            .unwrap()
+            .expr
+            .matches(&fields())
+    }
+
+    #[test]
+    fn parse_rules() {
+        let rule = parse_rule("dns = protocol == udp and port == 53 ;topic=pesto-sflow").unwrap();
+        assert_eq!(rule.name, "dns");
+        assert_eq!(rule.topic.as_deref(), Some("pesto-sflow"));
+        assert_eq!(rule.expression, "protocol == udp and port == 53");
+
+        let rule = parse_rule("backup=vlan in 100-199").unwrap();
+        assert_eq!(rule.topic, None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        for rule in [
+            "no expression",
+            "=port == 53",
+            "unknown=foo == 1",
+            "cmp=port ~ 53",
+            "value=port ==",
+            "number=port == http",
+            "range=port in 53",
+            "empty=port in 100-10",
+            "addr=src < 10.0.0.0/8",
+            "prefix=src in 10.0.0.0/33",
+            "paren=(port == 53",
+            "trailing=port == 53 port",
+            "option=port == 53;partition=1",
+            "topic=port == 53;topic=",
+        ] {
+            assert!(parse_rule(rule).is_err(), "{} should not parse", rule);
+        }
+    }
+
+    #[test]
+    fn precedence() {
+        // `and` binds tighter than `or`, `not` tighter than `and`
+        let or_and = parse_rule("a=port == 1 or port == 2 and port == 3").unwrap();
+        let explicit = parse_rule("b=port == 1 or (port == 2 and port == 3)").unwrap();
+        assert_eq!(or_and.expr, explicit.expr);
+
+        let not_and = parse_rule("a=not port == 1 and port == 2").unwrap();
+        let explicit = parse_rule("b=(not port == 1) and port == 2").unwrap();
+        assert_eq!(not_and.expr, explicit.expr);
+
+        assert!(matches("vlan == 1 or vlan == 150 and port == 53"));
+        assert!(!matches("(vlan == 1 or vlan == 150) and port == 54"));
+        assert!(matches(
+            "not protocol == tcp and not (port == 22 or port == 443)"
+        ));
+    }
+
+    #[test]
+    fn addresses() {
+        assert!(matches("src in 10.0.0.0/8"));
+        assert!(matches("src == 10.1.1.1"));
+        assert!(matches("src != 10.1.1.2"));
+        // IPv4-mapped addresses match IPv4 prefixes
+        assert!(matches("dst in 192.0.2.0/24"));
+        assert!(matches("agent == 192.0.2.1"));
+        assert!(!matches("dst in 2001:db8::/32"));
+    }
+
+    #[test]
+    fn numbers() {
+        assert!(matches("protocol == udp"));
+        assert!(matches("protocol == 17"));
+        assert!(matches("dst_port == 53 and src_port >= 1024"));
+        assert!(matches("port in 50-60"));
+        assert!(matches(
+            "src_port > 39999 and src_port < 40001 and src_port <= 40000"
+        ));
+        assert!(matches("in_if == 1 and out_if == 2 and interface == 2"));
+        assert!(matches("vlan in 100-199 and sampling_rate != 0"));
+        assert!(!matches("dst_port in 1024-65535"));
+    }
+
+    #[test]
+    fn unknown_agent() {
+        let rule = parse_rule("agent=agent in 0.0.0.0/0").unwrap();
+        let fields = FlowFields {
+            agent: None,
+            ..fields()
+        };
+        assert!(!rule.expr.matches(&fields));
+    }
+
+    #[test]
+    fn topic_scope() {
+        let rules = vec![
+            parse_rule("all=port == 22").unwrap(),
+            parse_rule("dns=port == 53;topic=pesto-sflow-blue").unwrap(),
+        ];
+        assert!(Filter::new(&rules, "pesto-sflow-blue").drops(&fields()));
+        assert!(!Filter::new(&rules, "pesto-sflow").drops(&fields()));
+        assert!(!Filter::new(&[], "pesto-sflow-blue").drops(&fields()));
+    }
+}
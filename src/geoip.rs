@@ -0,0 +1,104 @@
+use anyhow::{Context, Result};
+use maxminddb::{geoip2, Reader};
+use serde::Deserialize;
+use std::net::IpAddr;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Country, city and AS of an address, empty or 0 if unknown
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GeoInfo<'a> {
+    pub country: &'a str,
+    pub city: &'a str,
+    pub asn: u32,
+    pub as_org: &'a str,
+}
+
+// Fields of a City or Country database record used by pesto, borrowed from the
+// database: the other names and records are skipped rather than decoded
+#[derive(Deserialize)]
+struct CityRecord<'a> {
+    #[serde(borrow)]
+    country: Option<CountryRecord<'a>>,
+    #[serde(borrow)]
+    city: Option<CityNames<'a>>,
+}
+
+#[derive(Deserialize)]
+struct CountryRecord<'a> {
+    iso_code: Option<&'a str>,
+}
+
+#[derive(Deserialize)]
+struct CityNames<'a> {
+    #[serde(borrow)]
+    names: Option<Names<'a>>,
+}
+
+#[derive(Deserialize)]
+struct Names<'a> {
+    en: Option<&'a str>,
+}
+
+/// MaxMind databases (GeoIP2 or GeoLite2), read in memory
+///
+/// The country and the city come from a City database, a Country database
+/// only gives the country. The AS comes from an ASN database.
+#[derive(Clone, Default)]
+pub struct GeoIp {
+    pub city: Option<Arc<Reader<Vec<u8>>>>,
+    pub asn: Option<Arc<Reader<Vec<u8>>>>,
+}
+
+impl GeoIp {
+    pub fn lookup(&self, ip: IpAddr) -> GeoInfo<'_> {
+        // The databases hold IPv4 networks as IPv4, not IPv4-mapped
+        let ip = ip.to_canonical();
+        let mut info = GeoInfo::default();
+
+        let city = self
+            .city
+            .as_ref()
+            .and_then(|reader| reader.lookup::<CityRecord>(ip).ok());
+        if let Some(city) = city {
+            info.country = city
+                .country
+                .and_then(|country| country.iso_code)
+                .unwrap_or_default();
+            info.city = city
+                .city
+                .and_then(|city| city.names)
+                .and_then(|names| names.en)
+                .unwrap_or_default();
+        }
+
+        let asn = self
+            .asn
+            .as_ref()
+            .and_then(|reader| reader.lookup::<geoip2::Asn>(ip).ok());
+        if let Some(asn) = asn {
+            info.asn = asn.autonomous_system_number.unwrap_or_default();
+            info.as_org = asn.autonomous_system_organization.unwrap_or_default();
+        }
+        info
+    }
+}
+
+/// Read a database, its type must contain one of the expected ones
+pub fn open(path: &Path, expected_types: &[&str]) -> Result<Arc<Reader<Vec<u8>>>> {
+    let reader = Reader::open_readfile(path)
+        .with_context(|| format!("Failed to read GeoIP database {}", path.display()))?;
+    let database_type = &reader.metadata.database_type;
+    if !expected_types
+        .iter()
+        .any(|expected| database_type.contains(expected))
+    {
+        anyhow::bail!(
+            "Unexpected GeoIP database type {} in {}, expected {}",
+            database_type,
+            path.display(),
+            expected_types.join(" or ")
+        );
+    }
+    Ok(Arc::new(reader))
+}
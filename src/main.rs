@@ -1,48 +1,105 @@
+mod acl;
+mod anonymize;
+mod batch;
 mod config;
+mod enrich;
+mod filter;
+mod geoip;
+mod metadata;
+mod netflow;
 mod producer;
+mod reload;
+mod retry;
+mod sequence;
 mod serializer;
 mod sflow;
 mod sflow_capnp;
+mod socket;
+mod spool;
 
 use anyhow::Result;
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::net::UdpSocket;
+use tokio::net::{TcpListener, UdpSocket};
 use tokio::sync::mpsc::channel;
+use tokio::sync::watch;
+use tokio::task::JoinSet;
 use tokio_graceful::Shutdown;
 use tracing::{debug, error, trace};
 
 use crate::config::{configure, AppConfig};
+use crate::enrich::Enrichment;
+use crate::socket::{Receiver, SocketOptions};
 
-async fn sflow_handler(cfg: Arc<AppConfig>) {
+async fn sflow_handler(
+    config_rx: watch::Receiver<Arc<AppConfig>>,
+    enrichment_rx: watch::Receiver<Arc<Enrichment>>,
+) {
+    let cfg = config_rx.borrow().clone();
     let sflow_config = cfg.sflow.clone();
+    let netflow_config = cfg.netflow.clone();
     let kafka_config = cfg.kafka.clone();
 
-    debug!("binding sFlow listener to {}", sflow_config.host);
-    let socket = UdpSocket::bind(sflow_config.host)
-        .await
-        .expect("Failed to bind UDP socket");
-
     // Initialize MPSC channel to communicate between sFlow handler and producer
     let (tx, rx) = channel(kafka_config.mpsc_buffer_size);
 
+    // Spawn NetFlow and IPFIX listeners, sharing the producer channel
+    for host in netflow_config.hosts {
+        debug!("binding NetFlow listener to {}", host);
+        let socket = UdpSocket::bind(host)
+            .await
+            .expect("Failed to bind NetFlow UDP socket");
+        let tx = tx.clone();
+        let config_rx = config_rx.clone();
+        tokio::spawn(async move {
+            if let Err(err) = netflow::handle(socket, config_rx, tx).await {
+                error!("Error handling NetFlow packets: {}", err);
+            }
+        });
+    }
+
+    // Handle sFlow datagrams, one task per listener and worker
+    let mut sflow_handles = JoinSet::new();
+    for listener in sflow_config.listeners {
+        debug!(
+            "binding {} sFlow workers to {}",
+            sflow_config.workers, listener.host
+        );
+        let listener = Arc::new(listener);
+        for worker in 0..sflow_config.workers {
+            let options = SocketOptions {
+                reuse_port: sflow_config.workers > 1,
+                recv_buffer_bytes: sflow_config.recv_buffer_bytes,
+                kernel_timestamps: sflow_config.kernel_timestamps,
+            };
+            let socket =
+                socket::bind_udp(listener.host, options).expect("Failed to bind UDP socket");
+            let receiver = Receiver::new(socket, sflow_config.recv_batch_size);
+            let listener = listener.clone();
+            let config_rx = config_rx.clone();
+            let enrichment_rx = enrichment_rx.clone();
+            let tx = tx.clone();
+            sflow_handles.spawn(async move {
+                if let Err(err) =
+                    sflow::handle(receiver, listener, worker, config_rx, enrichment_rx, tx).await
+                {
+                    error!("Error handling sFlow datagrams: {}", err);
+                }
+            });
+        }
+    }
+    drop(tx);
+
     // Spawn producer task
     let producer_handle = tokio::spawn(async move {
-        if let Err(err) = producer::handle(&kafka_config, rx).await {
+        if let Err(err) = producer::handle(config_rx, rx).await {
             error!("Error handling Kafka producer: {}", err);
         }
     });
 
-    // Handle sFlow datagrams
-    let sflow_handle = tokio::spawn(async move {
-        if let Err(err) = sflow::handle(socket, tx).await {
-            error!("Error handling sFlow datagrams: {}", err);
-        }
-    });
-
-    // Wait for both tasks
+    // Wait for the producer or any sFlow listener to exit
     tokio::select! {
-        _ = sflow_handle => {}
+        _ = sflow_handles.join_next() => {}
         _ = producer_handle => {}
     }
 }
@@ -54,8 +111,38 @@ async fn main() -> Result<()> {
 
     let shutdown = Shutdown::default();
 
+    // Load the enrichment files, reloaded when they change
+    let enrichment = enrich::load(&cfg)?;
+    let (enrichment_tx, enrichment_rx) = watch::channel(Arc::new(enrichment));
+    let enrichment_config = cfg.clone();
+    tokio::spawn(async move {
+        if let Err(err) = enrich::handle(enrichment_config, enrichment_tx).await {
+            error!("Error reloading enrichment files: {}", err);
+        }
+    });
+
+    // Reload the configuration on SIGHUP and on the admin endpoint
+    let (config_tx, config_rx) = watch::channel(cfg.clone());
+    let config_tx = Arc::new(config_tx);
+    let signals_config_tx = config_tx.clone();
+    tokio::spawn(async move {
+        if let Err(err) = reload::handle_signals(signals_config_tx).await {
+            error!("Error handling signals: {}", err);
+        }
+    });
+    if let Some(admin_address) = cfg.admin_address {
+        let listener = TcpListener::bind(admin_address)
+            .await
+            .expect("Failed to bind admin listener");
+        tokio::spawn(async move {
+            if let Err(err) = reload::handle_admin(listener, config_tx).await {
+                error!("Error handling admin requests: {}", err);
+            }
+        });
+    }
+
     // Initialize sFlow handler task
-    let sflow_task = shutdown.spawn_task(sflow_handler(cfg.clone()));
+    let sflow_task = shutdown.spawn_task(sflow_handler(config_rx, enrichment_rx));
 
     tokio::select! {
         biased;
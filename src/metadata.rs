@@ -0,0 +1,246 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::Path;
+
+// The metadata file describes the agents and their interfaces, in YAML:
+//
+//   agents:
+//     - address: 192.0.2.1
+//       hostname: edge1.par1
+//       site: par1
+//       role: edge
+//       interfaces:
+//         - index: 1
+//           name: et-0/0/1
+//           description: "transit: Example Transit"
+//           speed: 100000000000
+//           boundary: external
+//
+// Every field but the agent address and the interface index is optional.
+
+/// Side of the network an interface is on
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Boundary {
+    #[default]
+    Undefined,
+    Internal,
+    External,
+}
+
+impl Boundary {
+    /// Value of the flow records, 0 if undefined
+    pub fn value(self) -> u8 {
+        match self {
+            Boundary::Undefined => 0,
+            Boundary::Internal => 1,
+            Boundary::External => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Interface {
+    pub index: u32,
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    // Bits per second
+    #[serde(default)]
+    pub speed: u64,
+    #[serde(default)]
+    pub boundary: Boundary,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct AgentEntry {
+    address: IpAddr,
+    #[serde(default)]
+    hostname: String,
+    #[serde(default)]
+    site: String,
+    #[serde(default)]
+    role: String,
+    #[serde(default)]
+    interfaces: Vec<Interface>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MetadataFile {
+    #[serde(default)]
+    agents: Vec<AgentEntry>,
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Agent {
+    pub hostname: String,
+    pub site: String,
+    pub role: String,
+    interfaces: HashMap<u32, Interface>,
+}
+
+impl Agent {
+    pub fn interface(&self, index: u32) -> Option<&Interface> {
+        self.interfaces.get(&index)
+    }
+}
+
+/// Agents and interfaces metadata, by agent address and ifIndex
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Metadata {
+    agents: HashMap<IpAddr, Agent>,
+}
+
+impl Metadata {
+    /// Read the metadata file (.yaml or .yml)
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read metadata file {}", path.display()))?;
+        let file: MetadataFile = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .with_context(|| format!("Invalid metadata file {}", path.display()))?,
+            _ => anyhow::bail!(
+                "Unsupported metadata file format: {} (expected .yaml or .yml)",
+                path.display()
+            ),
+        };
+
+        let mut agents = HashMap::new();
+        for entry in file.agents {
+            // Agents reporting IPv4 addresses match IPv4-mapped entries too
+            let address = entry.address.to_canonical();
+            let mut interfaces = HashMap::new();
+            for interface in entry.interfaces {
+                let index = interface.index;
+                if interfaces.insert(index, interface).is_some() {
+                    anyhow::bail!(
+                        "Duplicate interface {} of agent {} in metadata file {}",
+                        index,
+                        address,
+                        path.display()
+                    );
+                }
+            }
+            let agent = Agent {
+                hostname: entry.hostname,
+                site: entry.site,
+                role: entry.role,
+                interfaces,
+            };
+            if agents.insert(address, agent).is_some() {
+                anyhow::bail!(
+                    "Duplicate agent {} in metadata file {}",
+                    address,
+                    path.display()
+                );
+            }
+        }
+        Ok(Metadata { agents })
+    }
+
+    pub fn agent(&self, address: Option<IpAddr>) -> Option<&Agent> {
+        self.agents.get(&address?.to_canonical())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    // JSON is valid YAML, and keeps the tests independent of the indentation
+    fn write(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "pesto-metadata-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn read(name: &str, content: &str) -> Result<Metadata> {
+        let path = write(name, content);
+        let metadata = Metadata::from_file(&path);
+        let _ = std::fs::remove_file(&path);
+        metadata
+    }
+
+    #[test]
+    fn lookup() {
+        let metadata = read(
+            "lookup.yaml",
+            r#"{"agents": [
+                {"address": "192.0.2.1", "hostname": "edge1.par1", "site": "par1", "role": "edge",
+                 "interfaces": [
+                    {"index": 1, "name": "et-0/0/1", "description": "transit", "speed": 100000000000,
+                     "boundary": "external"},
+                    {"index": 2, "name": "ae0", "boundary": "internal"}]},
+                {"address": "2001:db8::1"}]}"#,
+        )
+        .unwrap();
+
+        let agent = metadata.agent(Some("192.0.2.1".parse().unwrap())).unwrap();
+        assert_eq!(
+            (agent.hostname.as_str(), agent.site.as_str()),
+            ("edge1.par1", "par1")
+        );
+        assert_eq!(agent.role, "edge");
+        let interface = agent.interface(1).unwrap();
+        assert_eq!(
+            (interface.name.as_str(), interface.speed),
+            ("et-0/0/1", 100_000_000_000)
+        );
+        assert_eq!(interface.boundary.value(), 2);
+        let interface = agent.interface(2).unwrap();
+        assert_eq!(
+            (interface.speed, interface.boundary),
+            (0, Boundary::Internal)
+        );
+        assert!(agent.interface(3).is_none());
+
+        // IPv4-mapped agent addresses match the IPv4 entries
+        assert!(metadata
+            .agent(Some("::ffff:192.0.2.1".parse().unwrap()))
+            .is_some());
+        let agent = metadata
+            .agent(Some("2001:db8::1".parse().unwrap()))
+            .unwrap();
+        assert_eq!(agent.hostname, "");
+        assert!(metadata.agent(Some("192.0.2.2".parse().unwrap())).is_none());
+        assert!(metadata.agent(None).is_none());
+    }
+
+    #[test]
+    fn invalid_files() {
+        for (name, content) in [
+            (
+                "unknown.yaml",
+                r#"{"agents": [{"address": "192.0.2.1", "host": "edge1"}]}"#,
+            ),
+            ("address.yaml", r#"{"agents": [{"address": "edge1"}]}"#),
+            (
+                "boundary.yaml",
+                r#"{"agents": [{"address": "192.0.2.1", "interfaces": [{"index": 1, "boundary": "dmz"}]}]}"#,
+            ),
+            (
+                "agents.yaml",
+                r#"{"agents": [{"address": "192.0.2.1"}, {"address": "::ffff:192.0.2.1"}]}"#,
+            ),
+            (
+                "interfaces.yml",
+                r#"{"agents": [{"address": "192.0.2.1", "interfaces": [{"index": 1}, {"index": 1}]}]}"#,
+            ),
+            ("format.json", r#"{"agents": []}"#),
+        ] {
+            assert!(read(name, content).is_err(), "{} should be rejected", name);
+        }
+        assert!(Metadata::from_file(Path::new("/nonexistent/metadata.yaml")).is_err());
+    }
+}
@@ -0,0 +1,1034 @@
+use anyhow::Result;
+use chrono::Utc;
+use metrics::{counter, gauge};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::net::UdpSocket;
+use tokio::sync::mpsc::Sender;
+use tokio::sync::watch;
+use tracing::{debug, error, trace};
+
+use crate::acl;
+use crate::config::AppConfig;
+use crate::filter::Filter;
+use crate::serializer::{serialize_netflow_packet, SerializedRecords};
+
+const MAX_DATAGRAM_SIZE: usize = 65535;
+
+// Templates not refreshed for this long are forgotten, exporters resend them
+// periodically over UDP
+const TEMPLATE_IDLE_TIMEOUT: Duration = Duration::from_secs(3600);
+
+// Templates cached per exporter, over all its observation domains
+const MAX_TEMPLATES_PER_EXPORTER: usize = 1024;
+
+// Set IDs
+const V9_TEMPLATE_SET_ID: u16 = 0;
+const V9_OPTIONS_TEMPLATE_SET_ID: u16 = 1;
+const IPFIX_TEMPLATE_SET_ID: u16 = 2;
+const IPFIX_OPTIONS_TEMPLATE_SET_ID: u16 = 3;
+const MIN_DATA_SET_ID: u16 = 256;
+
+// IPFIX variable-length field, and enterprise-specific field bit
+const VARIABLE_LENGTH: u16 = 65535;
+const ENTERPRISE_BIT: u16 = 0x8000;
+
+// Information elements (IANA IPFIX, same IDs as NetFlow v9 for the ones below 128)
+const IE_OCTET_DELTA_COUNT: u16 = 1;
+const IE_PACKET_DELTA_COUNT: u16 = 2;
+const IE_PROTOCOL_IDENTIFIER: u16 = 4;
+const IE_IP_CLASS_OF_SERVICE: u16 = 5;
+const IE_TCP_CONTROL_BITS: u16 = 6;
+const IE_SOURCE_TRANSPORT_PORT: u16 = 7;
+const IE_SOURCE_IPV4_ADDRESS: u16 = 8;
+const IE_SOURCE_IPV4_PREFIX_LENGTH: u16 = 9;
+const IE_INGRESS_INTERFACE: u16 = 10;
+const IE_DESTINATION_TRANSPORT_PORT: u16 = 11;
+const IE_DESTINATION_IPV4_ADDRESS: u16 = 12;
+const IE_DESTINATION_IPV4_PREFIX_LENGTH: u16 = 13;
+const IE_EGRESS_INTERFACE: u16 = 14;
+const IE_IP_NEXT_HOP_IPV4_ADDRESS: u16 = 15;
+const IE_BGP_SOURCE_AS_NUMBER: u16 = 16;
+const IE_BGP_DESTINATION_AS_NUMBER: u16 = 17;
+const IE_FLOW_END_SYS_UP_TIME: u16 = 21;
+const IE_FLOW_START_SYS_UP_TIME: u16 = 22;
+const IE_SOURCE_IPV6_ADDRESS: u16 = 27;
+const IE_DESTINATION_IPV6_ADDRESS: u16 = 28;
+const IE_SOURCE_IPV6_PREFIX_LENGTH: u16 = 29;
+const IE_DESTINATION_IPV6_PREFIX_LENGTH: u16 = 30;
+const IE_SAMPLING_INTERVAL: u16 = 34;
+const IE_SOURCE_MAC_ADDRESS: u16 = 56;
+const IE_VLAN_ID: u16 = 58;
+const IE_IP_NEXT_HOP_IPV6_ADDRESS: u16 = 62;
+const IE_DESTINATION_MAC_ADDRESS: u16 = 80;
+const IE_FLOW_START_SECONDS: u16 = 150;
+const IE_FLOW_END_SECONDS: u16 = 151;
+const IE_FLOW_START_MILLISECONDS: u16 = 152;
+const IE_FLOW_END_MILLISECONDS: u16 = 153;
+const IE_SYSTEM_INIT_TIME_MILLISECONDS: u16 = 160;
+const IE_ETHERNET_TYPE: u16 = 256;
+const IE_SAMPLING_PACKET_INTERVAL: u16 = 305;
+
+/// Decoded NetFlow v5, v9 or IPFIX packet
+#[derive(Debug, Clone)]
+pub struct NetFlowPacket {
+    pub version: u16,
+    pub sequence_number: u32,
+    pub observation_domain_id: u32,
+    pub export_time: u32,
+    pub flows: Vec<NetFlowFlow>,
+}
+
+/// Flow data record, limited to the fields of the schema
+#[derive(Debug, Clone, Default)]
+pub struct NetFlowFlow {
+    pub flow_start_ms: u64,
+    pub flow_end_ms: u64,
+    pub bytes: u64,
+    pub packets: u64,
+    pub sampling_rate: u32,
+    pub src_ip: Option<IpAddr>,
+    pub dst_ip: Option<IpAddr>,
+    pub src_port: u32,
+    pub dst_port: u32,
+    pub protocol: u32,
+    pub tos: u32,
+    pub tcp_flags: u32,
+    pub input_interface: u32,
+    pub output_interface: u32,
+    pub src_mac: u64,
+    pub dst_mac: u64,
+    pub etype: u32,
+    pub vlan_id: u32,
+    pub next_hop: Option<IpAddr>,
+    pub src_mask_len: u32,
+    pub dst_mask_len: u32,
+    pub src_as: u32,
+    pub dst_as: u32,
+}
+
+#[derive(Debug, Clone)]
+struct TemplateField {
+    id: u16,
+    enterprise_number: u32,
+    length: u16,
+}
+
+#[derive(Debug, Clone)]
+struct Template {
+    fields: Vec<TemplateField>,
+    // Options data records describe the exporter, not flows
+    options: bool,
+    updated: Instant,
+}
+
+/// Templates announced by the exporters
+///
+/// Template IDs are only unique within an exporter and an observation domain
+/// (v9 source ID), so they are cached by exporter address, then by version,
+/// domain and template ID. Each exporter caches at most
+/// `MAX_TEMPLATES_PER_EXPORTER` templates, and the templates not refreshed for
+/// `TEMPLATE_IDLE_TIMEOUT` are forgotten.
+#[derive(Default)]
+pub struct TemplateCache {
+    templates: HashMap<IpAddr, HashMap<(u16, u32, u16), Template>>,
+    pruned: Option<Instant>,
+}
+
+impl TemplateCache {
+    fn insert(&mut self, domain: (IpAddr, u16, u32), template_id: u16, template: Template) {
+        let (exporter, version, domain_id) = domain;
+        let templates = self.templates.entry(exporter).or_default();
+        let key = (version, domain_id, template_id);
+        if !templates.contains_key(&key) && templates.len() >= MAX_TEMPLATES_PER_EXPORTER {
+            counter!("pesto_netflow_templates_dropped_total").increment(1);
+            debug!(
+                "too many templates from {}, dropping template {}",
+                exporter, template_id
+            );
+            return;
+        }
+        if templates.insert(key, template).is_none() {
+            gauge!("pesto_netflow_templates").increment(1);
+        }
+    }
+
+    fn remove(&mut self, domain: (IpAddr, u16, u32), template_id: u16) {
+        let (exporter, version, domain_id) = domain;
+        let removed = self
+            .templates
+            .get_mut(&exporter)
+            .and_then(|templates| templates.remove(&(version, domain_id, template_id)));
+        if removed.is_some() {
+            gauge!("pesto_netflow_templates").decrement(1);
+        }
+    }
+
+    fn get(&self, domain: (IpAddr, u16, u32), template_id: u16) -> Option<&Template> {
+        let (exporter, version, domain_id) = domain;
+        self.templates
+            .get(&exporter)
+            .and_then(|templates| templates.get(&(version, domain_id, template_id)))
+    }
+
+    // Forget the idle templates, at most once per timeout
+    fn prune(&mut self, now: Instant) {
+        if let Some(pruned) = self.pruned {
+            if now.duration_since(pruned) < TEMPLATE_IDLE_TIMEOUT {
+                return;
+            }
+        }
+        let mut removed = 0;
+        for templates in self.templates.values_mut() {
+            let len = templates.len();
+            templates
+                .retain(|_, template| now.duration_since(template.updated) < TEMPLATE_IDLE_TIMEOUT);
+            removed += len - templates.len();
+        }
+        self.templates.retain(|_, templates| !templates.is_empty());
+        gauge!("pesto_netflow_templates").decrement(removed as f64);
+        self.pruned = Some(now);
+    }
+}
+
+// Big-endian reader over a packet or a set, failing on truncated data
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Reader { data, pos: 0 }
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+
+    fn bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.remaining() < n {
+            anyhow::bail!(
+                "truncated data: {} bytes needed at offset {}, {} available",
+                n,
+                self.pos,
+                self.remaining()
+            );
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.bytes(2)?.try_into()?))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into()?))
+    }
+}
+
+/// Decode a NetFlow v5, v9 or IPFIX packet, the version is taken from the header
+pub fn decode(data: &[u8], exporter: IpAddr, cache: &mut TemplateCache) -> Result<NetFlowPacket> {
+    cache.prune(Instant::now());
+    match Reader::new(data).u16()? {
+        5 => decode_v5(data),
+        9 => decode_v9(data, exporter, cache),
+        10 => decode_ipfix(data, exporter, cache),
+        version => anyhow::bail!("unsupported NetFlow version {}", version),
+    }
+}
+
+fn decode_v5(data: &[u8]) -> Result<NetFlowPacket> {
+    let mut reader = Reader::new(data);
+    let version = reader.u16()?;
+    let count = reader.u16()?;
+    let sys_uptime = reader.u32()?;
+    let unix_secs = reader.u32()?;
+    let unix_nsecs = reader.u32()?;
+    let sequence_number = reader.u32()?;
+    let engine_type = reader.u8()?;
+    let engine_id = reader.u8()?;
+    // Two bits of sampling mode, then the sampling interval
+    let sampling_rate = (reader.u16()? & 0x3fff) as u32;
+
+    // Flow timestamps are relative to the exporter boot time
+    let export_ms = unix_secs as u64 * 1000 + unix_nsecs as u64 / 1_000_000;
+    let boot_ms = export_ms.saturating_sub(sys_uptime as u64);
+
+    let mut flows = Vec::with_capacity(count as usize);
+    for _ in 0..count {
+        let mut record = Reader::new(reader.bytes(48)?);
+        let src_ip = Ipv4Addr::from(record.u32()?);
+        let dst_ip = Ipv4Addr::from(record.u32()?);
+        let next_hop = Ipv4Addr::from(record.u32()?);
+        let input_interface = record.u16()? as u32;
+        let output_interface = record.u16()? as u32;
+        let packets = record.u32()? as u64;
+        let bytes = record.u32()? as u64;
+        let first = record.u32()?;
+        let last = record.u32()?;
+        let src_port = record.u16()? as u32;
+        let dst_port = record.u16()? as u32;
+        record.u8()?; // pad1
+        let tcp_flags = record.u8()? as u32;
+        let protocol = record.u8()? as u32;
+        let tos = record.u8()? as u32;
+        let src_as = record.u16()? as u32;
+        let dst_as = record.u16()? as u32;
+        let src_mask_len = record.u8()? as u32;
+        let dst_mask_len = record.u8()? as u32;
+
+        flows.push(NetFlowFlow {
+            flow_start_ms: boot_ms.saturating_add(first as u64),
+            flow_end_ms: boot_ms.saturating_add(last as u64),
+            bytes,
+            packets,
+            sampling_rate,
+            src_ip: Some(IpAddr::V4(src_ip)),
+            dst_ip: Some(IpAddr::V4(dst_ip)),
+            src_port,
+            dst_port,
+            protocol,
+            tos,
+            tcp_flags,
+            input_interface,
+            output_interface,
+            next_hop: Some(IpAddr::V4(next_hop)),
+            src_mask_len,
+            dst_mask_len,
+            src_as,
+            dst_as,
+            ..Default::default()
+        });
+    }
+
+    Ok(NetFlowPacket {
+        version,
+        sequence_number,
+        observation_domain_id: (engine_type as u32) << 8 | engine_id as u32,
+        export_time: unix_secs,
+        flows,
+    })
+}
+
+fn decode_v9(data: &[u8], exporter: IpAddr, cache: &mut TemplateCache) -> Result<NetFlowPacket> {
+    let mut reader = Reader::new(data);
+    let version = reader.u16()?;
+    let _count = reader.u16()?;
+    let sys_uptime = reader.u32()?;
+    let unix_secs = reader.u32()?;
+    let sequence_number = reader.u32()?;
+    let source_id = reader.u32()?;
+
+    let domain = (exporter, version, source_id);
+    let boot_ms = (unix_secs as u64 * 1000).saturating_sub(sys_uptime as u64);
+
+    let mut flows = Vec::new();
+    while reader.remaining() >= 4 {
+        let set_id = reader.u16()?;
+        let length = reader.u16()? as usize;
+        if length < 4 {
+            anyhow::bail!("invalid NetFlow v9 flowset length {}", length);
+        }
+        let set = reader.bytes(length - 4)?;
+
+        match set_id {
+            V9_TEMPLATE_SET_ID => decode_templates(set, domain, false, cache)?,
+            V9_OPTIONS_TEMPLATE_SET_ID => decode_v9_options_templates(set, domain, cache)?,
+            id if id >= MIN_DATA_SET_ID => {
+                decode_data_set(set, id, domain, Some(boot_ms), cache, &mut flows)?
+            }
+            id => trace!("skipping reserved NetFlow v9 flowset {}", id),
+        }
+    }
+
+    Ok(NetFlowPacket {
+        version,
+        sequence_number,
+        observation_domain_id: source_id,
+        export_time: unix_secs,
+        flows,
+    })
+}
+
+fn decode_ipfix(data: &[u8], exporter: IpAddr, cache: &mut TemplateCache) -> Result<NetFlowPacket> {
+    let mut reader = Reader::new(data);
+    let version = reader.u16()?;
+    let length = reader.u16()? as usize;
+    let export_time = reader.u32()?;
+    let sequence_number = reader.u32()?;
+    let observation_domain_id = reader.u32()?;
+
+    // The message length excludes anything trailing in the datagram
+    let Some(message) = data.get(16..length) else {
+        anyhow::bail!(
+            "invalid IPFIX message length {} for a {} bytes datagram",
+            length,
+            data.len()
+        );
+    };
+    let mut reader = Reader::new(message);
+    let domain = (exporter, version, observation_domain_id);
+
+    let mut flows = Vec::new();
+    while reader.remaining() >= 4 {
+        let set_id = reader.u16()?;
+        let length = reader.u16()? as usize;
+        if length < 4 {
+            anyhow::bail!("invalid IPFIX set length {}", length);
+        }
+        let set = reader.bytes(length - 4)?;
+
+        match set_id {
+            IPFIX_TEMPLATE_SET_ID => decode_templates(set, domain, false, cache)?,
+            IPFIX_OPTIONS_TEMPLATE_SET_ID => decode_templates(set, domain, true, cache)?,
+            id if id >= MIN_DATA_SET_ID => {
+                decode_data_set(set, id, domain, None, cache, &mut flows)?
+            }
+            id => trace!("skipping reserved IPFIX set {}", id),
+        }
+    }
+
+    Ok(NetFlowPacket {
+        version,
+        sequence_number,
+        observation_domain_id,
+        export_time,
+        flows,
+    })
+}
+
+// NetFlow v9 template flowsets and IPFIX (options) template sets
+fn decode_templates(
+    set: &[u8],
+    domain: (IpAddr, u16, u32),
+    options: bool,
+    cache: &mut TemplateCache,
+) -> Result<()> {
+    let ipfix = domain.1 == 10;
+    let mut reader = Reader::new(set);
+
+    // Anything shorter than a template header is padding
+    while reader.remaining() >= 4 {
+        let template_id = reader.u16()?;
+        let field_count = reader.u16()?;
+
+        // IPFIX template withdrawal
+        if ipfix && field_count == 0 {
+            debug!("template {} withdrawn by {:?}", template_id, domain);
+            cache.remove(domain, template_id);
+            continue;
+        }
+
+        if ipfix && options {
+            let _scope_field_count = reader.u16()?;
+        }
+
+        let mut fields = Vec::with_capacity(field_count as usize);
+        for _ in 0..field_count {
+            let id = reader.u16()?;
+            let length = reader.u16()?;
+            let (id, enterprise_number) = if ipfix && id & ENTERPRISE_BIT != 0 {
+                (id & !ENTERPRISE_BIT, reader.u32()?)
+            } else {
+                (id, 0)
+            };
+            fields.push(TemplateField {
+                id,
+                enterprise_number,
+                length,
+            });
+        }
+
+        trace!(
+            "template {} with {} fields from {:?}",
+            template_id,
+            fields.len(),
+            domain
+        );
+        let template = Template {
+            fields,
+            options,
+            updated: Instant::now(),
+        };
+        cache.insert(domain, template_id, template);
+    }
+    Ok(())
+}
+
+// NetFlow v9 options templates give the scope and option lengths in bytes
+fn decode_v9_options_templates(
+    set: &[u8],
+    domain: (IpAddr, u16, u32),
+    cache: &mut TemplateCache,
+) -> Result<()> {
+    let mut reader = Reader::new(set);
+
+    while reader.remaining() >= 6 {
+        let template_id = reader.u16()?;
+        let scope_length = reader.u16()? as usize;
+        let option_length = reader.u16()? as usize;
+
+        let mut fields = Vec::with_capacity((scope_length + option_length) / 4);
+        for _ in 0..(scope_length + option_length) / 4 {
+            fields.push(TemplateField {
+                id: reader.u16()?,
+                enterprise_number: 0,
+                length: reader.u16()?,
+            });
+        }
+        cache.insert(
+            domain,
+            template_id,
+            Template {
+                fields,
+                options: true,
+                updated: Instant::now(),
+            },
+        );
+    }
+    Ok(())
+}
+
+fn decode_data_set(
+    set: &[u8],
+    template_id: u16,
+    domain: (IpAddr, u16, u32),
+    boot_ms: Option<u64>,
+    cache: &TemplateCache,
+    flows: &mut Vec<NetFlowFlow>,
+) -> Result<()> {
+    let Some(template) = cache.get(domain, template_id) else {
+        counter!("pesto_netflow_missing_template_total").increment(1);
+        debug!(
+            "no template {} from {:?} yet, skipping data set",
+            template_id, domain
+        );
+        return Ok(());
+    };
+    if template.options {
+        trace!("skipping options data set {}", template_id);
+        return Ok(());
+    }
+
+    // Records are followed by padding shorter than a record
+    let min_record_length: usize = template
+        .fields
+        .iter()
+        .map(|field| match field.length {
+            VARIABLE_LENGTH => 1,
+            length => length as usize,
+        })
+        .sum();
+    if min_record_length == 0 {
+        return Ok(());
+    }
+
+    let mut reader = Reader::new(set);
+    while reader.remaining() >= min_record_length {
+        let mut record = DataRecord::default();
+        for field in &template.fields {
+            let length = match field.length {
+                VARIABLE_LENGTH => match reader.u8()? {
+                    255 => reader.u16()? as usize,
+                    length => length as usize,
+                },
+                length => length as usize,
+            };
+            let value = reader.bytes(length)?;
+            if field.enterprise_number == 0 {
+                record.set(field.id, value);
+            }
+        }
+        flows.push(record.into_flow(boot_ms));
+    }
+    Ok(())
+}
+
+// Fields of a data record, the timestamps are resolved once the record is complete
+#[derive(Default)]
+struct DataRecord {
+    flow: NetFlowFlow,
+    start_sys_uptime: Option<u64>,
+    end_sys_uptime: Option<u64>,
+    system_init_time_ms: Option<u64>,
+}
+
+impl DataRecord {
+    fn set(&mut self, id: u16, value: &[u8]) {
+        let flow = &mut self.flow;
+        match id {
+            IE_OCTET_DELTA_COUNT => flow.bytes = read_uint(value),
+            IE_PACKET_DELTA_COUNT => flow.packets = read_uint(value),
+            IE_PROTOCOL_IDENTIFIER => flow.protocol = read_uint(value) as u32,
+            IE_IP_CLASS_OF_SERVICE => flow.tos = read_uint(value) as u32,
+            IE_TCP_CONTROL_BITS => flow.tcp_flags = read_uint(value) as u32,
+            IE_SOURCE_TRANSPORT_PORT => flow.src_port = read_uint(value) as u32,
+            IE_DESTINATION_TRANSPORT_PORT => flow.dst_port = read_uint(value) as u32,
+            IE_SOURCE_IPV4_ADDRESS | IE_SOURCE_IPV6_ADDRESS => flow.src_ip = read_ip(value),
+            IE_DESTINATION_IPV4_ADDRESS | IE_DESTINATION_IPV6_ADDRESS => {
+                flow.dst_ip = read_ip(value)
+            }
+            IE_IP_NEXT_HOP_IPV4_ADDRESS | IE_IP_NEXT_HOP_IPV6_ADDRESS => {
+                flow.next_hop = read_ip(value)
+            }
+            IE_SOURCE_IPV4_PREFIX_LENGTH | IE_SOURCE_IPV6_PREFIX_LENGTH => {
+                flow.src_mask_len = read_uint(value) as u32
+            }
+            IE_DESTINATION_IPV4_PREFIX_LENGTH | IE_DESTINATION_IPV6_PREFIX_LENGTH => {
+                flow.dst_mask_len = read_uint(value) as u32
+            }
+            IE_INGRESS_INTERFACE => flow.input_interface = read_uint(value) as u32,
+            IE_EGRESS_INTERFACE => flow.output_interface = read_uint(value) as u32,
+            IE_BGP_SOURCE_AS_NUMBER => flow.src_as = read_uint(value) as u32,
+            IE_BGP_DESTINATION_AS_NUMBER => flow.dst_as = read_uint(value) as u32,
+            IE_SAMPLING_INTERVAL | IE_SAMPLING_PACKET_INTERVAL => {
+                flow.sampling_rate = read_uint(value) as u32
+            }
+            IE_SOURCE_MAC_ADDRESS => flow.src_mac = read_uint(value),
+            IE_DESTINATION_MAC_ADDRESS => flow.dst_mac = read_uint(value),
+            IE_VLAN_ID => flow.vlan_id = read_uint(value) as u32,
+            IE_ETHERNET_TYPE => flow.etype = read_uint(value) as u32,
+            IE_FLOW_START_SECONDS => flow.flow_start_ms = read_uint(value).saturating_mul(1000),
+            IE_FLOW_END_SECONDS => flow.flow_end_ms = read_uint(value).saturating_mul(1000),
+            IE_FLOW_START_MILLISECONDS => flow.flow_start_ms = read_uint(value),
+            IE_FLOW_END_MILLISECONDS => flow.flow_end_ms = read_uint(value),
+            IE_FLOW_START_SYS_UP_TIME => self.start_sys_uptime = Some(read_uint(value)),
+            IE_FLOW_END_SYS_UP_TIME => self.end_sys_uptime = Some(read_uint(value)),
+            IE_SYSTEM_INIT_TIME_MILLISECONDS => self.system_init_time_ms = Some(read_uint(value)),
+            _ => {}
+        }
+    }
+
+    // Uptime-relative timestamps are resolved with the exporter boot time, from
+    // the v9 header or from the IPFIX system init time of the same record
+    fn into_flow(self, boot_ms: Option<u64>) -> NetFlowFlow {
+        let mut flow = self.flow;
+        if let Some(boot_ms) = self.system_init_time_ms.or(boot_ms) {
+            if let Some(start) = self.start_sys_uptime {
+                flow.flow_start_ms = boot_ms.saturating_add(start);
+            }
+            if let Some(end) = self.end_sys_uptime {
+                flow.flow_end_ms = boot_ms.saturating_add(end);
+            }
+        }
+        flow
+    }
+}
+
+// Unsigned integers may use a reduced-size encoding
+fn read_uint(value: &[u8]) -> u64 {
+    value
+        .iter()
+        .take(8)
+        .fold(0, |acc, byte| acc << 8 | *byte as u64)
+}
+
+fn read_ip(value: &[u8]) -> Option<IpAddr> {
+    match value.len() {
+        4 => <[u8; 4]>::try_from(value).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(value)
+            .ok()
+            .map(|octets| IpAddr::V6(Ipv6Addr::from(octets))),
+        _ => None,
+    }
+}
+
+fn version_label(data: &[u8]) -> &'static str {
+    match data.get(..2) {
+        Some([0, 5]) => "5",
+        Some([0, 9]) => "9",
+        Some([0, 10]) => "10",
+        _ => "unknown",
+    }
+}
+
+pub async fn handle(
+    socket: UdpSocket,
+    config_rx: watch::Receiver<Arc<AppConfig>>,
+    tx: Sender<SerializedRecords>,
+) -> Result<()> {
+    let local_addr = socket.local_addr()?;
+    debug!("NetFlow listener bound to {}", local_addr);
+
+    let mut buf = vec![0u8; MAX_DATAGRAM_SIZE];
+    let mut cache = TemplateCache::default();
+
+    loop {
+        let (n_bytes, peer_addr) = match socket.recv_from(&mut buf).await {
+            Ok(received) => received,
+            Err(e) => {
+                error!("Failed to receive UDP datagram: {}", e);
+                return Err(e.into());
+            }
+        };
+        if n_bytes == 0 {
+            continue;
+        }
+
+        let time_received_ns = Utc::now().timestamp_nanos_opt().unwrap();
+        trace!("Received {} bytes from {}", n_bytes, peer_addr);
+
+        // Drop the packets of unknown exporters before caching their templates
+        let config = config_rx.borrow().clone();
+        if let Err(reject) =
+            acl::check_peer(&config.netflow.allow, &config.netflow.deny, peer_addr.ip())
+        {
+            counter!("pesto_netflow_rejected_packets_total", "reason" => reject.label())
+                .increment(1);
+            trace!(
+                "Rejected NetFlow packet from {}: {}",
+                peer_addr,
+                reject.label()
+            );
+            continue;
+        }
+
+        let data = &buf[..n_bytes];
+        let version = version_label(data);
+        let packet = match decode(data, peer_addr.ip(), &mut cache) {
+            Ok(packet) => packet,
+            Err(e) => {
+                counter!("pesto_netflow_packets_total", "version" => version, "status" => "parse_error")
+                    .increment(1);
+                error!(
+                    "Failed to parse NetFlow packet from {} (size: {} bytes): {}",
+                    peer_addr, n_bytes, e
+                );
+                continue;
+            }
+        };
+        counter!("pesto_netflow_packets_total", "version" => version, "status" => "success")
+            .increment(1);
+
+        if packet.flows.is_empty() {
+            // Templates only
+            continue;
+        }
+
+        trace!(
+            "Sending {} NetFlow v{} flows to producer channel",
+            packet.flows.len(),
+            packet.version
+        );
+        let filter = Filter::new(&config.filters, &config.kafka.netflow_topic);
+        let records = serialize_netflow_packet(
+            &packet,
+            time_received_ns,
+            peer_addr,
+            config.kafka.key,
+            filter,
+            config.anonymizer.as_ref(),
+        );
+        if let Err(e) = tx.send(records).await {
+            error!("Failed to send NetFlow records to producer: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPORTER: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+    // v5 packet with one record: 10.0.0.1:1234 -> 10.0.0.2:80 over TCP
+    const V5_PACKET: [u8; 72] = [
+        0x00, 0x05, 0x00, 0x01, // version, count
+        0x00, 0x00, 0x27, 0x10, // sys uptime 10000 ms
+        0x65, 0x00, 0x00, 0x00, // unix secs 1694498816
+        0x00, 0x00, 0x00, 0x00, // unix nsecs
+        0x00, 0x00, 0x00, 0x2a, // sequence 42
+        0x01, 0x02, // engine type, engine ID
+        0x40, 0x64, // sampling mode 1, interval 100
+        0x0a, 0x00, 0x00, 0x01, // src
+        0x0a, 0x00, 0x00, 0x02, // dst
+        0x0a, 0x00, 0x00, 0xfe, // next hop
+        0x00, 0x03, 0x00, 0x04, // input, output
+        0x00, 0x00, 0x00, 0x05, // packets
+        0x00, 0x00, 0x05, 0xdc, // bytes
+        0x00, 0x00, 0x13, 0x88, // first 5000 ms
+        0x00, 0x00, 0x1b, 0x58, // last 7000 ms
+        0x04, 0xd2, 0x00, 0x50, // ports
+        0x00, 0x1b, 0x06, 0x00, // pad, TCP flags, protocol, ToS
+        0xfd, 0xe8, 0xfd, 0xe9, // src and dst AS
+        0x18, 0x10, 0x00, 0x00, // masks, pad
+    ];
+
+    fn v9_header(source_id: u32) -> Vec<u8> {
+        let mut packet = vec![0x00, 0x09, 0x00, 0x00];
+        packet.extend_from_slice(&10_000u32.to_be_bytes()); // sys uptime
+        packet.extend_from_slice(&1_700_000_000u32.to_be_bytes()); // unix secs
+        packet.extend_from_slice(&7u32.to_be_bytes()); // sequence
+        packet.extend_from_slice(&source_id.to_be_bytes());
+        packet
+    }
+
+    fn ipfix_message(sets: &[Vec<u8>]) -> Vec<u8> {
+        let body: Vec<u8> = sets.concat();
+        let mut message = vec![0x00, 0x0a];
+        message.extend_from_slice(&(16 + body.len() as u16).to_be_bytes());
+        message.extend_from_slice(&1_700_000_000u32.to_be_bytes()); // export time
+        message.extend_from_slice(&3u32.to_be_bytes()); // sequence
+        message.extend_from_slice(&1u32.to_be_bytes()); // observation domain
+        message.extend_from_slice(&body);
+        message
+    }
+
+    fn set(id: u16, content: &[u8]) -> Vec<u8> {
+        let mut set = id.to_be_bytes().to_vec();
+        set.extend_from_slice(&(4 + content.len() as u16).to_be_bytes());
+        set.extend_from_slice(content);
+        set
+    }
+
+    // Template 256: src and dst IPv4, protocol, bytes (4), flow start and end uptime
+    fn v9_template() -> Vec<u8> {
+        let mut template = vec![0x01, 0x00, 0x00, 0x06];
+        for (id, length) in [(8u16, 4u16), (12, 4), (4, 1), (1, 4), (22, 4), (21, 4)] {
+            template.extend_from_slice(&id.to_be_bytes());
+            template.extend_from_slice(&length.to_be_bytes());
+        }
+        set(V9_TEMPLATE_SET_ID, &template)
+    }
+
+    fn v9_data() -> Vec<u8> {
+        let mut record = vec![10, 0, 0, 1, 10, 0, 0, 2, 17];
+        record.extend_from_slice(&1500u32.to_be_bytes());
+        record.extend_from_slice(&4000u32.to_be_bytes());
+        record.extend_from_slice(&9000u32.to_be_bytes());
+        record.extend_from_slice(&[0, 0, 0]); // padding
+        set(256, &record)
+    }
+
+    #[test]
+    fn v5() {
+        let mut cache = TemplateCache::default();
+        let packet = decode(&V5_PACKET, EXPORTER, &mut cache).unwrap();
+        assert_eq!(packet.version, 5);
+        assert_eq!(packet.sequence_number, 42);
+        assert_eq!(packet.observation_domain_id, 0x0102);
+        assert_eq!(packet.flows.len(), 1);
+
+        let flow = &packet.flows[0];
+        assert_eq!(flow.src_ip, Some("10.0.0.1".parse().unwrap()));
+        assert_eq!(flow.dst_ip, Some("10.0.0.2".parse().unwrap()));
+        assert_eq!((flow.src_port, flow.dst_port, flow.protocol), (1234, 80, 6));
+        assert_eq!((flow.packets, flow.bytes), (5, 1500));
+        assert_eq!(flow.tcp_flags, 0x1b);
+        assert_eq!((flow.src_as, flow.dst_as), (65000, 65001));
+        assert_eq!(flow.sampling_rate, 100);
+        // Boot time is the export time minus the uptime
+        let boot_ms = 1_694_498_816_000 - 10_000;
+        assert_eq!(flow.flow_start_ms, boot_ms + 5000);
+        assert_eq!(flow.flow_end_ms, boot_ms + 7000);
+    }
+
+    #[test]
+    fn v5_truncated_record() {
+        let mut cache = TemplateCache::default();
+        assert!(decode(&V5_PACKET[..60], EXPORTER, &mut cache).is_err());
+    }
+
+    #[test]
+    fn v9_template_before_data() {
+        let mut cache = TemplateCache::default();
+        let packet = [v9_header(1), v9_template(), v9_data()].concat();
+        let packet = decode(&packet, EXPORTER, &mut cache).unwrap();
+        assert_eq!(packet.flows.len(), 1);
+
+        let flow = &packet.flows[0];
+        assert_eq!(flow.src_ip, Some("10.0.0.1".parse().unwrap()));
+        assert_eq!((flow.protocol, flow.bytes), (17, 1500));
+        let boot_ms = 1_700_000_000_000 - 10_000;
+        assert_eq!(flow.flow_start_ms, boot_ms + 4000);
+        assert_eq!(flow.flow_end_ms, boot_ms + 9000);
+    }
+
+    #[test]
+    fn v9_data_before_template() {
+        let mut cache = TemplateCache::default();
+
+        // Skipped until the template arrives, in the same packet or a later one
+        let packet = [v9_header(1), v9_data(), v9_template()].concat();
+        assert!(decode(&packet, EXPORTER, &mut cache)
+            .unwrap()
+            .flows
+            .is_empty());
+        let packet = [v9_header(1), v9_data()].concat();
+        assert_eq!(
+            decode(&packet, EXPORTER, &mut cache).unwrap().flows.len(),
+            1
+        );
+    }
+
+    #[test]
+    fn v9_missing_template() {
+        let mut cache = TemplateCache::default();
+        let packet = [v9_header(1), v9_template()].concat();
+        decode(&packet, EXPORTER, &mut cache).unwrap();
+
+        // Templates are scoped by source ID and exporter
+        let packet = [v9_header(2), v9_data()].concat();
+        assert!(decode(&packet, EXPORTER, &mut cache)
+            .unwrap()
+            .flows
+            .is_empty());
+        let other = "192.0.2.2".parse().unwrap();
+        let packet = [v9_header(1), v9_data()].concat();
+        assert!(decode(&packet, other, &mut cache).unwrap().flows.is_empty());
+    }
+
+    #[test]
+    fn v9_options_template() {
+        let mut cache = TemplateCache::default();
+        // Template 257: system scope (4 bytes), sampling interval option (4 bytes)
+        let options_template = set(
+            V9_OPTIONS_TEMPLATE_SET_ID,
+            &[
+                0x01, 0x01, 0x00, 0x04, 0x00, 0x04, 0x00, 0x01, 0x00, 0x04, 0x00, 0x22, 0x00, 0x04,
+            ],
+        );
+        let options_data = set(257, &[0, 0, 0, 1, 0, 0, 0, 100]);
+        let packet = [
+            v9_header(1),
+            options_template,
+            options_data,
+            v9_template(),
+            v9_data(),
+        ]
+        .concat();
+
+        // Only the flow data record is decoded
+        let packet = decode(&packet, EXPORTER, &mut cache).unwrap();
+        assert_eq!(packet.flows.len(), 1);
+        assert_eq!(packet.flows[0].protocol, 17);
+    }
+
+    #[test]
+    fn v9_truncated_flowset() {
+        let mut cache = TemplateCache::default();
+        let packet = [v9_header(1), v9_template(), v9_data()].concat();
+        assert!(decode(&packet[..packet.len() - 8], EXPORTER, &mut cache).is_err());
+    }
+
+    // Template 300: src IPv6, interfaceName (variable length), an enterprise
+    // field and flow start and end seconds
+    fn ipfix_template() -> Vec<u8> {
+        let mut template = vec![0x01, 0x2c, 0x00, 0x05];
+        template.extend_from_slice(&[0x00, 0x1b, 0x00, 0x10]);
+        template.extend_from_slice(&[0x00, 0x52, 0xff, 0xff]);
+        template.extend_from_slice(&[0x80, 0x01, 0x00, 0x02, 0x00, 0x00, 0x12, 0x34]);
+        template.extend_from_slice(&[0x00, 0x96, 0x00, 0x04]);
+        template.extend_from_slice(&[0x00, 0x97, 0x00, 0x08]);
+        set(IPFIX_TEMPLATE_SET_ID, &template)
+    }
+
+    fn ipfix_record(interface_name: &[u8]) -> Vec<u8> {
+        let mut record = "2001:db8::1".parse::<Ipv6Addr>().unwrap().octets().to_vec();
+        if interface_name.len() < 255 {
+            record.push(interface_name.len() as u8);
+        } else {
+            record.push(255);
+            record.extend_from_slice(&(interface_name.len() as u16).to_be_bytes());
+        }
+        record.extend_from_slice(interface_name);
+        record.extend_from_slice(&[0xab, 0xcd]);
+        record.extend_from_slice(&1_700_000_000u32.to_be_bytes());
+        record.extend_from_slice(&u64::MAX.to_be_bytes());
+        record
+    }
+
+    #[test]
+    fn ipfix_variable_length() {
+        let mut cache = TemplateCache::default();
+        let records = [ipfix_record(b"et-0/0/1"), ipfix_record(&[b'x'; 300])].concat();
+        let message = ipfix_message(&[ipfix_template(), set(300, &records)]);
+
+        let packet = decode(&message, EXPORTER, &mut cache).unwrap();
+        assert_eq!(packet.version, 10);
+        assert_eq!(packet.flows.len(), 2);
+        for flow in &packet.flows {
+            assert_eq!(flow.src_ip, Some("2001:db8::1".parse().unwrap()));
+            assert_eq!(flow.flow_start_ms, 1_700_000_000_000);
+            // Saturated rather than overflowing
+            assert_eq!(flow.flow_end_ms, u64::MAX);
+        }
+    }
+
+    #[test]
+    fn ipfix_options_template() {
+        let mut cache = TemplateCache::default();
+        // Template 301: one scope field (observation domain), one option field
+        let options_template = set(
+            IPFIX_OPTIONS_TEMPLATE_SET_ID,
+            &[
+                0x01, 0x2d, 0x00, 0x02, 0x00, 0x01, 0x00, 0x95, 0x00, 0x04, 0x01, 0x31, 0x00, 0x04,
+            ],
+        );
+        let options_data = set(301, &[0, 0, 0, 1, 0, 0, 0, 100]);
+        let message = ipfix_message(&[options_template, options_data]);
+        assert!(decode(&message, EXPORTER, &mut cache)
+            .unwrap()
+            .flows
+            .is_empty());
+    }
+
+    #[test]
+    fn ipfix_template_withdrawal() {
+        let mut cache = TemplateCache::default();
+        let data = set(300, &ipfix_record(b"ae0"));
+        let message = ipfix_message(&[ipfix_template(), data.clone()]);
+        assert_eq!(
+            decode(&message, EXPORTER, &mut cache).unwrap().flows.len(),
+            1
+        );
+
+        let withdrawal = set(IPFIX_TEMPLATE_SET_ID, &[0x01, 0x2c, 0x00, 0x00]);
+        let message = ipfix_message(&[withdrawal, data]);
+        assert!(decode(&message, EXPORTER, &mut cache)
+            .unwrap()
+            .flows
+            .is_empty());
+    }
+
+    #[test]
+    fn ipfix_truncated() {
+        let mut cache = TemplateCache::default();
+        let message = ipfix_message(&[ipfix_template(), set(300, &ipfix_record(b"ae0"))]);
+
+        // Message length larger than the datagram
+        assert!(decode(&message[..message.len() - 1], EXPORTER, &mut cache).is_err());
+
+        // Variable-length field running past the end of the set
+        let mut record = ipfix_record(b"ae0");
+        record[16] = 200;
+        let message = ipfix_message(&[ipfix_template(), set(300, &record)]);
+        assert!(decode(&message, EXPORTER, &mut cache).is_err());
+    }
+
+    #[test]
+    fn template_limit() {
+        let mut cache = TemplateCache::default();
+        let domain = (EXPORTER, 10, 1);
+        for id in 0..=MAX_TEMPLATES_PER_EXPORTER as u16 {
+            let template = Template {
+                fields: Vec::new(),
+                options: false,
+                updated: Instant::now(),
+            };
+            cache.insert(domain, MIN_DATA_SET_ID + id, template);
+        }
+        assert!(cache.get(domain, MIN_DATA_SET_ID).is_some());
+        let last = MIN_DATA_SET_ID + MAX_TEMPLATES_PER_EXPORTER as u16;
+        assert!(cache.get(domain, last).is_none());
+    }
+}
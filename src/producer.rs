@@ -1,16 +1,54 @@
 use anyhow::Result;
 use metrics::counter;
 use rdkafka::config::ClientConfig;
-use rdkafka::message::OwnedHeaders;
+use rdkafka::error::{KafkaError, KafkaResult};
+use rdkafka::message::{Header, OwnedHeaders};
 use rdkafka::producer::{FutureProducer, FutureRecord};
-use sflow_parser::SFlowDatagram;
-use std::net::SocketAddr;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc::Receiver;
-use tracing::{debug, error, trace};
+use tokio::sync::watch;
+use tokio::task::JoinSet;
+use tracing::{debug, error, info, trace, warn};
 
-use crate::config::KafkaConfig;
-use crate::serializer::serialize_sflow_record;
+use crate::batch::{BatchMessage, Batcher, RecordType};
+use crate::config::{AppConfig, KafkaConfig, KeyStrategy};
+use crate::retry::RetryQueue;
+use crate::serializer::{SerializedRecords, SCHEMA_VERSION};
+use crate::spool::Spool;
+
+// Kafka message headers
+//
+// All values are UTF-8 strings. Header names are stable: headers may be added,
+// existing ones are never renamed or removed.
+//
+//   pesto-schema                Cap'n Proto struct of the records in schemas/sflow.capnp
+//                               (SFlowFlowRecord, SFlowCounterRecord, SFlowDropRecord
+//                               or NetFlowRecord)
+//   pesto-schema-version        Version of the schema, bumped on incompatible changes
+//   pesto-record-count          Number of records in the message
+//   pesto-version               Version of pesto
+//   pesto-hostname              Hostname of the collector
+//   pesto-time-received-min-ns  Earliest record receive timestamp (ns since UNIX epoch)
+//   pesto-time-received-max-ns  Latest record receive timestamp (ns since UNIX epoch)
+//   pesto-agent-addr            Agent address, only if all the records come from this agent
+const HEADER_SCHEMA: &str = "pesto-schema";
+const HEADER_SCHEMA_VERSION: &str = "pesto-schema-version";
+const HEADER_RECORD_COUNT: &str = "pesto-record-count";
+const HEADER_VERSION: &str = "pesto-version";
+const HEADER_HOSTNAME: &str = "pesto-hostname";
+const HEADER_TIME_RECEIVED_MIN_NS: &str = "pesto-time-received-min-ns";
+const HEADER_TIME_RECEIVED_MAX_NS: &str = "pesto-time-received-max-ns";
+const HEADER_AGENT_ADDR: &str = "pesto-agent-addr";
+
+// How long the messages in flight may take to be delivered at shutdown
+const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+// Message timeout of the spool replays (ms), a replay probing an unreachable
+// Kafka fails fast whatever the message timeout of the live batches
+const REPLAY_TIMEOUT_MS: usize = 10000;
 
 #[derive(Clone)]
 pub struct SaslAuth {
@@ -19,24 +57,50 @@ pub struct SaslAuth {
     pub mechanism: String,
 }
 
+#[derive(Clone)]
+pub struct SslAuth {
+    pub ca_location: Option<PathBuf>,
+    pub certificate_location: Option<PathBuf>,
+    pub key_location: Option<PathBuf>,
+    pub key_password: Option<String>,
+    pub verify_hostname: bool,
+}
+
 #[derive(Clone)]
 pub enum KafkaAuth {
     SaslPlainText(SaslAuth),
+    SaslSsl(SaslAuth, SslAuth),
+    Ssl(SslAuth),
     PlainText,
 }
 
 pub async fn handle(
-    config: &KafkaConfig,
-    mut rx: Receiver<(SFlowDatagram, i64, SocketAddr)>,
+    mut config_rx: watch::Receiver<Arc<AppConfig>>,
+    mut rx: Receiver<SerializedRecords>,
 ) -> Result<()> {
+    let mut config = config_rx.borrow_and_update().kafka.clone();
+
     // Configure Kafka authentication
+    let sasl_auth = SaslAuth {
+        username: config.auth_sasl_username.clone(),
+        password: config.auth_sasl_password.expose().to_string(),
+        mechanism: config.auth_sasl_mechanism.clone(),
+    };
+    let ssl_auth = SslAuth {
+        ca_location: config.auth_ssl_ca_location.clone(),
+        certificate_location: config.auth_ssl_certificate_location.clone(),
+        key_location: config.auth_ssl_key_location.clone(),
+        key_password: config
+            .auth_ssl_key_password
+            .as_ref()
+            .map(|password| password.expose().to_string()),
+        verify_hostname: config.auth_ssl_verify_hostname,
+    };
     let kafka_auth = match config.auth_protocol.as_str() {
         "PLAINTEXT" => KafkaAuth::PlainText,
-        "SASL_PLAINTEXT" => KafkaAuth::SaslPlainText(SaslAuth {
-            username: config.auth_sasl_username.clone(),
-            password: config.auth_sasl_password.clone(),
-            mechanism: config.auth_sasl_mechanism.clone(),
-        }),
+        "SASL_PLAINTEXT" => KafkaAuth::SaslPlainText(sasl_auth),
+        "SSL" => KafkaAuth::Ssl(ssl_auth),
+        "SASL_SSL" => KafkaAuth::SaslSsl(sasl_auth, ssl_auth),
         _ => {
             anyhow::bail!("invalid Kafka producer authentication protocol");
         }
@@ -44,7 +108,7 @@ pub async fn handle(
 
     if config.disable {
         debug!("producer disabled");
-        while let Some(_datagram) = rx.recv().await {
+        while let Some(_records) = rx.recv().await {
             // Just consume and drop
         }
         return Ok(());
@@ -71,33 +135,90 @@ pub async fn handle(
         .set("request.timeout.ms", "10000")
         .set("debug", "broker,security");
 
-    if let KafkaAuth::SaslPlainText(auth) = kafka_auth {
-        client_config = client_config
-            .set("sasl.username", auth.username)
-            .set("sasl.password", auth.password)
-            .set("sasl.mechanisms", auth.mechanism)
-            .set("security.protocol", "SASL_PLAINTEXT")
-            .to_owned();
+    match kafka_auth {
+        KafkaAuth::PlainText => {}
+        KafkaAuth::SaslPlainText(sasl) => {
+            set_sasl_auth(&mut client_config, sasl);
+            client_config.set("security.protocol", "SASL_PLAINTEXT");
+        }
+        KafkaAuth::Ssl(ssl) => {
+            set_ssl_auth(&mut client_config, ssl)?;
+            client_config.set("security.protocol", "SSL");
+        }
+        KafkaAuth::SaslSsl(sasl, ssl) => {
+            set_sasl_auth(&mut client_config, sasl);
+            set_ssl_auth(&mut client_config, ssl)?;
+            client_config.set("security.protocol", "SASL_SSL");
+        }
     }
 
     let producer: FutureProducer = client_config
         .create()
         .expect("Failed to create Kafka producer");
+    let replay_producer = match config.spool_dir {
+        Some(_) => {
+            let replay_timeout_ms = config.message_timeout_ms.min(REPLAY_TIMEOUT_MS);
+            // One request in flight per broker keeps the replay window in order
+            client_config
+                .set("message.timeout.ms", replay_timeout_ms.to_string())
+                .set("max.in.flight.requests.per.connection", "1");
+            let replay_producer: FutureProducer = client_config
+                .create()
+                .expect("Failed to create Kafka producer");
+            Some(replay_producer)
+        }
+        None => None,
+    };
+    let producer = Producer {
+        producer,
+        replay_producer,
+        hostname: hostname(),
+    };
 
-    // Send to Kafka
-    let mut additional_messages: Vec<Vec<u8>> = Vec::new();
+    // Send to Kafka, one batcher per topic
+    //
+    // Messages are handed to librdkafka without waiting for their delivery, the
+    // delivery reports come back through `deliveries` so that a slow or
+    // unreachable Kafka never blocks the reception of the records.
+    warn_key_strategy(&config);
+    let mut batchers = Batchers::new(&config);
+    let mut deliveries = JoinSet::new();
+    let mut retry_queue = RetryQueue::new(&config);
+    let mut spool = match &config.spool_dir {
+        Some(dir) => Some(Spool::open(dir, &config).await?),
+        None => None,
+    };
     loop {
-        let start_time = std::time::Instant::now();
-        let mut final_message = Vec::new();
-        let mut n_records = 0;
+        // Apply the reloaded configuration between two batches
+        if config_rx.has_changed().unwrap_or(false) {
+            // Send the pending records with the previous settings
+            for batcher in batchers.iter_mut() {
+                batcher.flush();
+                send_batches(&producer, batcher, &mut deliveries, &mut spool).await;
+            }
 
-        // Send the additional messages first
-        for message in additional_messages.drain(..) {
-            final_message.extend_from_slice(&message);
-            n_records += 1;
+            config = config_rx.borrow_and_update().kafka.clone();
+            warn_key_strategy(&config);
+            batchers = Batchers::new(&config);
+            info!("Kafka producer configuration reloaded");
         }
 
+        let start_time = std::time::Instant::now();
+
         loop {
+            // Send the failed batches due for a retry
+            while let Some((batch, attempts)) = retry_queue.pop_due() {
+                let sent = Sent::Live {
+                    attempts: attempts + 1,
+                };
+                send_batch(&producer, batch, sent, &mut deliveries);
+            }
+
+            // Replay the spooled batches once Kafka is reachable again
+            if let Some(spool) = spool.as_mut() {
+                replay_spool(&producer, spool, &mut deliveries).await;
+            }
+
             let remaining_time = config.batch_wait_time.saturating_sub(
                 std::time::Instant::now()
                     .duration_since(start_time)
@@ -108,19 +229,41 @@ pub async fn handle(
                 break;
             }
 
-            let datagram = tokio::time::timeout(
-                Duration::from_millis(remaining_time.min(config.batch_wait_interval)),
-                rx.recv(),
-            )
-            .await;
-
-            let datagram = match datagram {
-                Ok(Some(d)) => {
-                    trace!("Received datagram from channel");
-                    d
+            let wait_time = Duration::from_millis(remaining_time.min(config.batch_wait_interval));
+            let records = tokio::select! {
+                Some(joined) = deliveries.join_next(), if !deliveries.is_empty() => {
+                    match joined {
+                        Ok(delivery) => delivered(delivery, &mut retry_queue, &mut spool).await,
+                        Err(err) => error!("Kafka delivery task failed: {}", err),
+                    }
+                    continue;
                 }
+                records = tokio::time::timeout(wait_time, rx.recv()) => records,
+            };
+
+            let records = match records {
+                Ok(Some(records)) => records,
                 Ok(None) => {
                     error!("sFlow channel closed");
+                    // Send what is left before exiting
+                    for batcher in batchers.iter_mut() {
+                        batcher.flush();
+                        send_batches(&producer, batcher, &mut deliveries, &mut spool).await;
+                    }
+                    let pending = async {
+                        while let Some(joined) = deliveries.join_next().await {
+                            if let Ok(delivery) = joined {
+                                delivered(delivery, &mut retry_queue, &mut spool).await;
+                            }
+                        }
+                    };
+                    if tokio::time::timeout(SHUTDOWN_TIMEOUT, pending)
+                        .await
+                        .is_err()
+                    {
+                        error!("Kafka messages still in flight at shutdown");
+                    }
+                    retry_queue.drain().await;
                     return Ok(());
                 }
                 Err(_) => {
@@ -130,63 +273,412 @@ pub async fn handle(
                 }
             };
 
-            let (datagram, time_received_ns, peer_addr) = datagram;
             trace!(
-                "Processing datagram from {} with {} samples",
-                peer_addr,
-                datagram.samples.len()
+                "Received {} flow, {} counter, {} drop and {} NetFlow messages",
+                records.flows.len(),
+                records.counters.len(),
+                records.drops.len(),
+                records.netflows.len()
             );
 
-            // Serialize the sFlow records (only flow samples)
-            let messages = serialize_sflow_record(&datagram, time_received_ns, peer_addr);
-            trace!("Serialized {} messages from datagram", messages.len());
+            batchers.flows(records.flows_topic).extend(records.flows);
+            batchers.counters.extend(records.counters);
+            batchers.drops.extend(records.drops);
+            batchers.netflows.extend(records.netflows);
 
-            for message in messages {
-                // Max message size check
-                if final_message.len() + message.len() > config.message_max_bytes {
-                    additional_messages.push(message);
-                    break;
-                }
-
-                final_message.extend_from_slice(&message);
-                n_records += 1;
+            // Send the batches that reached the max message size right away
+            for batcher in batchers.iter_mut() {
+                send_batches(&producer, batcher, &mut deliveries, &mut spool).await;
             }
         }
 
-        if final_message.is_empty() {
-            trace!("Batch timeout reached but no messages collected, continuing");
-            continue;
+        // Batch wait time reached, send the pending records
+        for batcher in batchers.iter_mut() {
+            batcher.flush();
+            send_batches(&producer, batcher, &mut deliveries, &mut spool).await;
+        }
+    }
+}
+
+// One batcher per topic, the flow records of the sFlow listeners with a topic
+// override get their own batcher
+struct Batchers {
+    message_max_bytes: usize,
+    flows: Batcher,
+    counters: Batcher,
+    drops: Batcher,
+    netflows: Batcher,
+    listener_flows: HashMap<String, Batcher>,
+}
+
+impl Batchers {
+    fn new(config: &KafkaConfig) -> Self {
+        Batchers {
+            message_max_bytes: config.message_max_bytes,
+            flows: Batcher::new(RecordType::Flow, &config.topic, config.message_max_bytes),
+            counters: Batcher::new(
+                RecordType::Counter,
+                &config.counters_topic,
+                config.message_max_bytes,
+            ),
+            drops: Batcher::new(
+                RecordType::Drop,
+                &config.drops_topic,
+                config.message_max_bytes,
+            ),
+            netflows: Batcher::new(
+                RecordType::NetFlow,
+                &config.netflow_topic,
+                config.message_max_bytes,
+            ),
+            listener_flows: HashMap::new(),
+        }
+    }
+
+    fn flows(&mut self, topic: Option<String>) -> &mut Batcher {
+        match topic {
+            Some(topic) => {
+                let message_max_bytes = self.message_max_bytes;
+                self.listener_flows
+                    .entry(topic)
+                    .or_insert_with_key(|topic| {
+                        Batcher::new(RecordType::Flow, topic, message_max_bytes)
+                    })
+            }
+            None => &mut self.flows,
         }
+    }
+
+    fn iter_mut(&mut self) -> impl Iterator<Item = &mut Batcher> {
+        [
+            &mut self.flows,
+            &mut self.counters,
+            &mut self.drops,
+            &mut self.netflows,
+        ]
+        .into_iter()
+        .chain(self.listener_flows.values_mut())
+    }
+}
+
+// Kafka producers and the collector hostname sent in the message headers
+struct Producer {
+    producer: FutureProducer,
+    // Short message timeout, only with a spool
+    replay_producer: Option<FutureProducer>,
+    hostname: String,
+}
 
-        debug!(
-            "sending {} sFlow records to Kafka (message size: {} bytes)",
-            n_records,
-            final_message.len()
+// Records are batched by key, and flows rarely share their 5-tuple
+fn warn_key_strategy(config: &KafkaConfig) {
+    if config.key == KeyStrategy::FiveTuple {
+        warn!(
+            "Kafka messages keyed by 5-tuple hold about one flow record each, \
+             expect many more and smaller messages than with the other key strategies"
         );
-        let delivery_status = producer
-            .send(
-                FutureRecord::to(config.topic.as_str())
-                    .payload(&final_message)
-                    .key("")
-                    .headers(OwnedHeaders::new()),
-                Duration::from_secs(10),
-            )
-            .await;
-
-        let metric_name = "pesto_kafka_messages_total";
-        match delivery_status {
-            Ok(delivery) => {
-                counter!(metric_name, "status" => "success").increment(1);
-                counter!("pesto_sflow_records_total").increment(n_records);
-                debug!(
-                    "successfully sent message to partition {} at offset {}",
-                    delivery.partition, delivery.offset
-                );
+    }
+}
+
+// Hostname of the kernel, or of the environment outside Linux
+fn hostname() -> String {
+    std::fs::read_to_string("/proc/sys/kernel/hostname")
+        .ok()
+        .map(|hostname| hostname.trim_end().to_string())
+        .or_else(|| std::env::var("HOSTNAME").ok())
+        .filter(|hostname| !hostname.is_empty())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+fn batch_headers(batch: &BatchMessage, hostname: &str) -> OwnedHeaders {
+    let mut values = vec![
+        (HEADER_SCHEMA, batch.record_type.schema().to_string()),
+        (HEADER_SCHEMA_VERSION, SCHEMA_VERSION.to_string()),
+        (HEADER_RECORD_COUNT, batch.n_records.to_string()),
+        (HEADER_VERSION, env!("CARGO_PKG_VERSION").to_string()),
+        (HEADER_HOSTNAME, hostname.to_string()),
+        (
+            HEADER_TIME_RECEIVED_MIN_NS,
+            batch.min_time_received_ns.to_string(),
+        ),
+        (
+            HEADER_TIME_RECEIVED_MAX_NS,
+            batch.max_time_received_ns.to_string(),
+        ),
+    ];
+    if let Some(agent_addr) = batch.agent_addr {
+        values.push((HEADER_AGENT_ADDR, agent_addr.to_string()));
+    }
+
+    let mut headers = OwnedHeaders::new_with_capacity(values.len());
+    for (key, value) in values {
+        headers = headers.insert(Header {
+            key,
+            value: Some(&value),
+        });
+    }
+    headers
+}
+
+fn set_sasl_auth(client_config: &mut ClientConfig, auth: SaslAuth) {
+    client_config
+        .set("sasl.username", auth.username)
+        .set("sasl.password", auth.password)
+        .set("sasl.mechanisms", auth.mechanism);
+}
+
+fn set_ssl_auth(client_config: &mut ClientConfig, auth: SslAuth) -> Result<()> {
+    // A client certificate requires its private key, and the other way around
+    if auth.certificate_location.is_some() != auth.key_location.is_some() {
+        anyhow::bail!("Kafka SSL client certificate and key must be set together");
+    }
+
+    if let Some(ca_location) = auth.ca_location {
+        client_config.set("ssl.ca.location", ca_location.to_string_lossy());
+    }
+    if let Some(certificate_location) = auth.certificate_location {
+        client_config.set(
+            "ssl.certificate.location",
+            certificate_location.to_string_lossy(),
+        );
+    }
+    if let Some(key_location) = auth.key_location {
+        client_config.set("ssl.key.location", key_location.to_string_lossy());
+    }
+    if let Some(key_password) = auth.key_password {
+        client_config.set("ssl.key.password", key_password);
+    }
+
+    let endpoint_identification = if auth.verify_hostname {
+        "https"
+    } else {
+        "none"
+    };
+    client_config.set(
+        "ssl.endpoint.identification.algorithm",
+        endpoint_identification,
+    );
+    Ok(())
+}
+
+// How a batch was sent, to know what to do with its delivery report
+#[derive(Debug, Clone, Copy)]
+enum Sent {
+    // From a batcher or the retry queue, for the `attempts`-th time
+    Live { attempts: u32 },
+    // From the spool, with the sequence number of its replay
+    Replay { seq: u64 },
+}
+
+// Delivery report of a batch
+struct Delivery {
+    batch: BatchMessage,
+    sent: Sent,
+    result: KafkaResult<(i32, i64)>,
+}
+
+async fn send_batches(
+    producer: &Producer,
+    batcher: &mut Batcher,
+    deliveries: &mut JoinSet<Delivery>,
+    spool: &mut Option<Spool>,
+) {
+    while let Some(batch) = batcher.pop() {
+        // While the spool is not empty, Kafka is unreachable or being caught up:
+        // spool the batch to keep the order and avoid waiting on Kafka
+        if let Some(spool) = spool.as_mut() {
+            if !spool.is_empty() {
+                spool.append(&batch).await;
+                continue;
+            }
+        }
+
+        send_batch(producer, batch, Sent::Live { attempts: 1 }, deliveries);
+    }
+}
+
+// Send the oldest spooled batches, up to the replay window so that the spool
+// drains faster than the new batches are spooled
+async fn replay_spool(producer: &Producer, spool: &mut Spool, deliveries: &mut JoinSet<Delivery>) {
+    while spool.replay_due() {
+        let Some((batch, seq)) = spool.next_replay().await else {
+            break;
+        };
+        send_batch(producer, batch, Sent::Replay { seq }, deliveries);
+    }
+}
+
+// Hand a batch to librdkafka, its delivery report goes to `deliveries`
+fn send_batch(
+    producer: &Producer,
+    batch: BatchMessage,
+    sent: Sent,
+    deliveries: &mut JoinSet<Delivery>,
+) {
+    debug!(
+        "sending {} sFlow {} records to Kafka topic {} (message size: {} bytes)",
+        batch.n_records,
+        batch.record_type,
+        batch.topic,
+        batch.payload.len()
+    );
+    let record = FutureRecord::to(batch.topic.as_str())
+        .payload(&batch.payload)
+        .key(batch.key.as_slice())
+        .headers(batch_headers(&batch, &producer.hostname));
+    let kafka_producer = match (sent, &producer.replay_producer) {
+        (Sent::Replay { .. }, Some(replay_producer)) => replay_producer,
+        _ => &producer.producer,
+    };
+    // Fails right away if the librdkafka queue is full
+    let delivery_future = kafka_producer
+        .send_result(record)
+        .map_err(|(error, _)| error);
+
+    deliveries.spawn(async move {
+        let result = match delivery_future {
+            Ok(delivery_future) => match delivery_future.await {
+                Ok(Ok(delivery)) => Ok((delivery.partition, delivery.offset)),
+                Ok(Err((error, _))) => Err(error),
+                Err(_) => Err(KafkaError::Canceled),
+            },
+            Err(error) => Err(error),
+        };
+        Delivery {
+            batch,
+            sent,
+            result,
+        }
+    });
+}
+
+// Count and log a delivery report
+fn count_delivery(delivery: &Delivery) -> KafkaResult<()> {
+    let metric_name = "pesto_kafka_messages_total";
+    match &delivery.result {
+        Ok((partition, offset)) => {
+            counter!(metric_name, "status" => "success").increment(1);
+            counter!("pesto_sflow_records_total", "type" => delivery.batch.record_type.label())
+                .increment(delivery.batch.n_records);
+            debug!(
+                "successfully sent message to partition {} at offset {}",
+                partition, offset
+            );
+            Ok(())
+        }
+        Err(error) => {
+            counter!(metric_name, "status" => "failure").increment(1);
+            error!("failed to send message: {}", error);
+            Err(error.clone())
+        }
+    }
+}
+
+// Spool or retry the batches that failed to be delivered, and move the spool
+// replay forward
+async fn delivered(delivery: Delivery, retry_queue: &mut RetryQueue, spool: &mut Option<Spool>) {
+    let success = count_delivery(&delivery).is_ok();
+    match (delivery.sent, spool.as_mut()) {
+        (Sent::Replay { seq }, Some(spool)) => {
+            if !success {
+                spool.abort_replay(seq);
+                return;
             }
-            Err((error, _)) => {
-                counter!(metric_name, "status" => "failure").increment(1);
-                error!("failed to send message: {}", error);
+            spool.replayed(seq).await;
+            if spool.is_empty() {
+                info!("spool replay complete");
             }
         }
+        (_, _) if success => {}
+        (_, Some(spool)) => {
+            spool.append(&delivery.batch).await;
+            spool.defer_replay();
+        }
+        (Sent::Live { attempts }, None) => retry_queue.push(delivery.batch, attempts).await,
+        (Sent::Replay { .. }, None) => unreachable!("spool replay without a spool"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+    use crate::serializer::SerializedRecord;
+    use tokio::sync::mpsc;
+
+    // Nothing listens on port 1, the broker is down for the whole test
+    async fn start(
+        args: &[&str],
+    ) -> (
+        mpsc::Sender<SerializedRecords>,
+        watch::Sender<Arc<AppConfig>>,
+    ) {
+        let mut args = args.to_vec();
+        args.extend([
+            "--kafka-brokers",
+            "127.0.0.1:1",
+            "--kafka-mpsc-buffer-size",
+            "1",
+        ]);
+        let config = config::from_args(&args).await.unwrap();
+        let (tx, rx) = mpsc::channel(config.kafka.mpsc_buffer_size);
+        let (config_tx, config_rx) = watch::channel(Arc::new(config));
+        tokio::spawn(handle(config_rx, rx));
+        (tx, config_tx)
+    }
+
+    fn records(n: usize) -> SerializedRecords {
+        let flows = (0..n)
+            .map(|_| SerializedRecord {
+                key: Vec::new(),
+                payload: vec![0; 100],
+                time_received_ns: 0,
+                agent_addr: None,
+            })
+            .collect();
+        SerializedRecords {
+            flows,
+            ..Default::default()
+        }
+    }
+
+    // Send records for `duration`, as a sFlow worker does, each send failing
+    // the test if the producer stops draining the channel
+    async fn ingest(tx: &mpsc::Sender<SerializedRecords>, duration: Duration) {
+        let start = tokio::time::Instant::now();
+        while start.elapsed() < duration {
+            tokio::time::timeout(Duration::from_secs(1), tx.send(records(10)))
+                .await
+                .expect("producer stopped draining the records")
+                .unwrap();
+            tokio::time::sleep(Duration::from_millis(1)).await;
+        }
+    }
+
+    #[tokio::test]
+    async fn drains_while_broker_down() {
+        let (tx, _config_tx) = start(&["--kafka-retry-max-attempts", "3"]).await;
+        ingest(&tx, Duration::from_secs(3)).await;
+    }
+
+    #[tokio::test]
+    async fn drains_while_broker_down_with_spool() {
+        let dir = std::env::temp_dir().join(format!("pesto-producer-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let (tx, _config_tx) = start(&[
+            "--kafka-spool-dir",
+            dir.to_str().unwrap(),
+            "--kafka-message-timeout-ms",
+            "500",
+        ])
+        .await;
+
+        // The batches that fail are spooled, and the next ones spooled directly
+        ingest(&tx, Duration::from_secs(3)).await;
+        let spooled: u64 = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().metadata().unwrap().len())
+            .sum();
+        assert!(spooled > 0);
+        let _ = std::fs::remove_dir_all(&dir);
     }
 }
@@ -0,0 +1,116 @@
+use anyhow::Result;
+use metrics::counter;
+use std::sync::Arc;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::watch;
+use tracing::{debug, error, info};
+
+use crate::config::{self, AppConfig};
+
+const MAX_REQUEST_SIZE: usize = 8192;
+
+/// Read the configuration again and hand it to the running tasks
+///
+/// The configuration is rejected as a whole if a setting that is not
+/// reloadable changed.
+pub async fn reload(config_tx: &watch::Sender<Arc<AppConfig>>) -> Result<()> {
+    let result = async {
+        let current = config_tx.borrow().clone();
+        let new_config = config::reload(&current).await?;
+        config::check_reload(&current, &new_config)?;
+        config_tx.send_replace(Arc::new(new_config));
+        Ok(())
+    }
+    .await;
+
+    let metric_name = "pesto_config_reloads_total";
+    match &result {
+        Ok(()) => {
+            counter!(metric_name, "status" => "success").increment(1);
+            info!("configuration reloaded");
+        }
+        Err(err) => {
+            counter!(metric_name, "status" => "failure").increment(1);
+            error!("configuration not reloaded: {}", err);
+        }
+    }
+    result
+}
+
+/// Reload the configuration on SIGHUP
+pub async fn handle_signals(config_tx: Arc<watch::Sender<Arc<AppConfig>>>) -> Result<()> {
+    let mut sighup = signal(SignalKind::hangup())?;
+    while sighup.recv().await.is_some() {
+        debug!("received SIGHUP");
+        // Errors are logged, the current configuration stays in place
+        let _ = reload(&config_tx).await;
+    }
+    Ok(())
+}
+
+/// Minimal HTTP admin endpoint, `POST /reload` reloads the configuration
+pub async fn handle_admin(
+    listener: TcpListener,
+    config_tx: Arc<watch::Sender<Arc<AppConfig>>>,
+) -> Result<()> {
+    debug!("admin listener bound to {}", listener.local_addr()?);
+    loop {
+        let (stream, peer_addr) = listener.accept().await?;
+        let config_tx = config_tx.clone();
+        tokio::spawn(async move {
+            if let Err(err) = handle_admin_request(stream, &config_tx).await {
+                error!("failed to handle admin request from {}: {}", peer_addr, err);
+            }
+        });
+    }
+}
+
+async fn handle_admin_request(
+    mut stream: TcpStream,
+    config_tx: &watch::Sender<Arc<AppConfig>>,
+) -> Result<()> {
+    // Only the request line is needed, read until the end of the headers
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|window| window == b"\r\n\r\n") {
+        let n_bytes = stream.read(&mut buf).await?;
+        if n_bytes == 0 || request.len() + n_bytes > MAX_REQUEST_SIZE {
+            break;
+        }
+        request.extend_from_slice(&buf[..n_bytes]);
+    }
+
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or_default();
+    debug!("admin request: {} {}", method, path);
+
+    let (status, body) = match (method, path) {
+        ("POST", "/reload") => match reload(config_tx).await {
+            Ok(()) => ("200 OK", "configuration reloaded\n".to_string()),
+            Err(err) => (
+                "409 Conflict",
+                format!("configuration not reloaded: {}\n", err),
+            ),
+        },
+        (_, "/reload") => ("405 Method Not Allowed", "use POST\n".to_string()),
+        _ => ("404 Not Found", "not found\n".to_string()),
+    };
+
+    let response = format!(
+        "HTTP/1.1 {}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await?;
+    Ok(())
+}
@@ -0,0 +1,167 @@
+use metrics::{counter, gauge};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use tokio::io::AsyncWriteExt;
+use tokio::time::{Duration, Instant};
+use tracing::{debug, error, warn};
+
+use crate::batch::BatchMessage;
+use crate::config::KafkaConfig;
+
+struct RetryEntry {
+    batch: BatchMessage,
+    attempts: u32,
+    retry_at: Instant,
+}
+
+/// Bounded in-memory queue of Kafka batches waiting to be sent again
+///
+/// Each failed attempt doubles the backoff, up to `retry_backoff_max_ms`.
+/// Batches that exhausted their retries, or that do not fit in the queue,
+/// are handed to the fallback sink.
+///
+/// Newer batches keep being sent while older ones wait for their retry, so a
+/// retried batch lands after them: the per-key ordering of the messages is
+/// lost for the batches that needed a retry.
+pub struct RetryQueue {
+    max_attempts: u32,
+    backoff: Duration,
+    backoff_max: Duration,
+    capacity: usize,
+    entries: VecDeque<RetryEntry>,
+    fallback: FallbackSink,
+}
+
+impl RetryQueue {
+    pub fn new(config: &KafkaConfig) -> Self {
+        RetryQueue {
+            max_attempts: config.retry_max_attempts,
+            backoff: Duration::from_millis(config.retry_backoff_ms),
+            backoff_max: Duration::from_millis(config.retry_backoff_max_ms),
+            capacity: config.retry_queue_size,
+            entries: VecDeque::new(),
+            fallback: FallbackSink::new(config.fallback_dir.clone()),
+        }
+    }
+
+    /// Enqueue a batch after its `attempts`-th failed delivery
+    pub async fn push(&mut self, batch: BatchMessage, attempts: u32) {
+        if attempts > self.max_attempts {
+            warn!(
+                "giving up on {} sFlow {} records after {} attempts",
+                batch.n_records, batch.record_type, attempts
+            );
+            self.fallback.write(&batch).await;
+            return;
+        }
+
+        if self.entries.len() >= self.capacity {
+            warn!(
+                "retry queue full, {} sFlow {} records not retried",
+                batch.n_records, batch.record_type
+            );
+            self.fallback.write(&batch).await;
+            return;
+        }
+
+        let backoff = self
+            .backoff
+            .saturating_mul(2u32.saturating_pow(attempts - 1))
+            .min(self.backoff_max);
+        debug!(
+            "retrying {} sFlow {} records in {:?} (attempt {})",
+            batch.n_records,
+            batch.record_type,
+            backoff,
+            attempts + 1
+        );
+
+        self.entries.push_back(RetryEntry {
+            batch,
+            attempts,
+            retry_at: Instant::now() + backoff,
+        });
+        gauge!("pesto_kafka_retry_queue_batches").set(self.entries.len() as f64);
+    }
+
+    /// Next batch due for a retry, with the number of attempts already made
+    ///
+    /// Batches are retried in order, a batch is never retried before an older one.
+    pub fn pop_due(&mut self) -> Option<(BatchMessage, u32)> {
+        if self.entries.front()?.retry_at > Instant::now() {
+            return None;
+        }
+
+        let entry = self.entries.pop_front()?;
+        gauge!("pesto_kafka_retry_queue_batches").set(self.entries.len() as f64);
+        counter!("pesto_kafka_retries_total").increment(1);
+        Some((entry.batch, entry.attempts))
+    }
+
+    /// Hand all pending batches to the fallback sink
+    pub async fn drain(&mut self) {
+        while let Some(entry) = self.entries.pop_front() {
+            self.fallback.write(&entry.batch).await;
+        }
+        gauge!("pesto_kafka_retry_queue_batches").set(0.0);
+    }
+}
+
+/// Last resort for batches that could not be delivered to Kafka
+///
+/// Payloads are appended to `<dir>/<topic>.bin`. Batch payloads are
+/// concatenations of Cap'n Proto messages, so each file is a valid message stream.
+pub struct FallbackSink {
+    dir: Option<PathBuf>,
+}
+
+impl FallbackSink {
+    pub fn new(dir: Option<PathBuf>) -> Self {
+        FallbackSink { dir }
+    }
+
+    pub async fn write(&self, batch: &BatchMessage) {
+        let metric_name = "pesto_kafka_fallback_batches_total";
+        let Some(dir) = &self.dir else {
+            counter!(metric_name, "status" => "dropped").increment(1);
+            error!(
+                "no fallback directory, dropping {} sFlow {} records",
+                batch.n_records, batch.record_type
+            );
+            return;
+        };
+
+        let path = dir.join(format!("{}.bin", batch.topic));
+        match Self::append(&path, &batch.payload).await {
+            Ok(()) => {
+                counter!(metric_name, "status" => "success").increment(1);
+                debug!(
+                    "wrote {} sFlow {} records to {}",
+                    batch.n_records,
+                    batch.record_type,
+                    path.display()
+                );
+            }
+            Err(err) => {
+                counter!(metric_name, "status" => "failure").increment(1);
+                error!(
+                    "failed to write {} sFlow {} records to {}: {}",
+                    batch.n_records,
+                    batch.record_type,
+                    path.display(),
+                    err
+                );
+            }
+        }
+    }
+
+    async fn append(path: &Path, payload: &[u8]) -> std::io::Result<()> {
+        let mut file = tokio::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await?;
+        file.write_all(payload).await?;
+        file.flush().await
+    }
+}
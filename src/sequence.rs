@@ -0,0 +1,408 @@
+use metrics::counter;
+use sflow_parser::{SFlowDatagram, SampleData};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::time::{Duration, Instant};
+
+use crate::serializer::address_to_ip;
+
+// Sequence numbers further back than this are a restart of the agent, not a
+// reordered datagram or sample, and the numbers missing further back are lost
+const REORDER_WINDOW: u32 = 1024;
+// Sequence numbers further ahead than this are a restart of the agent, not a gap
+const MAX_GAP: u32 = 1 << 20;
+// Sequences not updated for this long are forgotten, their agent is gone
+const STATE_IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Kind {
+    Datagram,
+    FlowSample,
+    CounterSample,
+    DropSample,
+}
+
+impl Kind {
+    fn label(self) -> &'static str {
+        match self {
+            Kind::Datagram => "datagram",
+            Kind::FlowSample => "flow_sample",
+            Kind::CounterSample => "counter_sample",
+            Kind::DropSample => "drop_sample",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Event {
+    InOrder,
+    Gap(u32),
+    Duplicate,
+    Reorder,
+    Reset,
+}
+
+// Datagram sequences are kept per (agent, sub-agent), sample sequences per
+// (agent, sub-agent, source type and index) and sample type, since each sample
+// type is numbered separately by a data source
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Key {
+    agent: Option<IpAddr>,
+    sub_agent_id: u32,
+    kind: Kind,
+    source: (u32, u32),
+}
+
+// `len` missing sequence numbers from `start`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Gap {
+    start: u32,
+    len: u32,
+}
+
+impl Gap {
+    fn contains(&self, sequence_number: u32) -> bool {
+        sequence_number.wrapping_sub(self.start) < self.len
+    }
+
+    fn last(&self) -> u32 {
+        self.start.wrapping_add(self.len - 1)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct State {
+    last: u32,
+    uptime: u32,
+    // Missing sequence numbers that may still arrive reordered, oldest first
+    gaps: Vec<Gap>,
+    updated: Instant,
+}
+
+impl State {
+    fn new(sequence_number: u32, uptime: u32, now: Instant) -> Self {
+        State {
+            last: sequence_number,
+            uptime,
+            gaps: Vec::new(),
+            updated: now,
+        }
+    }
+
+    // Returns the event, and how many sequence numbers are now known to be lost
+    fn update(&mut self, sequence_number: u32, uptime: u32, now: Instant) -> (Event, u64) {
+        self.updated = now;
+        let event = classify(self, sequence_number, uptime);
+        let mut lost = 0;
+        match event {
+            Event::InOrder => {}
+            Event::Gap(missing) => self.gaps.push(Gap {
+                start: self.last.wrapping_add(1),
+                len: missing,
+            }),
+            Event::Reset => {
+                lost = self.pending();
+                self.gaps.clear();
+            }
+            Event::Reorder => self.fill(sequence_number),
+            Event::Duplicate => {}
+        }
+        if matches!(event, Event::InOrder | Event::Gap(_) | Event::Reset) {
+            self.last = sequence_number;
+            self.uptime = uptime;
+        }
+
+        // Numbers out of the reorder window cannot arrive anymore
+        let last = self.last;
+        let expired = self
+            .gaps
+            .iter()
+            .take_while(|gap| last.wrapping_sub(gap.last()) > REORDER_WINDOW)
+            .count();
+        lost += self
+            .gaps
+            .drain(..expired)
+            .map(|gap| gap.len as u64)
+            .sum::<u64>();
+        (event, lost)
+    }
+
+    // Remove a reordered sequence number from its gap
+    fn fill(&mut self, sequence_number: u32) {
+        let Some(index) = self
+            .gaps
+            .iter()
+            .position(|gap| gap.contains(sequence_number))
+        else {
+            return;
+        };
+        let gap = self.gaps[index];
+        let before = sequence_number.wrapping_sub(gap.start);
+        let after = Gap {
+            start: sequence_number.wrapping_add(1),
+            len: gap.len - before - 1,
+        };
+        let mut split = Vec::with_capacity(2);
+        if before > 0 {
+            split.push(Gap {
+                start: gap.start,
+                len: before,
+            });
+        }
+        if after.len > 0 {
+            split.push(after);
+        }
+        self.gaps.splice(index..=index, split);
+    }
+
+    // Missing sequence numbers not known to be lost yet
+    fn pending(&self) -> u64 {
+        self.gaps.iter().map(|gap| gap.len as u64).sum()
+    }
+}
+
+/// Tracks the sequence numbers of the agents to measure the sFlow loss
+///
+/// Each worker has its own tracker. With SO_REUSEPORT an agent always lands on
+/// the same worker, but an agent sending to several listeners is tracked once
+/// per listener.
+///
+/// A gap is counted when it is seen, but its sequence numbers are only counted
+/// as missing once they fall out of the reorder window without arriving, or
+/// when the agent restarts or goes idle.
+#[derive(Default)]
+pub struct SequenceTracker {
+    states: HashMap<Key, State>,
+    pruned: Option<Instant>,
+}
+
+impl SequenceTracker {
+    /// Check the datagram and sample sequence numbers, and update the metrics
+    pub fn observe(&mut self, datagram: &SFlowDatagram) {
+        let now = Instant::now();
+        self.prune(now);
+
+        let agent = address_to_ip(&datagram.agent_address);
+        let key = |kind, source| Key {
+            agent,
+            sub_agent_id: datagram.sub_agent_id,
+            kind,
+            source,
+        };
+
+        let datagram_reset = self.check(
+            key(Kind::Datagram, (0, 0)),
+            datagram.sequence_number,
+            datagram.uptime,
+            now,
+        );
+
+        for sample in &datagram.samples {
+            let (kind, source_id, sequence_number) = match &sample.sample_data {
+                SampleData::FlowSample(flow) => (
+                    Kind::FlowSample,
+                    (flow.source_id.source_type() as u32, flow.source_id.index()),
+                    flow.sequence_number,
+                ),
+                SampleData::FlowSampleExpanded(flow) => (
+                    Kind::FlowSample,
+                    (
+                        flow.source_id.source_id_type,
+                        flow.source_id.source_id_index,
+                    ),
+                    flow.sequence_number,
+                ),
+                SampleData::CountersSample(counters) => (
+                    Kind::CounterSample,
+                    (
+                        counters.source_id.source_type() as u32,
+                        counters.source_id.index(),
+                    ),
+                    counters.sequence_number,
+                ),
+                SampleData::CountersSampleExpanded(counters) => (
+                    Kind::CounterSample,
+                    (
+                        counters.source_id.source_id_type,
+                        counters.source_id.source_id_index,
+                    ),
+                    counters.sequence_number,
+                ),
+                SampleData::DiscardedPacket(discarded) => (
+                    Kind::DropSample,
+                    (
+                        discarded.source_id.source_id_type,
+                        discarded.source_id.source_id_index,
+                    ),
+                    discarded.sequence_number,
+                ),
+                SampleData::Unknown { .. } => continue,
+            };
+
+            let key = key(kind, source_id);
+            if datagram_reset {
+                // The agent restarted, its samples are numbered from scratch too
+                if let Some(state) = self.states.remove(&key) {
+                    count_missing(&key, state.pending());
+                }
+            }
+            self.check(key, sequence_number, datagram.uptime, now);
+        }
+    }
+
+    // Forget the idle sequences, at most once per timeout
+    fn prune(&mut self, now: Instant) {
+        if let Some(pruned) = self.pruned {
+            if now.duration_since(pruned) < STATE_IDLE_TIMEOUT {
+                return;
+            }
+        }
+        self.states.retain(|key, state| {
+            let idle = now.duration_since(state.updated) >= STATE_IDLE_TIMEOUT;
+            if idle {
+                count_missing(key, state.pending());
+            }
+            !idle
+        });
+        self.pruned = Some(now);
+    }
+
+    // Returns whether the agent restarted
+    fn check(&mut self, key: Key, sequence_number: u32, uptime: u32, now: Instant) -> bool {
+        let Some(previous) = self.states.get_mut(&key) else {
+            self.states
+                .insert(key, State::new(sequence_number, uptime, now));
+            return false;
+        };
+
+        let (event, lost) = previous.update(sequence_number, uptime, now);
+        count_missing(&key, lost);
+
+        let agent = agent_label(&key);
+        let kind = key.kind.label();
+        let metric_name = "pesto_sflow_sequence_events_total";
+        let event_label = match event {
+            Event::InOrder => return false,
+            Event::Gap(_) => "gap",
+            Event::Duplicate => "duplicate",
+            Event::Reorder => "reorder",
+            Event::Reset => "reset",
+        };
+        counter!(metric_name, "agent" => agent, "kind" => kind, "event" => event_label)
+            .increment(1);
+        event == Event::Reset
+    }
+}
+
+fn agent_label(key: &Key) -> String {
+    key.agent
+        .map_or_else(|| "unknown".to_string(), |agent| agent.to_string())
+}
+
+fn count_missing(key: &Key, missing: u64) {
+    if missing > 0 {
+        counter!("pesto_sflow_sequence_missing_total", "agent" => agent_label(key), "kind" => key.kind.label())
+            .increment(missing);
+    }
+}
+
+fn classify(previous: &State, sequence_number: u32, uptime: u32) -> Event {
+    // The uptime only goes back when the agent restarts
+    if uptime < previous.uptime && previous.uptime - uptime > 60_000 {
+        return Event::Reset;
+    }
+
+    // Wrapping distance, sequence numbers roll over at 2^32
+    let ahead = sequence_number.wrapping_sub(previous.last);
+    let behind = previous.last.wrapping_sub(sequence_number);
+    match (ahead, behind) {
+        (0, _) => Event::Duplicate,
+        (1, _) => Event::InOrder,
+        (ahead, _) if ahead <= MAX_GAP => Event::Gap(ahead - 1),
+        // Only a missing number is reordered, anything else was already seen
+        (_, behind) if behind <= REORDER_WINDOW => {
+            if previous
+                .gaps
+                .iter()
+                .any(|gap| gap.contains(sequence_number))
+            {
+                Event::Reorder
+            } else {
+                Event::Duplicate
+            }
+        }
+        _ => Event::Reset,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn updates(numbers: &[u32]) -> (Vec<Event>, u64, State) {
+        let now = Instant::now();
+        let mut state = State::new(numbers[0], 1000, now);
+        let mut events = Vec::new();
+        let mut lost = 0;
+        for &number in &numbers[1..] {
+            let (event, n) = state.update(number, 1000, now);
+            events.push(event);
+            lost += n;
+        }
+        (events, lost, state)
+    }
+
+    #[test]
+    fn in_order_and_duplicate() {
+        let (events, lost, _) = updates(&[1, 2, 2, 3]);
+        assert_eq!(events, [Event::InOrder, Event::Duplicate, Event::InOrder]);
+        assert_eq!(lost, 0);
+    }
+
+    #[test]
+    fn reorder_fills_gap() {
+        let (events, lost, state) = updates(&[1, 2, 5, 4, 3, 4]);
+        assert_eq!(
+            events,
+            [
+                Event::InOrder,
+                Event::Gap(2),
+                Event::Reorder,
+                Event::Reorder,
+                Event::Duplicate
+            ]
+        );
+        assert_eq!(lost, 0);
+        assert_eq!(state.pending(), 0);
+    }
+
+    #[test]
+    fn gap_lost_out_of_window() {
+        let (_, lost, state) = updates(&[1, 4, 2]);
+        assert_eq!(state.pending(), 1);
+        assert_eq!(lost, 0);
+
+        // 3 never arrives
+        let mut state = state;
+        let (event, lost) = state.update(4 + REORDER_WINDOW + 10, 1000, Instant::now());
+        assert_eq!(event, Event::Gap(REORDER_WINDOW + 9));
+        assert_eq!(lost, 1);
+    }
+
+    #[test]
+    fn wraps_around() {
+        let (events, lost, state) = updates(&[u32::MAX - 1, 1, u32::MAX, 0]);
+        assert_eq!(events, [Event::Gap(2), Event::Reorder, Event::Reorder]);
+        assert_eq!((lost, state.pending()), (0, 0));
+    }
+
+    #[test]
+    fn reset_loses_pending() {
+        let now = Instant::now();
+        let mut state = State::new(10, 100_000, now);
+        state.update(13, 100_000, now);
+        let (event, lost) = state.update(1, 1000, now);
+        assert_eq!(event, Event::Reset);
+        assert_eq!(lost, 2);
+    }
+}
@@ -1,13 +1,97 @@
 use capnp::message::Builder;
 use capnp::serialize;
-use etherparse::SlicedPacket;
+use etherparse::{EtherType, LinkSlice, NetSlice, SlicedPacket, TransportSlice, VlanSlice};
 use metrics::counter;
-use sflow_parser::models::{Address, FlowData, FlowRecord};
+use sflow_parser::models::record_flows::{HeaderProtocol, SampledEthernet, SampledHeader};
+use sflow_parser::models::{
+    Address, CounterData, CounterRecord, DiscardedPacket, FlowData, FlowRecord,
+};
 use sflow_parser::{SFlowDatagram, SampleData};
-use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use tracing::trace;
 
-use crate::sflow_capnp::s_flow_flow_record;
+use crate::anonymize::Anonymizer;
+use crate::config::KeyStrategy;
+use crate::enrich::Enrichment;
+use crate::filter::{Filter, FlowFields};
+use crate::netflow::NetFlowPacket;
+use crate::sflow_capnp::{
+    net_flow_record, s_flow_counter_record, s_flow_drop_record, s_flow_flow_record,
+};
+
+/// Version of `schemas/sflow.capnp`, bumped on incompatible changes
+pub const SCHEMA_VERSION: u32 = 1;
+
+// Packet information extracted from a flow record
+#[derive(Debug, Clone, Copy)]
+struct PacketData {
+    // Link layer
+    src_mac: u64,
+    dst_mac: u64,
+    etype: u32,
+    vlan_id: u32,
+    inner_vlan_id: u32,
+
+    // Network and transport layers (IPv4 mapped to IPv6)
+    length: u32,
+    protocol: u32,
+    src_ip: Ipv6Addr,
+    dst_ip: Ipv6Addr,
+    src_port: u32,
+    dst_port: u32,
+    tcp_flags: u32,
+    tos: u32,
+}
+
+impl Default for PacketData {
+    fn default() -> Self {
+        PacketData {
+            src_mac: 0,
+            dst_mac: 0,
+            etype: 0,
+            vlan_id: 0,
+            inner_vlan_id: 0,
+            length: 0,
+            protocol: 0,
+            src_ip: Ipv6Addr::UNSPECIFIED,
+            dst_ip: Ipv6Addr::UNSPECIFIED,
+            src_port: 0,
+            dst_port: 0,
+            tcp_flags: 0,
+            tos: 0,
+        }
+    }
+}
+
+impl PacketData {
+    // Take the link layer values from an Ethernet frame data record (format 2)
+    fn set_ethernet(&mut self, ethernet: &SampledEthernet) {
+        self.src_mac = mac_to_u64(ethernet.src_mac.as_bytes());
+        self.dst_mac = mac_to_u64(ethernet.dst_mac.as_bytes());
+        self.etype = ethernet.eth_type;
+    }
+}
+
+/// Serialized Cap'n Proto message, its Kafka message key and the metadata
+/// exposed in the Kafka message headers
+#[derive(Debug)]
+pub struct SerializedRecord {
+    pub key: Vec<u8>,
+    pub payload: Vec<u8>,
+    pub time_received_ns: i64,
+    pub agent_addr: Option<IpAddr>,
+}
+
+/// Serialized Cap'n Proto messages of a datagram, by record type
+#[derive(Debug, Default)]
+pub struct SerializedRecords {
+    pub flows: Vec<SerializedRecord>,
+    pub counters: Vec<SerializedRecord>,
+    pub drops: Vec<SerializedRecord>,
+    pub netflows: Vec<SerializedRecord>,
+    // Kafka topic of the flow records, if the listener overrides it
+    pub flows_topic: Option<String>,
+}
 
 pub fn serialize_address(addr: &Address) -> Vec<u8> {
     match addr {
@@ -23,89 +107,364 @@ pub fn serialize_address(addr: &Address) -> Vec<u8> {
     }
 }
 
-// Parse raw packet header to extract IP information using etherparse
-// Returns (length, protocol, src_ip, dst_ip, src_port, dst_port, tcp_flags, tos/priority)
-fn parse_raw_packet_header(
-    header: &[u8],
-) -> Option<(u32, u32, Ipv6Addr, Ipv6Addr, u32, u32, u32, u32)> {
+// IP address as 16 bytes (IPv4 mapped to IPv6), unspecified if unknown
+fn serialize_ip(ip: Option<IpAddr>) -> Vec<u8> {
+    match ip {
+        Some(IpAddr::V4(ipv4)) => ipv4.to_ipv6_mapped().octets().to_vec(),
+        Some(IpAddr::V6(ipv6)) => ipv6.octets().to_vec(),
+        None => Ipv6Addr::UNSPECIFIED.octets().to_vec(),
+    }
+}
+
+fn ip_to_ipv6(ip: Option<IpAddr>) -> Ipv6Addr {
+    match ip {
+        Some(IpAddr::V4(ipv4)) => ipv4.to_ipv6_mapped(),
+        Some(IpAddr::V6(ipv6)) => ipv6,
+        None => Ipv6Addr::UNSPECIFIED,
+    }
+}
+
+pub fn address_to_ip(addr: &Address) -> Option<IpAddr> {
+    match addr {
+        Address::IPv4(ipv4) => Some(IpAddr::V4(*ipv4)),
+        Address::IPv6(ipv6) => Some(IpAddr::V6(*ipv6)),
+        Address::Unknown => None,
+    }
+}
+
+// Kafka message key of a record
+//
+// Agent keys are the 16 bytes agent address (IPv4 mapped to IPv6), followed by
+// the big-endian sub-agent ID. 5-tuple keys are the big-endian FNV-1a hash of
+// the IPs, ports and protocol, records without packet data use the agent key.
+// NetFlow exporters and observation domains stand for agents and sub-agents.
+fn record_key(
+    strategy: KeyStrategy,
+    agent_addr: Vec<u8>,
+    sub_agent_id: u32,
+    data: Option<&PacketData>,
+) -> Vec<u8> {
+    match (strategy, data) {
+        (KeyStrategy::None, _) => Vec::new(),
+        (KeyStrategy::AgentSubAgent, _) => {
+            let mut key = agent_addr;
+            key.extend_from_slice(&sub_agent_id.to_be_bytes());
+            key
+        }
+        (KeyStrategy::FiveTuple, Some(data)) => five_tuple_hash(data).to_be_bytes().to_vec(),
+        (KeyStrategy::Agent | KeyStrategy::FiveTuple, _) => agent_addr,
+    }
+}
+
+// FNV-1a, stable across builds so that a flow always maps to the same partition
+fn five_tuple_hash(data: &PacketData) -> u64 {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    let fields = [
+        &data.src_ip.octets()[..],
+        &data.dst_ip.octets()[..],
+        &(data.src_port as u16).to_be_bytes()[..],
+        &(data.dst_port as u16).to_be_bytes()[..],
+        &[data.protocol as u8][..],
+    ];
+    for byte in fields.iter().flat_map(|field| field.iter()) {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+// MAC address as a 48-bit integer
+fn mac_to_u64(mac: &[u8; 6]) -> u64 {
+    mac.iter().fold(0, |acc, byte| (acc << 8) | *byte as u64)
+}
+
+// PPP protocol numbers (RFC 1661) carried by sampled PPP/POS headers
+const PPP_IPV4: u16 = 0x0021;
+const PPP_IPV6: u16 = 0x0057;
+const PPP_MPLS_UNICAST: u16 = 0x0281;
+const PPP_MPLS_MULTICAST: u16 = 0x0283;
+
+fn header_protocol_name(protocol: HeaderProtocol) -> &'static str {
+    match protocol {
+        HeaderProtocol::EthernetIso88023 => "ethernet",
+        HeaderProtocol::Iso88024TokenBus => "token_bus",
+        HeaderProtocol::Iso88025TokenRing => "token_ring",
+        HeaderProtocol::Fddi => "fddi",
+        HeaderProtocol::FrameRelay => "frame_relay",
+        HeaderProtocol::X25 => "x25",
+        HeaderProtocol::Ppp => "ppp",
+        HeaderProtocol::Smds => "smds",
+        HeaderProtocol::Aal5 => "aal5",
+        HeaderProtocol::Aal5Ip => "aal5_ip",
+        HeaderProtocol::Ipv4 => "ipv4",
+        HeaderProtocol::Ipv6 => "ipv6",
+        HeaderProtocol::Mpls => "mpls",
+        HeaderProtocol::Pos => "pos",
+        HeaderProtocol::Ieee80211Mac => "ieee80211_mac",
+        HeaderProtocol::Ieee80211Ampdu => "ieee80211_ampdu",
+        HeaderProtocol::Ieee80211Amsdu => "ieee80211_amsdu",
+    }
+}
+
+// Skip the MPLS label stack and return the payload after the bottom of stack label
+fn strip_mpls_labels(data: &[u8]) -> Option<&[u8]> {
+    let mut offset = 0;
+    loop {
+        let label = data.get(offset..offset + 4)?;
+        offset += 4;
+        if label[2] & 0x01 != 0 {
+            return Some(&data[offset..]);
+        }
+    }
+}
+
+// Strip the PPP (RFC 1662 HDLC-like framing) or Cisco HDLC header
+// Returns the EtherType equivalent of the encapsulated protocol and its payload
+fn strip_ppp_header(data: &[u8]) -> Option<(EtherType, &[u8])> {
+    match data {
+        // Cisco HDLC: address, control, then an EtherType
+        [0x0f | 0x8f, 0x00, hi, lo, payload @ ..] => {
+            Some((EtherType(u16::from_be_bytes([*hi, *lo])), payload))
+        }
+        _ => {
+            // Address and control fields may be omitted (ACFC)
+            let data = data.strip_prefix(&[0xff, 0x03]).unwrap_or(data);
+            // Protocol field may be compressed to one byte (PFC)
+            let (protocol, payload) = match data {
+                [first, payload @ ..] if first & 0x01 != 0 => (*first as u16, payload),
+                [hi, lo, payload @ ..] => (u16::from_be_bytes([*hi, *lo]), payload),
+                _ => return None,
+            };
+            match protocol {
+                PPP_IPV4 => Some((EtherType::IPV4, payload)),
+                PPP_IPV6 => Some((EtherType::IPV6, payload)),
+                PPP_MPLS_UNICAST | PPP_MPLS_MULTICAST => {
+                    let payload = strip_mpls_labels(payload)?;
+                    match payload.first()? >> 4 {
+                        4 => Some((EtherType::IPV4, payload)),
+                        6 => Some((EtherType::IPV6, payload)),
+                        _ => None,
+                    }
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+// Strip the IEEE 802.11 MAC header and the LLC/SNAP header of a data frame
+// Returns the EtherType of the encapsulated protocol and its payload
+fn strip_ieee80211_header(data: &[u8]) -> Option<(EtherType, &[u8])> {
+    let (frame_control, flags) = (*data.first()?, *data.get(1)?);
+
+    // Only unprotected data frames carry a readable payload
+    let frame_type = (frame_control >> 2) & 0x03;
+    let protected = flags & 0x40 != 0;
+    if frame_type != 2 || protected {
+        return None;
+    }
+
+    let mut header_len = 24;
+    // Fourth address when both ToDS and FromDS are set
+    if flags & 0x03 == 0x03 {
+        header_len += 6;
+    }
+    // QoS data frames have a QoS control field, and an HT control field when Order is set
+    if frame_control & 0x80 != 0 {
+        header_len += 2;
+        if flags & 0x80 != 0 {
+            header_len += 4;
+        }
+    }
+
+    match data.get(header_len..)? {
+        [0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, hi, lo, payload @ ..] => {
+            Some((EtherType(u16::from_be_bytes([*hi, *lo])), payload))
+        }
+        _ => None,
+    }
+}
+
+// Slice the sampled header according to its header protocol
+fn slice_sampled_header(header: &SampledHeader) -> Option<SlicedPacket<'_>> {
+    let data = header.header.as_slice();
+    let packet = match header.protocol {
+        HeaderProtocol::EthernetIso88023 => SlicedPacket::from_ethernet(data),
+        HeaderProtocol::Ipv4 | HeaderProtocol::Ipv6 => SlicedPacket::from_ip(data),
+        HeaderProtocol::Mpls => SlicedPacket::from_ip(strip_mpls_labels(data)?),
+        HeaderProtocol::Ppp | HeaderProtocol::Pos => {
+            let (ether_type, payload) = strip_ppp_header(data)?;
+            SlicedPacket::from_ether_type(ether_type, payload)
+        }
+        HeaderProtocol::Ieee80211Mac => {
+            let (ether_type, payload) = strip_ieee80211_header(data)?;
+            SlicedPacket::from_ether_type(ether_type, payload)
+        }
+        protocol => {
+            counter!(
+                "pesto_sflow_headers_unsupported_total",
+                "protocol" => header_protocol_name(protocol)
+            )
+            .increment(1);
+            trace!(
+                "Skipping sampled header with unsupported protocol: {:?}",
+                protocol
+            );
+            return None;
+        }
+    };
+
+    packet.ok()
+}
+
+// Parse raw packet header to extract packet information using etherparse
+fn parse_raw_packet_header(header: &SampledHeader) -> Option<PacketData> {
     // Use etherparse to safely parse the packet
-    let packet = SlicedPacket::from_ethernet(header).ok()?;
+    let packet = slice_sampled_header(header)?;
+    let mut data = PacketData::default();
+
+    // Extract link layer information (MAC addresses and VLAN tags)
+    if let Some(LinkSlice::Ethernet2(ethernet)) = &packet.link {
+        data.src_mac = mac_to_u64(&ethernet.source());
+        data.dst_mac = mac_to_u64(&ethernet.destination());
+    }
+    match &packet.vlan {
+        Some(VlanSlice::SingleVlan(vlan)) => {
+            data.vlan_id = vlan.vlan_identifier().value() as u32;
+        }
+        Some(VlanSlice::DoubleVlan(vlan)) => {
+            data.vlan_id = vlan.outer().vlan_identifier().value() as u32;
+            data.inner_vlan_id = vlan.inner().vlan_identifier().value() as u32;
+        }
+        None => {}
+    }
 
     // Extract IP information
-    let (length, protocol, src_ip, dst_ip, tos) = match packet.net {
-        Some(etherparse::NetSlice::Ipv4(ipv4)) => {
+    match &packet.net {
+        Some(NetSlice::Ipv4(ipv4)) => {
             let hdr = ipv4.header();
-            (
-                hdr.total_len() as u32,
-                hdr.protocol().0 as u32,
-                Ipv4Addr::from(hdr.source()).to_ipv6_mapped(),
-                Ipv4Addr::from(hdr.destination()).to_ipv6_mapped(),
-                hdr.dcp().value() as u32, // Differentiated Services Code Point
-            )
+            data.etype = EtherType::IPV4.0 as u32;
+            data.length = hdr.total_len() as u32;
+            data.protocol = hdr.protocol().0 as u32;
+            data.src_ip = Ipv4Addr::from(hdr.source()).to_ipv6_mapped();
+            data.dst_ip = Ipv4Addr::from(hdr.destination()).to_ipv6_mapped();
+            data.tos = hdr.dcp().value() as u32; // Differentiated Services Code Point
         }
-        Some(etherparse::NetSlice::Ipv6(ipv6)) => {
+        Some(NetSlice::Ipv6(ipv6)) => {
             let hdr = ipv6.header();
+            data.etype = EtherType::IPV6.0 as u32;
             // Use the payload length from the header since we can't easily get actual payload size
-            (
-                40 + hdr.payload_length() as u32, // IPv6 header is always 40 bytes
-                hdr.next_header().0 as u32,
-                Ipv6Addr::from(hdr.source()),
-                Ipv6Addr::from(hdr.destination()),
-                hdr.traffic_class() as u32,
-            )
+            data.length = 40 + hdr.payload_length() as u32; // IPv6 header is always 40 bytes
+            data.protocol = hdr.next_header().0 as u32;
+            data.src_ip = Ipv6Addr::from(hdr.source());
+            data.dst_ip = Ipv6Addr::from(hdr.destination());
+            data.tos = hdr.traffic_class() as u32;
         }
         None => return None,
-    };
+    }
 
     // Extract transport layer information (ports and TCP flags)
-    let (src_port, dst_port, tcp_flags) = match packet.transport {
-        Some(etherparse::TransportSlice::Tcp(tcp)) => {
+    match &packet.transport {
+        Some(TransportSlice::Tcp(tcp)) => {
             let hdr = tcp.to_header();
-            (
-                hdr.source_port as u32,
-                hdr.destination_port as u32,
-                hdr.ns as u32
-                    | ((hdr.fin as u32) << 1)
-                    | ((hdr.syn as u32) << 2)
-                    | ((hdr.rst as u32) << 3)
-                    | ((hdr.psh as u32) << 4)
-                    | ((hdr.ack as u32) << 5)
-                    | ((hdr.urg as u32) << 6)
-                    | ((hdr.ece as u32) << 7)
-                    | ((hdr.cwr as u32) << 8),
-            )
+            data.src_port = hdr.source_port as u32;
+            data.dst_port = hdr.destination_port as u32;
+            data.tcp_flags = hdr.ns as u32
+                | ((hdr.fin as u32) << 1)
+                | ((hdr.syn as u32) << 2)
+                | ((hdr.rst as u32) << 3)
+                | ((hdr.psh as u32) << 4)
+                | ((hdr.ack as u32) << 5)
+                | ((hdr.urg as u32) << 6)
+                | ((hdr.ece as u32) << 7)
+                | ((hdr.cwr as u32) << 8);
         }
-        Some(etherparse::TransportSlice::Udp(udp)) => {
+        Some(TransportSlice::Udp(udp)) => {
             let hdr = udp.to_header();
-            (
-                hdr.source_port as u32,
-                hdr.destination_port as u32,
-                0, // No flags for UDP
-            )
+            data.src_port = hdr.source_port as u32;
+            data.dst_port = hdr.destination_port as u32;
+            // No flags for UDP
         }
-        _ => (0, 0, 0), // No transport layer or unsupported protocol
-    };
+        _ => {} // No transport layer or unsupported protocol
+    }
 
-    Some((
-        length, protocol, src_ip, dst_ip, src_port, dst_port, tcp_flags, tos,
-    ))
+    Some(data)
+}
+
+// Extract packet data from different flow record types
+// We process all records that contain IP information
+fn extract_packet_data(flow_record: &FlowRecord) -> Option<PacketData> {
+    match &flow_record.flow_data {
+        // Raw packet header (format 1) - most common, contains full packet
+        FlowData::SampledHeader(header) => parse_raw_packet_header(header),
+        // Direct IP samples (formats 3, 4)
+        FlowData::SampledIpv4(ipv4) => Some(PacketData {
+            etype: EtherType::IPV4.0 as u32,
+            length: ipv4.length,
+            protocol: ipv4.protocol,
+            src_ip: ipv4.src_ip.to_ipv6_mapped(),
+            dst_ip: ipv4.dst_ip.to_ipv6_mapped(),
+            src_port: ipv4.src_port,
+            dst_port: ipv4.dst_port,
+            tcp_flags: ipv4.tcp_flags,
+            tos: ipv4.tos,
+            ..Default::default()
+        }),
+        FlowData::SampledIpv6(ipv6) => Some(PacketData {
+            etype: EtherType::IPV6.0 as u32,
+            length: ipv6.length,
+            protocol: ipv6.protocol,
+            src_ip: ipv6.src_ip,
+            dst_ip: ipv6.dst_ip,
+            src_port: ipv6.src_port,
+            dst_port: ipv6.dst_port,
+            tcp_flags: ipv6.tcp_flags,
+            tos: ipv6.priority,
+            ..Default::default()
+        }),
+        // All other flow record types (extended metadata, Ethernet frame info, etc.)
+        _ => {
+            // Extended records are metadata only, merged with flow data by process_flow_sample
+            trace!(
+                "Skipping extended/metadata flow record: format={:?}",
+                flow_record.flow_format
+            );
+            None
+        }
+    }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn serialize_sflow_record(
     datagram: &SFlowDatagram,
     time_received_ns: i64,
     peer_addr: SocketAddr,
-) -> Vec<Vec<u8>> {
-    let mut messages = Vec::new();
+    listener_tag: &str,
+    key_strategy: KeyStrategy,
+    filter: Filter,
+    anonymizer: Option<&Anonymizer>,
+    enrichment: &Enrichment,
+) -> SerializedRecords {
+    let mut records = SerializedRecords::default();
+    let agent_address = match anonymizer {
+        Some(anonymizer) => anonymizer.agent_address(&datagram.agent_address),
+        None => datagram.agent_address.clone(),
+    };
 
     for sample in &datagram.samples {
         match &sample.sample_data {
             SampleData::FlowSample(flow) => {
                 process_flow_sample(
-                    &mut messages,
+                    &mut records.flows,
                     datagram,
+                    &agent_address,
                     time_received_ns,
                     peer_addr,
+                    listener_tag,
+                    key_strategy,
+                    filter,
+                    anonymizer,
+                    enrichment,
                     flow.sequence_number,
                     flow.source_id.0,
                     flow.sampling_rate,
@@ -118,10 +477,16 @@ pub fn serialize_sflow_record(
             }
             SampleData::FlowSampleExpanded(flow) => {
                 process_flow_sample(
-                    &mut messages,
+                    &mut records.flows,
                     datagram,
+                    &agent_address,
                     time_received_ns,
                     peer_addr,
+                    listener_tag,
+                    key_strategy,
+                    filter,
+                    anonymizer,
+                    enrichment,
                     flow.sequence_number,
                     flow.source_id.source_id_index,
                     flow.sampling_rate,
@@ -132,13 +497,46 @@ pub fn serialize_sflow_record(
                     &flow.flow_records,
                 );
             }
-            SampleData::CountersSample(_) | SampleData::CountersSampleExpanded(_) => {
-                // Count counter samples but don't serialize (not supported by schema)
-                counter!("pesto_sflow_samples_received_total", "type" => "counter").increment(1);
-                trace!("Received counter sample (not serialized)");
+            SampleData::CountersSample(counters) => {
+                process_counters_sample(
+                    &mut records.counters,
+                    datagram,
+                    &agent_address,
+                    time_received_ns,
+                    peer_addr,
+                    listener_tag,
+                    key_strategy,
+                    counters.sequence_number,
+                    counters.source_id.0,
+                    &counters.counters,
+                );
             }
-            SampleData::DiscardedPacket(_) => {
-                trace!("Skipping discarded packet sample");
+            SampleData::CountersSampleExpanded(counters) => {
+                process_counters_sample(
+                    &mut records.counters,
+                    datagram,
+                    &agent_address,
+                    time_received_ns,
+                    peer_addr,
+                    listener_tag,
+                    key_strategy,
+                    counters.sequence_number,
+                    counters.source_id.source_id_index,
+                    &counters.counters,
+                );
+            }
+            SampleData::DiscardedPacket(discarded) => {
+                process_discarded_packet(
+                    &mut records.drops,
+                    datagram,
+                    &agent_address,
+                    time_received_ns,
+                    peer_addr,
+                    listener_tag,
+                    key_strategy,
+                    anonymizer,
+                    discarded,
+                );
             }
             SampleData::Unknown { format, .. } => {
                 trace!("Skipping unknown sample type: {:?}", format);
@@ -146,15 +544,21 @@ pub fn serialize_sflow_record(
         }
     }
 
-    messages
+    records
 }
 
 #[allow(clippy::too_many_arguments)]
 fn process_flow_sample(
-    messages: &mut Vec<Vec<u8>>,
+    messages: &mut Vec<SerializedRecord>,
     datagram: &SFlowDatagram,
+    agent_address: &Address,
     time_received_ns: i64,
     peer_addr: SocketAddr,
+    listener_tag: &str,
+    key_strategy: KeyStrategy,
+    filter: Filter,
+    anonymizer: Option<&Anonymizer>,
+    enrichment: &Enrichment,
     sample_seq: u32,
     source_id: u32,
     sampling_rate: u32,
@@ -167,50 +571,65 @@ fn process_flow_sample(
     // Count flow sample received
     counter!("pesto_sflow_samples_received_total", "type" => "flow").increment(1);
 
+    // Ethernet frame data and extended records describe the whole flow sample,
+    // they are merged into every flow record of the sample
+    let mut ethernet = None;
+    let mut switch = None;
+    let mut router = None;
+    let mut gateway = None;
     for flow_record in flow_records {
-        // Extract IP data from different flow record types
-        // We process all records that contain IP information
-        let ip_data: Option<(u32, u32, Ipv6Addr, Ipv6Addr, u32, u32, u32, u32)> =
-            match &flow_record.flow_data {
-                // Raw packet header (format 1) - most common, contains full packet
-                FlowData::SampledHeader(header) => parse_raw_packet_header(&header.header),
-                // Direct IP samples (formats 3, 4)
-                FlowData::SampledIpv4(ipv4) => Some((
-                    ipv4.length,
-                    ipv4.protocol,
-                    ipv4.src_ip.to_ipv6_mapped(),
-                    ipv4.dst_ip.to_ipv6_mapped(),
-                    ipv4.src_port,
-                    ipv4.dst_port,
-                    ipv4.tcp_flags,
-                    ipv4.tos,
-                )),
-                FlowData::SampledIpv6(ipv6) => Some((
-                    ipv6.length,
-                    ipv6.protocol,
-                    ipv6.src_ip,
-                    ipv6.dst_ip,
-                    ipv6.src_port,
-                    ipv6.dst_port,
-                    ipv6.tcp_flags,
-                    ipv6.priority,
-                )),
-                // All other flow record types (extended metadata, Ethernet frame info, etc.)
-                _ => {
-                    // Extended records are metadata only - skip for now
-                    // In the future, we could store these separately or merge with flow data
-                    trace!(
-                        "Skipping extended/metadata flow record: format={:?}",
-                        flow_record.flow_format
-                    );
-                    None
-                }
-            };
+        match &flow_record.flow_data {
+            FlowData::SampledEthernet(data) => ethernet = Some(data),
+            FlowData::ExtendedSwitch(data) => switch = Some(data),
+            FlowData::ExtendedRouter(data) => router = Some(data),
+            FlowData::ExtendedGateway(data) => gateway = Some(data),
+            _ => {}
+        }
+    }
 
-        let (length, protocol, src_ip, dst_ip, src_port, dst_port, tcp_flags, tos) = match ip_data {
+    // Metadata of the real agent, before the anonymization
+    let agent = enrichment
+        .metadata
+        .agent(address_to_ip(&datagram.agent_address));
+    let input_interface = agent.and_then(|agent| agent.interface(input_if));
+    let output_interface = agent.and_then(|agent| agent.interface(output_if));
+
+    for flow_record in flow_records {
+        let mut data = match extract_packet_data(flow_record) {
             Some(data) => data,
             None => continue,
         };
+        // Ethernet frame data (format 2) takes precedence over the sampled header link layer
+        if let Some(ethernet) = ethernet {
+            data.set_ethernet(ethernet);
+        }
+
+        let fields = FlowFields {
+            agent: address_to_ip(&datagram.agent_address),
+            src: IpAddr::V6(data.src_ip).to_canonical(),
+            dst: IpAddr::V6(data.dst_ip).to_canonical(),
+            src_port: data.src_port,
+            dst_port: data.dst_port,
+            protocol: data.protocol,
+            input_interface: input_if,
+            output_interface: output_if,
+            // 802.1Q tag of the sampled header, or the extended switch VLAN
+            vlan: match data.vlan_id {
+                0 => switch.map_or(0, |switch| switch.src_vlan),
+                vlan_id => vlan_id,
+            },
+            sampling_rate,
+        };
+        if filter.drops(&fields) {
+            continue;
+        }
+        // Looked up before the anonymization
+        let src_geo = enrichment.geoip.lookup(fields.src);
+        let dst_geo = enrichment.geoip.lookup(fields.dst);
+        if let Some(anonymizer) = anonymizer {
+            data.src_ip = anonymizer.ipv6(data.src_ip);
+            data.dst_ip = anonymizer.ipv6(data.dst_ip);
+        }
 
         let mut message = Builder::new_default();
         {
@@ -218,11 +637,18 @@ fn process_flow_sample(
 
             // Set datagram metadata
             record.set_time_received_ns(time_received_ns as u64);
-            record.set_agent_addr(&serialize_address(&datagram.agent_address));
+            record.set_agent_addr(&serialize_address(agent_address));
             record.set_agent_port(peer_addr.port());
             record.set_agent_sub_id(datagram.sub_agent_id);
             record.set_datagram_sequence_number(datagram.sequence_number);
             record.set_uptime(datagram.uptime);
+            record.set_listener_tag(listener_tag);
+            // The UDP source is the agent address as well
+            let peer_ip = match anonymizer {
+                Some(anonymizer) if anonymizer.agent => anonymizer.ip(peer_addr.ip()),
+                _ => peer_addr.ip().to_canonical(),
+            };
+            record.set_peer_addr(&serialize_ip(Some(peer_ip)));
 
             // Set sample metadata
             record.set_sample_sequence_number(sample_seq);
@@ -236,16 +662,727 @@ fn process_flow_sample(
             record.set_output_interface(output_if);
 
             // Set flow data (all normalized to IPv6)
-            record.set_length(length);
-            record.set_protocol(protocol);
-            record.set_src_ip(&src_ip.octets());
-            record.set_dst_ip(&dst_ip.octets());
-            record.set_src_port(src_port);
-            record.set_dst_port(dst_port);
-            record.set_tcp_flags(tcp_flags);
-            record.set_tos(tos);
+            record.set_length(data.length);
+            record.set_protocol(data.protocol);
+            record.set_src_ip(&data.src_ip.octets());
+            record.set_dst_ip(&data.dst_ip.octets());
+            record.set_src_port(data.src_port);
+            record.set_dst_port(data.dst_port);
+            record.set_tcp_flags(data.tcp_flags);
+            record.set_tos(data.tos);
+
+            // Set link layer data
+            record.set_src_mac(data.src_mac);
+            record.set_dst_mac(data.dst_mac);
+            record.set_etype(data.etype);
+            record.set_vlan_id(data.vlan_id);
+            record.set_inner_vlan_id(data.inner_vlan_id);
+
+            // Set extended switch data
+            if let Some(switch) = switch {
+                record.set_src_vlan(switch.src_vlan);
+                record.set_src_priority(switch.src_priority);
+                record.set_dst_vlan(switch.dst_vlan);
+                record.set_dst_priority(switch.dst_priority);
+            }
+
+            // Set extended router data (next hops are always 16 bytes, unspecified if unknown)
+            let next_hop = router.map_or(&Address::Unknown, |router| &router.next_hop);
+            record.set_next_hop(&serialize_address(next_hop));
+            if let Some(router) = router {
+                record.set_src_mask_len(router.src_mask_len);
+                record.set_dst_mask_len(router.dst_mask_len);
+            }
+
+            // Set extended gateway data
+            let bgp_next_hop = gateway.map_or(&Address::Unknown, |gateway| &gateway.next_hop);
+            record.set_bgp_next_hop(&serialize_address(bgp_next_hop));
+            if let Some(gateway) = gateway {
+                let as_path: Vec<u32> = gateway
+                    .dst_as_path
+                    .iter()
+                    .flat_map(|segment| segment.path.iter().copied())
+                    .collect();
+
+                record.set_src_as(gateway.src_as);
+                // Routes originated by the agent's own AS have an empty path
+                record.set_dst_as(as_path.last().copied().unwrap_or(gateway.as_number));
+                record.set_src_peer_as(gateway.src_peer_as);
+                record.set_dst_peer_as(as_path.first().copied().unwrap_or_default());
+                record.set_local_pref(gateway.local_pref);
+
+                let mut as_path_list = record.reborrow().init_as_path(as_path.len() as u32);
+                for (i, asn) in as_path.iter().enumerate() {
+                    as_path_list.set(i as u32, *asn);
+                }
+                let mut communities_list = record
+                    .reborrow()
+                    .init_communities(gateway.communities.len() as u32);
+                for (i, community) in gateway.communities.iter().enumerate() {
+                    communities_list.set(i as u32, *community);
+                }
+            }
+
+            // Set GeoIP enrichment
+            record.set_src_country(src_geo.country);
+            record.set_dst_country(dst_geo.country);
+            record.set_src_city(src_geo.city);
+            record.set_dst_city(dst_geo.city);
+            record.set_src_asn(src_geo.asn);
+            record.set_dst_asn(dst_geo.asn);
+            record.set_src_as_org(src_geo.as_org);
+            record.set_dst_as_org(dst_geo.as_org);
+
+            // Set agent and interface metadata
+            if let Some(agent) = agent {
+                record.set_agent_hostname(&agent.hostname);
+                record.set_agent_site(&agent.site);
+                record.set_agent_role(&agent.role);
+            }
+            if let Some(interface) = input_interface {
+                record.set_input_interface_name(&interface.name);
+                record.set_input_interface_description(&interface.description);
+                record.set_input_interface_speed(interface.speed);
+                record.set_input_interface_boundary(interface.boundary.value());
+            }
+            if let Some(interface) = output_interface {
+                record.set_output_interface_name(&interface.name);
+                record.set_output_interface_description(&interface.description);
+                record.set_output_interface_speed(interface.speed);
+                record.set_output_interface_boundary(interface.boundary.value());
+            }
+        }
+
+        messages.push(SerializedRecord {
+            key: record_key(
+                key_strategy,
+                serialize_address(agent_address),
+                datagram.sub_agent_id,
+                Some(&data),
+            ),
+            payload: serialize::write_message_to_words(&message),
+            time_received_ns,
+            agent_addr: address_to_ip(agent_address),
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_counters_sample(
+    messages: &mut Vec<SerializedRecord>,
+    datagram: &SFlowDatagram,
+    agent_address: &Address,
+    time_received_ns: i64,
+    peer_addr: SocketAddr,
+    listener_tag: &str,
+    key_strategy: KeyStrategy,
+    sample_seq: u32,
+    source_id: u32,
+    counter_records: &[CounterRecord],
+) {
+    // Count counter sample received
+    counter!("pesto_sflow_samples_received_total", "type" => "counter").increment(1);
+
+    // Only interface counters are supported by the schema
+    let generic =
+        counter_records
+            .iter()
+            .find_map(|counter_record| match &counter_record.counter_data {
+                CounterData::GenericInterface(generic) => Some(generic),
+                _ => None,
+            });
+    let ethernet =
+        counter_records
+            .iter()
+            .find_map(|counter_record| match &counter_record.counter_data {
+                CounterData::EthernetInterface(ethernet) => Some(ethernet),
+                _ => None,
+            });
+
+    if generic.is_none() && ethernet.is_none() {
+        trace!("Skipping counter sample without interface counters");
+        return;
+    }
+
+    let mut message = Builder::new_default();
+    {
+        let mut record = message.init_root::<s_flow_counter_record::Builder>();
+
+        // Set datagram metadata
+        record.set_time_received_ns(time_received_ns as u64);
+        record.set_agent_addr(&serialize_address(agent_address));
+        record.set_agent_port(peer_addr.port());
+        record.set_agent_sub_id(datagram.sub_agent_id);
+        record.set_datagram_sequence_number(datagram.sequence_number);
+        record.set_uptime(datagram.uptime);
+        record.set_listener_tag(listener_tag);
+
+        // Set sample metadata
+        record.set_sample_sequence_number(sample_seq);
+        record.set_source_id(source_id);
+
+        // Set generic interface counters
+        if let Some(generic) = generic {
+            record.set_if_index(generic.if_index);
+            record.set_if_type(generic.if_type);
+            record.set_if_speed(generic.if_speed);
+            record.set_if_direction(generic.if_direction);
+            record.set_if_status(generic.if_status);
+            record.set_if_in_octets(generic.if_in_octets);
+            record.set_if_in_ucast_pkts(generic.if_in_ucast_pkts);
+            record.set_if_in_multicast_pkts(generic.if_in_multicast_pkts);
+            record.set_if_in_broadcast_pkts(generic.if_in_broadcast_pkts);
+            record.set_if_in_discards(generic.if_in_discards);
+            record.set_if_in_errors(generic.if_in_errors);
+            record.set_if_in_unknown_protos(generic.if_in_unknown_protos);
+            record.set_if_out_octets(generic.if_out_octets);
+            record.set_if_out_ucast_pkts(generic.if_out_ucast_pkts);
+            record.set_if_out_multicast_pkts(generic.if_out_multicast_pkts);
+            record.set_if_out_broadcast_pkts(generic.if_out_broadcast_pkts);
+            record.set_if_out_discards(generic.if_out_discards);
+            record.set_if_out_errors(generic.if_out_errors);
+            record.set_if_promiscuous_mode(generic.if_promiscuous_mode);
+        }
+
+        // Set Ethernet interface counters
+        if let Some(ethernet) = ethernet {
+            record.set_dot3_stats_alignment_errors(ethernet.dot3_stats_alignment_errors);
+            record.set_dot3_stats_fcs_errors(ethernet.dot3_stats_fcs_errors);
+            record.set_dot3_stats_single_collision_frames(
+                ethernet.dot3_stats_single_collision_frames,
+            );
+            record.set_dot3_stats_multiple_collision_frames(
+                ethernet.dot3_stats_multiple_collision_frames,
+            );
+            record.set_dot3_stats_sqe_test_errors(ethernet.dot3_stats_sqe_test_errors);
+            record
+                .set_dot3_stats_deferred_transmissions(ethernet.dot3_stats_deferred_transmissions);
+            record.set_dot3_stats_late_collisions(ethernet.dot3_stats_late_collisions);
+            record.set_dot3_stats_excessive_collisions(ethernet.dot3_stats_excessive_collisions);
+            record.set_dot3_stats_internal_mac_transmit_errors(
+                ethernet.dot3_stats_internal_mac_transmit_errors,
+            );
+            record.set_dot3_stats_carrier_sense_errors(ethernet.dot3_stats_carrier_sense_errors);
+            record.set_dot3_stats_frame_too_longs(ethernet.dot3_stats_frame_too_longs);
+            record.set_dot3_stats_internal_mac_receive_errors(
+                ethernet.dot3_stats_internal_mac_receive_errors,
+            );
+            record.set_dot3_stats_symbol_errors(ethernet.dot3_stats_symbol_errors);
+        }
+    }
+
+    messages.push(SerializedRecord {
+        key: record_key(
+            key_strategy,
+            serialize_address(agent_address),
+            datagram.sub_agent_id,
+            None,
+        ),
+        payload: serialize::write_message_to_words(&message),
+        time_received_ns,
+        agent_addr: address_to_ip(agent_address),
+    });
+}
+
+#[allow(clippy::too_many_arguments)]
+fn process_discarded_packet(
+    messages: &mut Vec<SerializedRecord>,
+    datagram: &SFlowDatagram,
+    agent_address: &Address,
+    time_received_ns: i64,
+    peer_addr: SocketAddr,
+    listener_tag: &str,
+    key_strategy: KeyStrategy,
+    anonymizer: Option<&Anonymizer>,
+    discarded: &DiscardedPacket,
+) {
+    // Count discarded packet sample received
+    counter!("pesto_sflow_samples_received_total", "type" => "discarded").increment(1);
+
+    // The dropped packet is described by the first record that contains IP information
+    let packet_data = discarded.flow_records.iter().find_map(extract_packet_data);
+    if packet_data.is_none() {
+        trace!("Discarded packet sample without IP information");
+    }
+    let packet_data = match (packet_data, anonymizer) {
+        (Some(mut data), Some(anonymizer)) => {
+            data.src_ip = anonymizer.ipv6(data.src_ip);
+            data.dst_ip = anonymizer.ipv6(data.dst_ip);
+            Some(data)
         }
+        (packet_data, _) => packet_data,
+    };
+    let data = packet_data.unwrap_or_default();
+
+    let mut message = Builder::new_default();
+    {
+        let mut record = message.init_root::<s_flow_drop_record::Builder>();
+
+        // Set datagram metadata
+        record.set_time_received_ns(time_received_ns as u64);
+        record.set_agent_addr(&serialize_address(agent_address));
+        record.set_agent_port(peer_addr.port());
+        record.set_agent_sub_id(datagram.sub_agent_id);
+        record.set_datagram_sequence_number(datagram.sequence_number);
+        record.set_uptime(datagram.uptime);
+        record.set_listener_tag(listener_tag);
+
+        // Set sample metadata
+        record.set_sample_sequence_number(discarded.sequence_number);
+        record.set_source_id(discarded.source_id.source_id_index);
+
+        // Set discarded packet sample fields
+        record.set_drops(discarded.drops);
+        record.set_input_interface(discarded.input_ifindex);
+        record.set_output_interface(discarded.output_ifindex);
+        record.set_reason(discarded.reason as u32);
+
+        // Set dropped packet data (all normalized to IPv6)
+        record.set_length(data.length);
+        record.set_protocol(data.protocol);
+        record.set_src_ip(&data.src_ip.octets());
+        record.set_dst_ip(&data.dst_ip.octets());
+        record.set_src_port(data.src_port);
+        record.set_dst_port(data.dst_port);
+        record.set_tcp_flags(data.tcp_flags);
+        record.set_tos(data.tos);
+    }
+
+    messages.push(SerializedRecord {
+        key: record_key(
+            key_strategy,
+            serialize_address(agent_address),
+            datagram.sub_agent_id,
+            packet_data.as_ref(),
+        ),
+        payload: serialize::write_message_to_words(&message),
+        time_received_ns,
+        agent_addr: address_to_ip(agent_address),
+    });
+}
+
+pub fn serialize_netflow_packet(
+    packet: &NetFlowPacket,
+    time_received_ns: i64,
+    peer_addr: SocketAddr,
+    key_strategy: KeyStrategy,
+    filter: Filter,
+    anonymizer: Option<&Anonymizer>,
+) -> SerializedRecords {
+    let mut records = SerializedRecords::default();
+    let exporter_ip = match anonymizer {
+        Some(anonymizer) if anonymizer.agent => anonymizer.ip(peer_addr.ip()),
+        _ => peer_addr.ip(),
+    };
+    let exporter_addr = serialize_ip(Some(exporter_ip));
+
+    for flow in &packet.flows {
+        counter!("pesto_netflow_records_received_total").increment(1);
+
+        let fields = FlowFields {
+            agent: Some(peer_addr.ip().to_canonical()),
+            src: IpAddr::V6(ip_to_ipv6(flow.src_ip)).to_canonical(),
+            dst: IpAddr::V6(ip_to_ipv6(flow.dst_ip)).to_canonical(),
+            src_port: flow.src_port,
+            dst_port: flow.dst_port,
+            protocol: flow.protocol,
+            input_interface: flow.input_interface,
+            output_interface: flow.output_interface,
+            vlan: flow.vlan_id,
+            sampling_rate: flow.sampling_rate,
+        };
+        if filter.drops(&fields) {
+            continue;
+        }
+        let (src_ip, dst_ip) = match anonymizer {
+            Some(anonymizer) => (
+                flow.src_ip.map(|ip| anonymizer.ip(ip)),
+                flow.dst_ip.map(|ip| anonymizer.ip(ip)),
+            ),
+            None => (flow.src_ip, flow.dst_ip),
+        };
+
+        let mut message = Builder::new_default();
+        {
+            let mut record = message.init_root::<net_flow_record::Builder>();
+
+            // Set packet metadata
+            record.set_time_received_ns(time_received_ns as u64);
+            record.set_exporter_addr(&exporter_addr);
+            record.set_exporter_port(peer_addr.port());
+            record.set_version(packet.version);
+            record.set_sequence_number(packet.sequence_number);
+            record.set_observation_domain_id(packet.observation_domain_id);
+            record.set_export_time(packet.export_time);
+
+            // Set flow fields
+            record.set_flow_start_ms(flow.flow_start_ms);
+            record.set_flow_end_ms(flow.flow_end_ms);
+            record.set_bytes(flow.bytes);
+            record.set_packets(flow.packets);
+            record.set_sampling_rate(flow.sampling_rate);
+            record.set_src_ip(&serialize_ip(src_ip));
+            record.set_dst_ip(&serialize_ip(dst_ip));
+            record.set_src_port(flow.src_port);
+            record.set_dst_port(flow.dst_port);
+            record.set_protocol(flow.protocol);
+            record.set_tos(flow.tos);
+            record.set_tcp_flags(flow.tcp_flags);
+            record.set_input_interface(flow.input_interface);
+            record.set_output_interface(flow.output_interface);
+            record.set_src_mac(flow.src_mac);
+            record.set_dst_mac(flow.dst_mac);
+            record.set_etype(flow.etype);
+            record.set_vlan_id(flow.vlan_id);
+            record.set_next_hop(&serialize_ip(flow.next_hop));
+            record.set_src_mask_len(flow.src_mask_len);
+            record.set_dst_mask_len(flow.dst_mask_len);
+            record.set_src_as(flow.src_as);
+            record.set_dst_as(flow.dst_as);
+        }
+
+        let data = PacketData {
+            protocol: flow.protocol,
+            src_ip: ip_to_ipv6(src_ip),
+            dst_ip: ip_to_ipv6(dst_ip),
+            src_port: flow.src_port,
+            dst_port: flow.dst_port,
+            ..Default::default()
+        };
+        records.netflows.push(SerializedRecord {
+            key: record_key(
+                key_strategy,
+                exporter_addr.clone(),
+                packet.observation_domain_id,
+                Some(&data),
+            ),
+            payload: serialize::write_message_to_words(&message),
+            time_received_ns,
+            agent_addr: Some(exporter_ip),
+        });
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+    use crate::enrich;
+    use capnp::message::ReaderOptions;
+    use sflow_parser::parse_datagram;
+
+    // Ethernet frame of a UDP packet
+    fn udp_frame(src: Ipv4Addr, dst: Ipv4Addr, src_port: u16, dst_port: u16) -> Vec<u8> {
+        let mut frame = vec![
+            0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xaa, 0xbb,
+        ];
+        frame.extend_from_slice(&[0x08, 0x00]);
+        frame.extend_from_slice(&[0x45, 0x00, 0x00, 28, 0, 0, 0, 0, 64, 17, 0, 0]);
+        frame.extend_from_slice(&src.octets());
+        frame.extend_from_slice(&dst.octets());
+        frame.extend_from_slice(&src_port.to_be_bytes());
+        frame.extend_from_slice(&dst_port.to_be_bytes());
+        frame.extend_from_slice(&[0, 8, 0, 0]);
+        frame
+    }
+
+    // sFlow v5 datagram from agent 192.0.2.1 with one flow sample, input
+    // interface 1 and output interface 2, made of the given flow records
+    fn datagram(flow_records: &[(u32, Vec<u8>)]) -> SFlowDatagram {
+        let mut records = Vec::new();
+        for (format, data) in flow_records {
+            records.extend_from_slice(&format.to_be_bytes());
+            records.extend_from_slice(&(data.len() as u32).to_be_bytes());
+            records.extend_from_slice(data);
+        }
+        let mut sample = Vec::new();
+        for value in [1u32, 3, 100, 1000, 0, 1, 2, flow_records.len() as u32] {
+            sample.extend_from_slice(&value.to_be_bytes());
+        }
+        sample.extend_from_slice(&records);
+
+        let mut datagram = Vec::new();
+        for value in [
+            5u32,
+            1,
+            u32::from_be_bytes([192, 0, 2, 1]),
+            0,
+            1,
+            100_000,
+            1,
+            1,
+        ] {
+            datagram.extend_from_slice(&value.to_be_bytes());
+        }
+        datagram.extend_from_slice(&(sample.len() as u32).to_be_bytes());
+        datagram.extend_from_slice(&sample);
+        parse_datagram(&datagram).unwrap()
+    }
+
+    // Sampled header flow record, padded to 4 bytes
+    fn sampled_header(protocol: u32, header: &[u8]) -> (u32, Vec<u8>) {
+        let mut data = Vec::new();
+        for value in [protocol, header.len() as u32, 0, header.len() as u32] {
+            data.extend_from_slice(&value.to_be_bytes());
+        }
+        data.extend_from_slice(header);
+        data.resize(data.len().next_multiple_of(4), 0);
+        (1, data)
+    }
+
+    fn serialize(datagram: &SFlowDatagram, enrichment: &Enrichment) -> Vec<SerializedRecord> {
+        let peer_addr = "192.0.2.1:6343".parse().unwrap();
+        let records = serialize_sflow_record(
+            datagram,
+            0,
+            peer_addr,
+            "",
+            KeyStrategy::None,
+            Filter::new(&[], "pesto-sflow"),
+            None,
+            enrichment,
+        );
+        records.flows
+    }
+
+    fn read(record: &SerializedRecord) -> capnp::message::Reader<serialize::OwnedSegments> {
+        serialize::read_message(&mut record.payload.as_slice(), ReaderOptions::new()).unwrap()
+    }
+
+    fn fixture(name: &str) -> String {
+        format!("{}/integration/{}", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[tokio::test]
+    async fn geoip_enrichment() {
+        let (city, asn) = (fixture("geoip/city.mmdb"), fixture("geoip/asn.mmdb"));
+        let args = ["--geoip-city-database", &city, "--geoip-asn-database", &asn];
+        let config = config::from_args(&args).await.unwrap();
+        let enrichment = enrich::load(&config).unwrap();
+
+        let frame = udp_frame(
+            Ipv4Addr::new(3, 3, 3, 3),
+            Ipv4Addr::new(4, 4, 4, 4),
+            5000,
+            53,
+        );
+        let flows = serialize(&datagram(&[sampled_header(1, &frame)]), &enrichment);
+        assert_eq!(flows.len(), 1);
+
+        let message = read(&flows[0]);
+        let flow = message.get_root::<s_flow_flow_record::Reader>().unwrap();
+        assert_eq!(flow.get_src_country().unwrap(), "US");
+        assert_eq!(flow.get_src_city().unwrap(), "Seattle");
+        assert_eq!(flow.get_src_asn(), 64496);
+        assert_eq!(flow.get_src_as_org().unwrap(), "Example Source Network");
+        assert_eq!(flow.get_dst_country().unwrap(), "FR");
+        assert_eq!(flow.get_dst_city().unwrap(), "Paris");
+        assert_eq!(flow.get_dst_asn(), 64497);
+        assert_eq!(
+            flow.get_dst_as_org().unwrap(),
+            "Example Destination Network"
+        );
+
+        // Addresses missing from the databases are left empty
+        let frame = udp_frame(
+            Ipv4Addr::new(10, 0, 0, 1),
+            Ipv4Addr::new(4, 4, 4, 4),
+            5000,
+            53,
+        );
+        let flows = serialize(
+            &super::tests::datagram(&[sampled_header(1, &frame)]),
+            &enrichment,
+        );
+        let message = read(&flows[0]);
+        let flow = message.get_root::<s_flow_flow_record::Reader>().unwrap();
+        assert_eq!(flow.get_src_country().unwrap(), "");
+        assert_eq!(flow.get_src_asn(), 0);
+        assert_eq!(flow.get_dst_country().unwrap(), "FR");
+    }
+
+    // Extended gateway flow record of the agent in AS 64500, with a one segment
+    // AS path to the destination unless empty
+    fn extended_gateway(as_path: &[u32]) -> (u32, Vec<u8>) {
+        let next_hop = u32::from_be_bytes([192, 0, 2, 254]);
+        let mut values = vec![1, next_hop, 64500, 64496, 64497];
+        if as_path.is_empty() {
+            values.push(0);
+        } else {
+            values.extend_from_slice(&[1, 2, as_path.len() as u32]);
+            values.extend_from_slice(as_path);
+        }
+        // No communities, local preference
+        values.extend_from_slice(&[0, 100]);
+        let data = values
+            .iter()
+            .flat_map(|value| value.to_be_bytes())
+            .collect();
+        (1003, data)
+    }
+
+    #[test]
+    fn gateway_as_numbers() {
+        let frame = udp_frame(
+            Ipv4Addr::new(3, 3, 3, 3),
+            Ipv4Addr::new(4, 4, 4, 4),
+            5000,
+            53,
+        );
+        let enrichment = Enrichment::default();
+
+        let records = [sampled_header(1, &frame), extended_gateway(&[64510, 64511])];
+        let flows = serialize(&datagram(&records), &enrichment);
+        let message = read(&flows[0]);
+        let flow = message.get_root::<s_flow_flow_record::Reader>().unwrap();
+        assert_eq!((flow.get_src_as(), flow.get_src_peer_as()), (64496, 64497));
+        assert_eq!((flow.get_dst_peer_as(), flow.get_dst_as()), (64510, 64511));
+        assert_eq!(flow.get_as_path().unwrap().len(), 2);
+
+        // Destinations in the agent's own AS
+        let records = [sampled_header(1, &frame), extended_gateway(&[])];
+        let flows = serialize(&datagram(&records), &enrichment);
+        let message = read(&flows[0]);
+        let flow = message.get_root::<s_flow_flow_record::Reader>().unwrap();
+        assert_eq!(flow.get_dst_as(), 64500);
+        assert_eq!(flow.get_local_pref(), 100);
+    }
+
+    fn parse_header(protocol: HeaderProtocol, header: &[u8]) -> Option<PacketData> {
+        let header = SampledHeader {
+            protocol,
+            frame_length: header.len() as u32,
+            stripped: 0,
+            header: header.to_vec(),
+        };
+        parse_raw_packet_header(&header)
+    }
+
+    // UDP packet 10.1.1.1:5000 -> 192.0.2.9:53, without the Ethernet header
+    fn ipv4_packet() -> Vec<u8> {
+        udp_frame(
+            Ipv4Addr::new(10, 1, 1, 1),
+            Ipv4Addr::new(192, 0, 2, 9),
+            5000,
+            53,
+        )[14..]
+            .to_vec()
+    }
+
+    fn ipv6_packet() -> Vec<u8> {
+        let mut packet = vec![0x60, 0, 0, 0, 0, 8, 17, 64];
+        packet.extend_from_slice(&"2001:db8::1".parse::<Ipv6Addr>().unwrap().octets());
+        packet.extend_from_slice(&"2001:db8::2".parse::<Ipv6Addr>().unwrap().octets());
+        packet.extend_from_slice(&[0x13, 0x88, 0x00, 0x35, 0, 8, 0, 0]);
+        packet
+    }
+
+    // Two labels, the second one at the bottom of the stack
+    const MPLS_LABELS: [u8; 8] = [0x00, 0x01, 0x40, 0x40, 0x00, 0x02, 0x01, 0x40];
+
+    fn assert_ipv4(data: Option<PacketData>) {
+        let data = data.expect("header should parse");
+        assert_eq!(data.src_ip, Ipv4Addr::new(10, 1, 1, 1).to_ipv6_mapped());
+        assert_eq!(data.dst_ip, Ipv4Addr::new(192, 0, 2, 9).to_ipv6_mapped());
+        assert_eq!(
+            (data.protocol, data.src_port, data.dst_port),
+            (17, 5000, 53)
+        );
+    }
+
+    #[test]
+    fn ppp_headers() {
+        let ipv4 = ipv4_packet();
+        // Full header, without address and control (ACFC), and with a one byte protocol (PFC)
+        for prefix in [
+            &[0xff, 0x03, 0x00, 0x21][..],
+            &[0x00, 0x21],
+            &[0x21],
+            &[0xff, 0x03, 0x21],
+        ] {
+            assert_ipv4(parse_header(HeaderProtocol::Ppp, &[prefix, &ipv4].concat()));
+        }
+        // Cisco HDLC
+        assert_ipv4(parse_header(
+            HeaderProtocol::Pos,
+            &[&[0x0f, 0x00, 0x08, 0x00][..], &ipv4].concat(),
+        ));
+
+        let data = parse_header(
+            HeaderProtocol::Ppp,
+            &[&[0x00, 0x57][..], &ipv6_packet()].concat(),
+        );
+        assert_eq!(
+            data.unwrap().dst_ip,
+            "2001:db8::2".parse::<Ipv6Addr>().unwrap()
+        );
+
+        // MPLS over PPP
+        let header = [&[0xff, 0x03, 0x02, 0x81][..], &MPLS_LABELS, &ipv4].concat();
+        assert_ipv4(parse_header(HeaderProtocol::Ppp, &header));
+    }
+
+    #[test]
+    fn ppp_invalid_headers() {
+        let ipv4 = ipv4_packet();
+        for header in [
+            &[][..],
+            &[0xff, 0x03],
+            &[0xff, 0x03, 0x00],
+            // LCP
+            &[0xff, 0x03, 0xc0, 0x21, 0x01],
+            // MPLS label stack without its bottom
+            &[0x02, 0x81, 0x00, 0x01, 0x40, 0x40],
+        ] {
+            assert!(strip_ppp_header(header).is_none(), "{:02x?}", header);
+        }
+        assert!(parse_header(
+            HeaderProtocol::Ppp,
+            &[&[0x00, 0x21][..], &ipv4[..10]].concat()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn mpls_headers() {
+        let header = [&MPLS_LABELS[..], &ipv4_packet()].concat();
+        assert_ipv4(parse_header(HeaderProtocol::Mpls, &header));
+        let header = [&MPLS_LABELS[4..], &ipv6_packet()].concat();
+        assert!(parse_header(HeaderProtocol::Mpls, &header).is_some());
+
+        assert_eq!(strip_mpls_labels(&MPLS_LABELS), Some(&[][..]));
+        assert!(strip_mpls_labels(&MPLS_LABELS[..4]).is_none());
+        assert!(strip_mpls_labels(&MPLS_LABELS[..6]).is_none());
+        assert!(parse_header(HeaderProtocol::Mpls, &MPLS_LABELS[..4]).is_none());
+    }
+
+    // 802.11 data frame header with the given frame control, flags and extra
+    // header bytes, followed by the LLC/SNAP header of IPv4
+    fn ieee80211_frame(frame_control: u8, flags: u8, extra: usize) -> Vec<u8> {
+        let mut frame = vec![frame_control, flags];
+        frame.resize(24 + extra, 0x11);
+        frame.extend_from_slice(&[0xaa, 0xaa, 0x03, 0x00, 0x00, 0x00, 0x08, 0x00]);
+        frame.extend_from_slice(&ipv4_packet());
+        frame
+    }
+
+    #[test]
+    fn ieee80211_headers() {
+        let parse = |frame: &[u8]| parse_header(HeaderProtocol::Ieee80211Mac, frame);
+        // Data frame to the DS, with four addresses, QoS data, and QoS data with HT control
+        assert_ipv4(parse(&ieee80211_frame(0x08, 0x01, 0)));
+        assert_ipv4(parse(&ieee80211_frame(0x08, 0x03, 6)));
+        assert_ipv4(parse(&ieee80211_frame(0x88, 0x01, 2)));
+        assert_ipv4(parse(&ieee80211_frame(0x88, 0x81, 6)));
 
-        messages.push(serialize::write_message_to_words(&message));
+        // Protected and management frames, truncated headers
+        assert!(parse(&ieee80211_frame(0x08, 0x41, 0)).is_none());
+        assert!(parse(&ieee80211_frame(0x00, 0x00, 0)).is_none());
+        let frame = ieee80211_frame(0x08, 0x01, 0);
+        assert!(strip_ieee80211_header(&frame[..1]).is_none());
+        assert!(strip_ieee80211_header(&frame[..24]).is_none());
+        assert!(strip_ieee80211_header(&frame[..30]).is_none());
     }
 }
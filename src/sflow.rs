@@ -1,86 +1,258 @@
 use anyhow::Result;
-use bytes::Bytes;
 use chrono::Utc;
-use metrics::counter;
-use sflow_parser::{parse_datagram, SFlowDatagram};
-use std::net::SocketAddr;
-use tokio::net::UdpSocket;
+use metrics::{counter, histogram};
+use sflow_parser::models::Address;
+use sflow_parser::parse_datagram;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::Arc;
 use tokio::sync::mpsc::Sender;
+use tokio::sync::watch;
 use tracing::{debug, error, trace};
 
-const MAX_DATAGRAM_SIZE: usize = 65535;
+use crate::acl::{self, RateLimiter, Reject};
+use crate::config::{AppConfig, SFlowListener};
+use crate::enrich::Enrichment;
+use crate::filter::Filter;
+use crate::sequence::SequenceTracker;
+use crate::serializer::{address_to_ip, serialize_sflow_record, SerializedRecords};
+use crate::socket::{Received, Receiver};
 
-pub async fn handle(socket: UdpSocket, tx: Sender<(SFlowDatagram, i64, SocketAddr)>) -> Result<()> {
-    let local_addr = socket.local_addr()?;
-    debug!("sFlow listener bound to {}", local_addr);
+/// Receive, parse and serialize the sFlow datagrams of a socket
+///
+/// A listener runs one worker per SO_REUSEPORT socket, the serialized records
+/// of all the workers go to the shared producer channel.
+pub async fn handle(
+    mut receiver: Receiver,
+    listener: Arc<SFlowListener>,
+    worker: usize,
+    config_rx: watch::Receiver<Arc<AppConfig>>,
+    enrichment_rx: watch::Receiver<Arc<Enrichment>>,
+    tx: Sender<SerializedRecords>,
+) -> Result<()> {
+    let local_addr = receiver.local_addr()?;
+    debug!(
+        "sFlow listener bound to {} (tag: {:?}, worker: {})",
+        local_addr, listener.tag, worker
+    );
 
-    let mut buf = vec![0u8; MAX_DATAGRAM_SIZE];
+    // Per-worker metrics, to spot an uneven spread of the agents over the workers
+    let labels = [
+        ("listener", local_addr.to_string()),
+        ("worker", worker.to_string()),
+    ];
+    let worker_datagrams = counter!("pesto_sflow_worker_datagrams_total", &labels);
+    let worker_bytes = counter!("pesto_sflow_worker_bytes_total", &labels);
+    let socket_drops = counter!("pesto_sflow_socket_drops_total", &labels);
+    let receive_delay = histogram!("pesto_sflow_receive_delay_seconds", &labels);
+    let mut sequences = SequenceTracker::default();
+    let mut rate_limiter = RateLimiter::default();
 
     loop {
-        match socket.recv_from(&mut buf).await {
-            Ok((n_bytes, peer_addr)) => {
-                if n_bytes == 0 {
-                    continue;
+        let count = match receiver.recv().await {
+            Ok(count) => count,
+            Err(e) => {
+                error!("Failed to receive UDP datagram: {}", e);
+                return Err(e.into());
+            }
+        };
+        let now_ns = Utc::now().timestamp_nanos_opt().unwrap();
+        let config = config_rx.borrow().clone();
+        let enrichment = enrichment_rx.borrow().clone();
+
+        // Datagrams dropped by the kernel on a full receive buffer
+        let drops = receiver.take_drops();
+        if drops > 0 {
+            socket_drops.increment(drops);
+            debug!(
+                "{} datagrams dropped by the kernel on {}",
+                drops, local_addr
+            );
+        }
+
+        for i in 0..count {
+            let Received {
+                data,
+                peer_addr,
+                kernel_time_ns,
+            } = receiver.get(i);
+            let n_bytes = data.len();
+            if n_bytes == 0 {
+                continue;
+            }
+
+            // Kernel timestamps leave out the time spent in the socket queue
+            let time_received_ns = match kernel_time_ns {
+                Some(kernel_time_ns) => {
+                    receive_delay.record((now_ns - kernel_time_ns) as f64 / 1e9);
+                    kernel_time_ns
                 }
+                None => now_ns,
+            };
 
-                let time_received_ns = Utc::now().timestamp_nanos_opt().unwrap();
-                trace!("Received {} bytes from {}", n_bytes, peer_addr);
-
-                // Parse the sFlow datagram
-                let data = Bytes::copy_from_slice(&buf[..n_bytes]);
-                match parse_datagram(&data) {
-                    Ok(datagram) => {
-                        counter!("pesto_sflow_datagrams_total", "status" => "success").increment(1);
-                        counter!("pesto_sflow_samples_total")
-                            .increment(datagram.samples.len() as u64);
-
-                        trace!(
-                            "Parsed sFlow datagram: version={:?}, agent={:?}, samples={}",
-                            datagram.version,
-                            datagram.agent_address,
-                            datagram.samples.len()
-                        );
-
-                        // Send to producer
-                        trace!("Sending datagram to producer channel");
-                        match tx.send((datagram, time_received_ns, peer_addr)).await {
-                            Ok(_) => {
-                                trace!("Successfully sent datagram to producer");
-                            }
-                            Err(e) => {
-                                error!("Failed to send datagram to producer: {}", e);
-                            }
-                        }
+            trace!("Received {} bytes from {}", n_bytes, peer_addr);
+            worker_datagrams.increment(1);
+            worker_bytes.increment(n_bytes as u64);
+
+            // Drop the datagrams of unknown hosts before parsing them
+            if let Err(reject) =
+                acl::check_peer(&config.sflow.allow, &config.sflow.deny, peer_addr.ip())
+            {
+                rejected(reject, peer_addr);
+                continue;
+            }
+
+            // Parse the sFlow datagram
+            match parse_datagram(data) {
+                Ok(mut datagram) => {
+                    counter!("pesto_sflow_datagrams_total", "status" => "success").increment(1);
+                    counter!("pesto_sflow_samples_total").increment(datagram.samples.len() as u64);
+
+                    trace!(
+                        "Parsed sFlow datagram: version={:?}, agent={:?}, samples={}",
+                        datagram.version,
+                        datagram.agent_address,
+                        datagram.samples.len()
+                    );
+
+                    // Relayed or NATed datagrams, and agents reporting an unusable address
+                    let mismatch = check_agent_address(
+                        &mut datagram.agent_address,
+                        peer_addr.ip(),
+                        config.sflow.substitute_agent_address,
+                    );
+
+                    // Agent ACL, by agent address
+                    let agent = address_to_ip(&datagram.agent_address);
+                    if let Err(reject) = acl::check_agent(&config.sflow, agent) {
+                        rejected(reject, peer_addr);
+                        continue;
                     }
-                    Err(e) => {
-                        counter!("pesto_sflow_datagrams_total", "status" => "parse_error")
+                    if let Some(kind) = mismatch {
+                        counter!("pesto_sflow_agent_address_mismatch_total", "kind" => kind)
                             .increment(1);
+                    }
 
-                        // Try to extract version from first 4 bytes to help diagnose
-                        let version_hint = if n_bytes >= 4 {
-                            let version_bytes = [buf[0], buf[1], buf[2], buf[3]];
-                            let version = u32::from_be_bytes(version_bytes);
-                            format!(" (version field: {})", version)
-                        } else {
-                            String::from(" (too short for version)")
-                        };
-
-                        error!(
-                            "Failed to parse sFlow datagram from {} (size: {} bytes{}): {:?}",
-                            peer_addr, n_bytes, version_hint, e
-                        );
-                        debug!(
-                            "Failed datagram header (first 32 bytes): {:02x?}",
-                            &buf[..n_bytes.min(32)]
-                        );
-                        trace!("Failed datagram full data: {:02x?}", &buf[..n_bytes]);
+                    // Gaps, duplicates, reorders and resets of the agent sequence
+                    // numbers, before the rate limit so that its drops are not
+                    // counted as sFlow loss
+                    sequences.observe(&datagram);
+
+                    // Rate limit, by agent address or UDP source address
+                    if let Err(reject) =
+                        rate_limiter.check(&config.sflow, agent.unwrap_or(peer_addr.ip()))
+                    {
+                        rejected(reject, peer_addr);
+                        continue;
+                    }
+
+                    // Serialize the sFlow records (flow, counter and discarded packet samples)
+                    let topic = listener.topic.as_ref().unwrap_or(&config.kafka.topic);
+                    let mut records = serialize_sflow_record(
+                        &datagram,
+                        time_received_ns,
+                        peer_addr,
+                        &listener.tag,
+                        config.kafka.key,
+                        Filter::new(&config.filters, topic),
+                        config.anonymizer.as_ref(),
+                        &enrichment,
+                    );
+                    records.flows_topic = listener.topic.clone();
+                    trace!(
+                        "Serialized {} flow, {} counter and {} drop messages from datagram",
+                        records.flows.len(),
+                        records.counters.len(),
+                        records.drops.len()
+                    );
+
+                    // Send to producer
+                    trace!("Sending records to producer channel");
+                    match tx.send(records).await {
+                        Ok(_) => {
+                            trace!("Successfully sent records to producer");
+                        }
+                        Err(e) => {
+                            error!("Failed to send records to producer: {}", e);
+                        }
                     }
                 }
-            }
-            Err(e) => {
-                error!("Failed to receive UDP datagram: {}", e);
-                return Err(e.into());
+                Err(e) => {
+                    counter!("pesto_sflow_datagrams_total", "status" => "parse_error").increment(1);
+
+                    // Try to extract version from first 4 bytes to help diagnose
+                    let version_hint = if n_bytes >= 4 {
+                        let version_bytes = [data[0], data[1], data[2], data[3]];
+                        let version = u32::from_be_bytes(version_bytes);
+                        format!(" (version field: {})", version)
+                    } else {
+                        String::from(" (too short for version)")
+                    };
+
+                    error!(
+                        "Failed to parse sFlow datagram from {} (size: {} bytes{}): {:?}",
+                        peer_addr, n_bytes, version_hint, e
+                    );
+                    debug!(
+                        "Failed datagram header (first 32 bytes): {:02x?}",
+                        &data[..n_bytes.min(32)]
+                    );
+                    trace!("Failed datagram full data: {:02x?}", data);
+                }
             }
         }
     }
 }
+
+// Kind of the agent address differing from the UDP source address (unknown,
+// private or public), replaced by the UDP source address if unknown or private
+// and enabled
+fn check_agent_address(
+    agent_address: &mut Address,
+    peer: IpAddr,
+    substitute: bool,
+) -> Option<&'static str> {
+    let peer = peer.to_canonical();
+    let agent = address_to_ip(agent_address);
+    if agent == Some(peer) {
+        return None;
+    }
+
+    let kind = match agent {
+        None => "unknown",
+        Some(agent) if is_private(agent) => "private",
+        Some(_) => "public",
+    };
+
+    if substitute && agent.is_none_or(is_private) {
+        trace!("Replacing agent address {:?} by {}", agent_address, peer);
+        *agent_address = match peer {
+            IpAddr::V4(peer) => Address::IPv4(peer),
+            IpAddr::V6(peer) => Address::IPv6(peer),
+        };
+    }
+    Some(kind)
+}
+
+// Addresses only meaningful inside the network of the agent
+fn is_private(addr: IpAddr) -> bool {
+    match addr {
+        IpAddr::V4(addr) => {
+            addr.is_private() || addr.is_loopback() || addr.is_link_local() || addr.is_unspecified()
+        }
+        IpAddr::V6(addr) => {
+            addr.is_unique_local()
+                || addr.is_unicast_link_local()
+                || addr.is_loopback()
+                || addr.is_unspecified()
+        }
+    }
+}
+
+fn rejected(reject: Reject, peer_addr: SocketAddr) {
+    counter!("pesto_sflow_rejected_datagrams_total", "reason" => reject.label()).increment(1);
+    trace!(
+        "Rejected sFlow datagram from {}: {}",
+        peer_addr,
+        reject.label()
+    );
+}
@@ -160,11 +160,251 @@ pub mod s_flow_flow_record {
     pub fn get_tos(self) -> u32 {
       self.reader.get_data_field::<u32>(18)
     }
+    #[inline]
+    pub fn get_src_mac(self) -> u64 {
+      self.reader.get_data_field::<u64>(10)
+    }
+    #[inline]
+    pub fn get_dst_mac(self) -> u64 {
+      self.reader.get_data_field::<u64>(11)
+    }
+    #[inline]
+    pub fn get_etype(self) -> u32 {
+      self.reader.get_data_field::<u32>(19)
+    }
+    #[inline]
+    pub fn get_vlan_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(24)
+    }
+    #[inline]
+    pub fn get_inner_vlan_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(25)
+    }
+    #[inline]
+    pub fn get_src_vlan(self) -> u32 {
+      self.reader.get_data_field::<u32>(26)
+    }
+    #[inline]
+    pub fn get_src_priority(self) -> u32 {
+      self.reader.get_data_field::<u32>(27)
+    }
+    #[inline]
+    pub fn get_dst_vlan(self) -> u32 {
+      self.reader.get_data_field::<u32>(28)
+    }
+    #[inline]
+    pub fn get_dst_priority(self) -> u32 {
+      self.reader.get_data_field::<u32>(29)
+    }
+    #[inline]
+    pub fn get_next_hop(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_next_hop(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_src_mask_len(self) -> u32 {
+      self.reader.get_data_field::<u32>(30)
+    }
+    #[inline]
+    pub fn get_dst_mask_len(self) -> u32 {
+      self.reader.get_data_field::<u32>(31)
+    }
+    #[inline]
+    pub fn get_bgp_next_hop(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(4), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_bgp_next_hop(&self) -> bool {
+      !self.reader.get_pointer_field(4).is_null()
+    }
+    #[inline]
+    pub fn get_src_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(32)
+    }
+    #[inline]
+    pub fn get_dst_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(33)
+    }
+    #[inline]
+    pub fn get_src_peer_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(34)
+    }
+    #[inline]
+    pub fn get_dst_peer_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(35)
+    }
+    #[inline]
+    pub fn get_as_path(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a,u32>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(5), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_as_path(&self) -> bool {
+      !self.reader.get_pointer_field(5).is_null()
+    }
+    #[inline]
+    pub fn get_communities(self) -> ::capnp::Result<::capnp::primitive_list::Reader<'a,u32>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(6), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_communities(&self) -> bool {
+      !self.reader.get_pointer_field(6).is_null()
+    }
+    #[inline]
+    pub fn get_local_pref(self) -> u32 {
+      self.reader.get_data_field::<u32>(36)
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(7), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.reader.get_pointer_field(7).is_null()
+    }
+    #[inline]
+    pub fn get_peer_addr(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(8), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_peer_addr(&self) -> bool {
+      !self.reader.get_pointer_field(8).is_null()
+    }
+    #[inline]
+    pub fn get_src_country(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(9), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_src_country(&self) -> bool {
+      !self.reader.get_pointer_field(9).is_null()
+    }
+    #[inline]
+    pub fn get_dst_country(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(10), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_dst_country(&self) -> bool {
+      !self.reader.get_pointer_field(10).is_null()
+    }
+    #[inline]
+    pub fn get_src_city(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(11), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_src_city(&self) -> bool {
+      !self.reader.get_pointer_field(11).is_null()
+    }
+    #[inline]
+    pub fn get_dst_city(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(12), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_dst_city(&self) -> bool {
+      !self.reader.get_pointer_field(12).is_null()
+    }
+    #[inline]
+    pub fn get_src_asn(self) -> u32 {
+      self.reader.get_data_field::<u32>(37)
+    }
+    #[inline]
+    pub fn get_dst_asn(self) -> u32 {
+      self.reader.get_data_field::<u32>(38)
+    }
+    #[inline]
+    pub fn get_src_as_org(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(13), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_src_as_org(&self) -> bool {
+      !self.reader.get_pointer_field(13).is_null()
+    }
+    #[inline]
+    pub fn get_dst_as_org(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(14), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_dst_as_org(&self) -> bool {
+      !self.reader.get_pointer_field(14).is_null()
+    }
+    #[inline]
+    pub fn get_agent_hostname(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(15), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_agent_hostname(&self) -> bool {
+      !self.reader.get_pointer_field(15).is_null()
+    }
+    #[inline]
+    pub fn get_agent_site(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(16), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_agent_site(&self) -> bool {
+      !self.reader.get_pointer_field(16).is_null()
+    }
+    #[inline]
+    pub fn get_agent_role(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(17), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_agent_role(&self) -> bool {
+      !self.reader.get_pointer_field(17).is_null()
+    }
+    #[inline]
+    pub fn get_input_interface_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(18), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_input_interface_name(&self) -> bool {
+      !self.reader.get_pointer_field(18).is_null()
+    }
+    #[inline]
+    pub fn get_input_interface_description(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(19), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_input_interface_description(&self) -> bool {
+      !self.reader.get_pointer_field(19).is_null()
+    }
+    #[inline]
+    pub fn get_input_interface_speed(self) -> u64 {
+      self.reader.get_data_field::<u64>(20)
+    }
+    #[inline]
+    pub fn get_input_interface_boundary(self) -> u8 {
+      self.reader.get_data_field::<u8>(10)
+    }
+    #[inline]
+    pub fn get_output_interface_name(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(20), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_output_interface_name(&self) -> bool {
+      !self.reader.get_pointer_field(20).is_null()
+    }
+    #[inline]
+    pub fn get_output_interface_description(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(21), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_output_interface_description(&self) -> bool {
+      !self.reader.get_pointer_field(21).is_null()
+    }
+    #[inline]
+    pub fn get_output_interface_speed(self) -> u64 {
+      self.reader.get_data_field::<u64>(21)
+    }
+    #[inline]
+    pub fn get_output_interface_boundary(self) -> u8 {
+      self.reader.get_data_field::<u8>(11)
+    }
   }
 
   pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
   impl <> ::capnp::traits::HasStructSize for Builder<'_,>  {
-    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 10, pointers: 3 };
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 22, pointers: 22 };
   }
   impl <> ::capnp::traits::HasTypeId for Builder<'_,>  {
     const TYPE_ID: u64 = _private::TYPE_ID;
@@ -303,108 +543,4443 @@ pub mod s_flow_flow_record {
       self.builder.set_data_field::<u32>(9, value);
     }
     #[inline]
-    pub fn get_drops(self) -> u32 {
-      self.builder.get_data_field::<u32>(10)
+    pub fn get_drops(self) -> u32 {
+      self.builder.get_data_field::<u32>(10)
+    }
+    #[inline]
+    pub fn set_drops(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(10, value);
+    }
+    #[inline]
+    pub fn get_input_interface(self) -> u32 {
+      self.builder.get_data_field::<u32>(11)
+    }
+    #[inline]
+    pub fn set_input_interface(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(11, value);
+    }
+    #[inline]
+    pub fn get_output_interface(self) -> u32 {
+      self.builder.get_data_field::<u32>(12)
+    }
+    #[inline]
+    pub fn set_output_interface(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(12, value);
+    }
+    #[inline]
+    pub fn get_length(self) -> u32 {
+      self.builder.get_data_field::<u32>(13)
+    }
+    #[inline]
+    pub fn set_length(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(13, value);
+    }
+    #[inline]
+    pub fn get_protocol(self) -> u32 {
+      self.builder.get_data_field::<u32>(14)
+    }
+    #[inline]
+    pub fn set_protocol(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(14, value);
+    }
+    #[inline]
+    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(1).set_data(value);
+    }
+    #[inline]
+    pub fn init_src_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(1).init_data(size)
+    }
+    #[inline]
+    pub fn has_src_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(2).set_data(value);
+    }
+    #[inline]
+    pub fn init_dst_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(2).init_data(size)
+    }
+    #[inline]
+    pub fn has_dst_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_src_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(15)
+    }
+    #[inline]
+    pub fn set_src_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(15, value);
+    }
+    #[inline]
+    pub fn get_dst_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn set_dst_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(16, value);
+    }
+    #[inline]
+    pub fn get_tcp_flags(self) -> u32 {
+      self.builder.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn set_tcp_flags(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(17, value);
+    }
+    #[inline]
+    pub fn get_tos(self) -> u32 {
+      self.builder.get_data_field::<u32>(18)
+    }
+    #[inline]
+    pub fn set_tos(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(18, value);
+    }
+    #[inline]
+    pub fn get_src_mac(self) -> u64 {
+      self.builder.get_data_field::<u64>(10)
+    }
+    #[inline]
+    pub fn set_src_mac(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(10, value);
+    }
+    #[inline]
+    pub fn get_dst_mac(self) -> u64 {
+      self.builder.get_data_field::<u64>(11)
+    }
+    #[inline]
+    pub fn set_dst_mac(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(11, value);
+    }
+    #[inline]
+    pub fn get_etype(self) -> u32 {
+      self.builder.get_data_field::<u32>(19)
+    }
+    #[inline]
+    pub fn set_etype(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(19, value);
+    }
+    #[inline]
+    pub fn get_vlan_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(24)
+    }
+    #[inline]
+    pub fn set_vlan_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(24, value);
+    }
+    #[inline]
+    pub fn get_inner_vlan_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(25)
+    }
+    #[inline]
+    pub fn set_inner_vlan_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(25, value);
+    }
+    #[inline]
+    pub fn get_src_vlan(self) -> u32 {
+      self.builder.get_data_field::<u32>(26)
+    }
+    #[inline]
+    pub fn set_src_vlan(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(26, value);
+    }
+    #[inline]
+    pub fn get_src_priority(self) -> u32 {
+      self.builder.get_data_field::<u32>(27)
+    }
+    #[inline]
+    pub fn set_src_priority(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(27, value);
+    }
+    #[inline]
+    pub fn get_dst_vlan(self) -> u32 {
+      self.builder.get_data_field::<u32>(28)
+    }
+    #[inline]
+    pub fn set_dst_vlan(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(28, value);
+    }
+    #[inline]
+    pub fn get_dst_priority(self) -> u32 {
+      self.builder.get_data_field::<u32>(29)
+    }
+    #[inline]
+    pub fn set_dst_priority(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(29, value);
+    }
+    #[inline]
+    pub fn get_next_hop(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_next_hop(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(3).set_data(value);
+    }
+    #[inline]
+    pub fn init_next_hop(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(3).init_data(size)
+    }
+    #[inline]
+    pub fn has_next_hop(&self) -> bool {
+      !self.builder.is_pointer_field_null(3)
+    }
+    #[inline]
+    pub fn get_src_mask_len(self) -> u32 {
+      self.builder.get_data_field::<u32>(30)
+    }
+    #[inline]
+    pub fn set_src_mask_len(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(30, value);
+    }
+    #[inline]
+    pub fn get_dst_mask_len(self) -> u32 {
+      self.builder.get_data_field::<u32>(31)
+    }
+    #[inline]
+    pub fn set_dst_mask_len(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(31, value);
+    }
+    #[inline]
+    pub fn get_bgp_next_hop(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(4), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_bgp_next_hop(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(4).set_data(value);
+    }
+    #[inline]
+    pub fn init_bgp_next_hop(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(4).init_data(size)
+    }
+    #[inline]
+    pub fn has_bgp_next_hop(&self) -> bool {
+      !self.builder.is_pointer_field_null(4)
+    }
+    #[inline]
+    pub fn get_src_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(32)
+    }
+    #[inline]
+    pub fn set_src_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(32, value);
+    }
+    #[inline]
+    pub fn get_dst_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(33)
+    }
+    #[inline]
+    pub fn set_dst_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(33, value);
+    }
+    #[inline]
+    pub fn get_src_peer_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(34)
+    }
+    #[inline]
+    pub fn set_src_peer_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(34, value);
+    }
+    #[inline]
+    pub fn get_dst_peer_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(35)
+    }
+    #[inline]
+    pub fn set_dst_peer_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(35, value);
+    }
+    #[inline]
+    pub fn get_as_path(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a,u32>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(5), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_as_path(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::primitive_list::Owned<u32>>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(5), value, false)
+    }
+    #[inline]
+    pub fn init_as_path(self, size: u32) -> ::capnp::primitive_list::Builder<'a,u32> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(5), size)
+    }
+    #[inline]
+    pub fn has_as_path(&self) -> bool {
+      !self.builder.is_pointer_field_null(5)
+    }
+    #[inline]
+    pub fn get_communities(self) -> ::capnp::Result<::capnp::primitive_list::Builder<'a,u32>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(6), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_communities(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::primitive_list::Owned<u32>>) -> ::capnp::Result<()> {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(6), value, false)
+    }
+    #[inline]
+    pub fn init_communities(self, size: u32) -> ::capnp::primitive_list::Builder<'a,u32> {
+      ::capnp::traits::FromPointerBuilder::init_pointer(self.builder.get_pointer_field(6), size)
+    }
+    #[inline]
+    pub fn has_communities(&self) -> bool {
+      !self.builder.is_pointer_field_null(6)
+    }
+    #[inline]
+    pub fn get_local_pref(self) -> u32 {
+      self.builder.get_data_field::<u32>(36)
+    }
+    #[inline]
+    pub fn set_local_pref(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(36, value);
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(7), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_listener_tag(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(7), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_listener_tag(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(7).init_text(size)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.builder.is_pointer_field_null(7)
+    }
+    #[inline]
+    pub fn get_peer_addr(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(8), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_peer_addr(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(8).set_data(value);
+    }
+    #[inline]
+    pub fn init_peer_addr(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(8).init_data(size)
+    }
+    #[inline]
+    pub fn has_peer_addr(&self) -> bool {
+      !self.builder.is_pointer_field_null(8)
+    }
+    #[inline]
+    pub fn get_src_country(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(9), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_country(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(9), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_src_country(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(9).init_text(size)
+    }
+    #[inline]
+    pub fn has_src_country(&self) -> bool {
+      !self.builder.is_pointer_field_null(9)
+    }
+    #[inline]
+    pub fn get_dst_country(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(10), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_country(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(10), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_dst_country(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(10).init_text(size)
+    }
+    #[inline]
+    pub fn has_dst_country(&self) -> bool {
+      !self.builder.is_pointer_field_null(10)
+    }
+    #[inline]
+    pub fn get_src_city(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(11), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_city(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(11), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_src_city(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(11).init_text(size)
+    }
+    #[inline]
+    pub fn has_src_city(&self) -> bool {
+      !self.builder.is_pointer_field_null(11)
+    }
+    #[inline]
+    pub fn get_dst_city(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(12), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_city(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(12), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_dst_city(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(12).init_text(size)
+    }
+    #[inline]
+    pub fn has_dst_city(&self) -> bool {
+      !self.builder.is_pointer_field_null(12)
+    }
+    #[inline]
+    pub fn get_src_asn(self) -> u32 {
+      self.builder.get_data_field::<u32>(37)
+    }
+    #[inline]
+    pub fn set_src_asn(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(37, value);
+    }
+    #[inline]
+    pub fn get_dst_asn(self) -> u32 {
+      self.builder.get_data_field::<u32>(38)
+    }
+    #[inline]
+    pub fn set_dst_asn(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(38, value);
+    }
+    #[inline]
+    pub fn get_src_as_org(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(13), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_as_org(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(13), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_src_as_org(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(13).init_text(size)
+    }
+    #[inline]
+    pub fn has_src_as_org(&self) -> bool {
+      !self.builder.is_pointer_field_null(13)
+    }
+    #[inline]
+    pub fn get_dst_as_org(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(14), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_as_org(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(14), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_dst_as_org(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(14).init_text(size)
+    }
+    #[inline]
+    pub fn has_dst_as_org(&self) -> bool {
+      !self.builder.is_pointer_field_null(14)
+    }
+    #[inline]
+    pub fn get_agent_hostname(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(15), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_agent_hostname(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(15), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_agent_hostname(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(15).init_text(size)
+    }
+    #[inline]
+    pub fn has_agent_hostname(&self) -> bool {
+      !self.builder.is_pointer_field_null(15)
+    }
+    #[inline]
+    pub fn get_agent_site(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(16), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_agent_site(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(16), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_agent_site(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(16).init_text(size)
+    }
+    #[inline]
+    pub fn has_agent_site(&self) -> bool {
+      !self.builder.is_pointer_field_null(16)
+    }
+    #[inline]
+    pub fn get_agent_role(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(17), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_agent_role(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(17), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_agent_role(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(17).init_text(size)
+    }
+    #[inline]
+    pub fn has_agent_role(&self) -> bool {
+      !self.builder.is_pointer_field_null(17)
+    }
+    #[inline]
+    pub fn get_input_interface_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(18), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_input_interface_name(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(18), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_input_interface_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(18).init_text(size)
+    }
+    #[inline]
+    pub fn has_input_interface_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(18)
+    }
+    #[inline]
+    pub fn get_input_interface_description(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(19), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_input_interface_description(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(19), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_input_interface_description(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(19).init_text(size)
+    }
+    #[inline]
+    pub fn has_input_interface_description(&self) -> bool {
+      !self.builder.is_pointer_field_null(19)
+    }
+    #[inline]
+    pub fn get_input_interface_speed(self) -> u64 {
+      self.builder.get_data_field::<u64>(20)
+    }
+    #[inline]
+    pub fn set_input_interface_speed(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(20, value);
+    }
+    #[inline]
+    pub fn get_input_interface_boundary(self) -> u8 {
+      self.builder.get_data_field::<u8>(10)
+    }
+    #[inline]
+    pub fn set_input_interface_boundary(&mut self, value: u8)  {
+      self.builder.set_data_field::<u8>(10, value);
+    }
+    #[inline]
+    pub fn get_output_interface_name(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(20), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_output_interface_name(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(20), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_output_interface_name(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(20).init_text(size)
+    }
+    #[inline]
+    pub fn has_output_interface_name(&self) -> bool {
+      !self.builder.is_pointer_field_null(20)
+    }
+    #[inline]
+    pub fn get_output_interface_description(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(21), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_output_interface_description(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(21), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_output_interface_description(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(21).init_text(size)
+    }
+    #[inline]
+    pub fn has_output_interface_description(&self) -> bool {
+      !self.builder.is_pointer_field_null(21)
+    }
+    #[inline]
+    pub fn get_output_interface_speed(self) -> u64 {
+      self.builder.get_data_field::<u64>(21)
+    }
+    #[inline]
+    pub fn set_output_interface_speed(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(21, value);
+    }
+    #[inline]
+    pub fn get_output_interface_boundary(self) -> u8 {
+      self.builder.get_data_field::<u8>(11)
+    }
+    #[inline]
+    pub fn set_output_interface_boundary(&mut self, value: u8)  {
+      self.builder.set_data_field::<u8>(11, value);
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl Pipeline  {
+  }
+  mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 1009] = [
+      ::capnp::word(0, 0, 0, 0, 6, 0, 6, 0),
+      ::capnp::word(26, 85, 235, 151, 144, 216, 39, 207),
+      ::capnp::word(12, 0, 0, 0, 1, 0, 22, 0),
+      ::capnp::word(201, 184, 167, 246, 229, 212, 195, 184),
+      ::capnp::word(22, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(143, 0, 0, 0, 212, 10, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 226, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 151, 13, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 102, 108, 111, 119, 46, 99, 97),
+      ::capnp::word(112, 110, 112, 58, 83, 70, 108, 111),
+      ::capnp::word(119, 70, 108, 111, 119, 82, 101, 99),
+      ::capnp::word(111, 114, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(248, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(185, 6, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(184, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(196, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 6, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(192, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(204, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(201, 6, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(200, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(212, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(209, 6, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(208, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(220, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(217, 6, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(220, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(232, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(229, 6, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(224, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(236, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(233, 6, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(236, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(248, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(245, 6, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(244, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(0, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(253, 6, 0, 0, 106, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(252, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(9, 0, 0, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(5, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(10, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(11, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 7, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(28, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 7, 0, 0, 130, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(24, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(36, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(13, 0, 0, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(33, 7, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(28, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(40, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(14, 0, 0, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(37, 7, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(15, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(44, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(56, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(17, 0, 0, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(53, 7, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(60, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(18, 0, 0, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(57, 7, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(52, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(64, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(19, 0, 0, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(61, 7, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(60, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(72, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 7, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(64, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(21, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(73, 7, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(22, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 22, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 7, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(72, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(23, 0, 0, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 23, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(81, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(24, 0, 0, 0, 24, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 24, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(85, 7, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(80, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(92, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(25, 0, 0, 0, 25, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 25, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(89, 7, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(88, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(100, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(26, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 7, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(92, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(104, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(27, 0, 0, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 7, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(112, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(28, 0, 0, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(109, 7, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(104, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(116, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(29, 0, 0, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(113, 7, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(124, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(30, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 30, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(121, 7, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(128, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(31, 0, 0, 0, 30, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 31, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(125, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(124, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(136, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(32, 0, 0, 0, 31, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(133, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(132, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(144, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(33, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(141, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(140, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(34, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(149, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(144, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(156, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(35, 0, 0, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 35, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 7, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(148, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(160, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(36, 0, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 36, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(157, 7, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(156, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(168, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(37, 0, 0, 0, 35, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 37, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(165, 7, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(164, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(176, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(38, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 38, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(173, 7, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(168, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(196, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(39, 0, 0, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 39, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 7, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(192, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(220, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(40, 0, 0, 0, 36, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 40, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(217, 7, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(216, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(228, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(41, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 41, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(225, 7, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(224, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(236, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(42, 0, 0, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(233, 7, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(232, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(244, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(43, 0, 0, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 43, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(241, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(240, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(252, 7, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(44, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 44, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(249, 7, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(248, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(4, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(45, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 45, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(1, 8, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(252, 7, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(46, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 46, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(5, 8, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(12, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(47, 0, 0, 0, 37, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 47, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 8, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(16, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(48, 0, 0, 0, 38, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 48, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 8, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(49, 0, 0, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 49, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 8, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(28, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(50, 0, 0, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 8, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(24, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(36, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(51, 0, 0, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 51, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(33, 8, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(32, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(44, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(52, 0, 0, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 52, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(41, 8, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(40, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(52, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(53, 0, 0, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 53, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(49, 8, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(60, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(54, 0, 0, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 54, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(57, 8, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(60, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(72, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(55, 0, 0, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 55, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 8, 0, 0, 210, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(56, 0, 0, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 56, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(85, 8, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(88, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(100, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(57, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 57, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 8, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(112, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(58, 0, 0, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(109, 8, 0, 0, 162, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(124, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(59, 0, 0, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 59, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(121, 8, 0, 0, 218, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(128, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(140, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(60, 0, 0, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 60, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(137, 8, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(140, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(61, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 61, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(149, 8, 0, 0, 194, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(152, 8, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(164, 8, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(116, 105, 109, 101, 82, 101, 99, 101),
+      ::capnp::word(105, 118, 101, 100, 78, 115, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 65, 100, 100),
+      ::capnp::word(114, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 80, 111, 114),
+      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 83, 117, 98),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 97, 116, 97, 103, 114, 97, 109),
+      ::capnp::word(83, 101, 113, 117, 101, 110, 99, 101),
+      ::capnp::word(78, 117, 109, 98, 101, 114, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(117, 112, 116, 105, 109, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 101, 83, 101),
+      ::capnp::word(113, 117, 101, 110, 99, 101, 78, 117),
+      ::capnp::word(109, 98, 101, 114, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 111, 117, 114, 99, 101, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 105, 110, 103),
+      ::capnp::word(82, 97, 116, 101, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 101, 80, 111),
+      ::capnp::word(111, 108, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 114, 111, 112, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 101, 110, 103, 116, 104, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 116, 111, 99, 111, 108),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 80, 111, 114, 116, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 80, 111, 114, 116, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 99, 112, 70, 108, 97, 103, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 111, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 77, 97, 99, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 77, 97, 99, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 116, 121, 112, 101, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(118, 108, 97, 110, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 110, 101, 114, 86, 108, 97),
+      ::capnp::word(110, 73, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 86, 108, 97, 110, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 80, 114, 105, 111, 114),
+      ::capnp::word(105, 116, 121, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 86, 108, 97, 110, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 80, 114, 105, 111, 114),
+      ::capnp::word(105, 116, 121, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(110, 101, 120, 116, 72, 111, 112, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 77, 97, 115, 107, 76),
+      ::capnp::word(101, 110, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 77, 97, 115, 107, 76),
+      ::capnp::word(101, 110, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(98, 103, 112, 78, 101, 120, 116, 72),
+      ::capnp::word(111, 112, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 65, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 65, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 80, 101, 101, 114, 65),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 80, 101, 101, 114, 65),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 115, 80, 97, 116, 104, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(99, 111, 109, 109, 117, 110, 105, 116),
+      ::capnp::word(105, 101, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(14, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 111, 99, 97, 108, 80, 114, 101),
+      ::capnp::word(102, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 105, 115, 116, 101, 110, 101, 114),
+      ::capnp::word(84, 97, 103, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 101, 101, 114, 65, 100, 100, 114),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 67, 111, 117, 110, 116),
+      ::capnp::word(114, 121, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 67, 111, 117, 110, 116),
+      ::capnp::word(114, 121, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 67, 105, 116, 121, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 67, 105, 116, 121, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 65, 115, 110, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 65, 115, 110, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 65, 115, 79, 114, 103),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 65, 115, 79, 114, 103),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 72, 111, 115),
+      ::capnp::word(116, 110, 97, 109, 101, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 83, 105, 116),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 82, 111, 108),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 78, 97),
+      ::capnp::word(109, 101, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 68, 101),
+      ::capnp::word(115, 99, 114, 105, 112, 116, 105, 111),
+      ::capnp::word(110, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 83, 112),
+      ::capnp::word(101, 101, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 66, 111),
+      ::capnp::word(117, 110, 100, 97, 114, 121, 0, 0),
+      ::capnp::word(6, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(6, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 78),
+      ::capnp::word(97, 109, 101, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 68),
+      ::capnp::word(101, 115, 99, 114, 105, 112, 116, 105),
+      ::capnp::word(111, 110, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 83),
+      ::capnp::word(112, 101, 101, 100, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 66),
+      ::capnp::word(111, 117, 110, 100, 97, 114, 121, 0),
+      ::capnp::word(6, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(6, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        4 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        5 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        6 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        7 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        8 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        9 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        10 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        11 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        12 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        13 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        14 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        15 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        16 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        17 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        18 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        19 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        20 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        21 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        22 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        23 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        24 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        25 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        26 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        27 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        28 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        29 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        30 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        31 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        32 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        33 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        34 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        35 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        36 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        37 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        38 => <::capnp::primitive_list::Owned<u32> as ::capnp::introspect::Introspect>::introspect(),
+        39 => <::capnp::primitive_list::Owned<u32> as ::capnp::introspect::Introspect>::introspect(),
+        40 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        41 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        42 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        43 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        44 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        45 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        46 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        47 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        48 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        49 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        50 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        51 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        52 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        53 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        54 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        55 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        56 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        57 => <u8 as ::capnp::introspect::Introspect>::introspect(),
+        58 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        59 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        60 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        61 => <u8 as ::capnp::introspect::Introspect>::introspect(),
+        _ => ::capnp::introspect::panic_invalid_field_index(index),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      ::capnp::introspect::panic_invalid_annotation_indices(child_index, index)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      members_by_name: MEMBERS_BY_NAME,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,33,34,35,36,37,38,39,40,41,42,43,44,45,46,47,48,49,50,51,52,53,54,55,56,57,58,59,60,61];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub static MEMBERS_BY_NAME : &[u16] = &[1,51,2,53,52,3,38,33,39,4,10,35,50,48,46,44,16,22,32,37,18,29,28,23,25,11,57,55,54,56,13,41,40,30,12,61,59,58,60,42,14,9,6,8,7,34,49,47,45,43,15,21,31,36,17,27,26,19,0,20,5,24];
+    pub const TYPE_ID: u64 = 0xcf27_d890_97eb_551a;
+  }
+}
+
+pub mod s_flow_counter_record {
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <> ::core::marker::Copy for Reader<'_,>  {}
+  impl <> ::core::clone::Clone for Reader<'_,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <> ::capnp::traits::HasTypeId for Reader<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <> ::core::fmt::Debug for Reader<'_,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_agent_addr(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_agent_addr(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_agent_port(self) -> u16 {
+      self.reader.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn get_agent_sub_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn get_datagram_sequence_number(self) -> u32 {
+      self.reader.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn get_uptime(self) -> u32 {
+      self.reader.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn get_sample_sequence_number(self) -> u32 {
+      self.reader.get_data_field::<u32>(6)
+    }
+    #[inline]
+    pub fn get_source_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(7)
+    }
+    #[inline]
+    pub fn get_if_index(self) -> u32 {
+      self.reader.get_data_field::<u32>(8)
+    }
+    #[inline]
+    pub fn get_if_type(self) -> u32 {
+      self.reader.get_data_field::<u32>(9)
+    }
+    #[inline]
+    pub fn get_if_speed(self) -> u64 {
+      self.reader.get_data_field::<u64>(5)
+    }
+    #[inline]
+    pub fn get_if_direction(self) -> u32 {
+      self.reader.get_data_field::<u32>(12)
+    }
+    #[inline]
+    pub fn get_if_status(self) -> u32 {
+      self.reader.get_data_field::<u32>(13)
+    }
+    #[inline]
+    pub fn get_if_in_octets(self) -> u64 {
+      self.reader.get_data_field::<u64>(7)
+    }
+    #[inline]
+    pub fn get_if_in_ucast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn get_if_in_multicast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn get_if_in_broadcast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(18)
+    }
+    #[inline]
+    pub fn get_if_in_discards(self) -> u32 {
+      self.reader.get_data_field::<u32>(19)
+    }
+    #[inline]
+    pub fn get_if_in_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(20)
+    }
+    #[inline]
+    pub fn get_if_in_unknown_protos(self) -> u32 {
+      self.reader.get_data_field::<u32>(21)
+    }
+    #[inline]
+    pub fn get_if_out_octets(self) -> u64 {
+      self.reader.get_data_field::<u64>(11)
+    }
+    #[inline]
+    pub fn get_if_out_ucast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(24)
+    }
+    #[inline]
+    pub fn get_if_out_multicast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(25)
+    }
+    #[inline]
+    pub fn get_if_out_broadcast_pkts(self) -> u32 {
+      self.reader.get_data_field::<u32>(26)
+    }
+    #[inline]
+    pub fn get_if_out_discards(self) -> u32 {
+      self.reader.get_data_field::<u32>(27)
+    }
+    #[inline]
+    pub fn get_if_out_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(28)
+    }
+    #[inline]
+    pub fn get_if_promiscuous_mode(self) -> u32 {
+      self.reader.get_data_field::<u32>(29)
+    }
+    #[inline]
+    pub fn get_dot3_stats_alignment_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(30)
+    }
+    #[inline]
+    pub fn get_dot3_stats_fcs_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(31)
+    }
+    #[inline]
+    pub fn get_dot3_stats_single_collision_frames(self) -> u32 {
+      self.reader.get_data_field::<u32>(32)
+    }
+    #[inline]
+    pub fn get_dot3_stats_multiple_collision_frames(self) -> u32 {
+      self.reader.get_data_field::<u32>(33)
+    }
+    #[inline]
+    pub fn get_dot3_stats_sqe_test_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(34)
+    }
+    #[inline]
+    pub fn get_dot3_stats_deferred_transmissions(self) -> u32 {
+      self.reader.get_data_field::<u32>(35)
+    }
+    #[inline]
+    pub fn get_dot3_stats_late_collisions(self) -> u32 {
+      self.reader.get_data_field::<u32>(36)
+    }
+    #[inline]
+    pub fn get_dot3_stats_excessive_collisions(self) -> u32 {
+      self.reader.get_data_field::<u32>(37)
+    }
+    #[inline]
+    pub fn get_dot3_stats_internal_mac_transmit_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(38)
+    }
+    #[inline]
+    pub fn get_dot3_stats_carrier_sense_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(39)
+    }
+    #[inline]
+    pub fn get_dot3_stats_frame_too_longs(self) -> u32 {
+      self.reader.get_data_field::<u32>(40)
+    }
+    #[inline]
+    pub fn get_dot3_stats_internal_mac_receive_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(41)
+    }
+    #[inline]
+    pub fn get_dot3_stats_symbol_errors(self) -> u32 {
+      self.reader.get_data_field::<u32>(42)
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <> ::capnp::traits::HasStructSize for Builder<'_,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 22, pointers: 2 };
+  }
+  impl <> ::capnp::traits::HasTypeId for Builder<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <> ::capnp::traits::SetterInput<Owned<>> for Reader<'_,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_time_received_ns(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn get_agent_addr(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_agent_addr(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_data(value);
+    }
+    #[inline]
+    pub fn init_agent_addr(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(0).init_data(size)
+    }
+    #[inline]
+    pub fn has_agent_addr(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_agent_port(self) -> u16 {
+      self.builder.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn set_agent_port(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(4, value);
+    }
+    #[inline]
+    pub fn get_agent_sub_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn set_agent_sub_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(3, value);
+    }
+    #[inline]
+    pub fn get_datagram_sequence_number(self) -> u32 {
+      self.builder.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn set_datagram_sequence_number(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(4, value);
+    }
+    #[inline]
+    pub fn get_uptime(self) -> u32 {
+      self.builder.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn set_uptime(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(5, value);
+    }
+    #[inline]
+    pub fn get_sample_sequence_number(self) -> u32 {
+      self.builder.get_data_field::<u32>(6)
+    }
+    #[inline]
+    pub fn set_sample_sequence_number(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(6, value);
+    }
+    #[inline]
+    pub fn get_source_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(7)
+    }
+    #[inline]
+    pub fn set_source_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(7, value);
+    }
+    #[inline]
+    pub fn get_if_index(self) -> u32 {
+      self.builder.get_data_field::<u32>(8)
+    }
+    #[inline]
+    pub fn set_if_index(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(8, value);
+    }
+    #[inline]
+    pub fn get_if_type(self) -> u32 {
+      self.builder.get_data_field::<u32>(9)
+    }
+    #[inline]
+    pub fn set_if_type(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(9, value);
+    }
+    #[inline]
+    pub fn get_if_speed(self) -> u64 {
+      self.builder.get_data_field::<u64>(5)
+    }
+    #[inline]
+    pub fn set_if_speed(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(5, value);
+    }
+    #[inline]
+    pub fn get_if_direction(self) -> u32 {
+      self.builder.get_data_field::<u32>(12)
+    }
+    #[inline]
+    pub fn set_if_direction(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(12, value);
+    }
+    #[inline]
+    pub fn get_if_status(self) -> u32 {
+      self.builder.get_data_field::<u32>(13)
+    }
+    #[inline]
+    pub fn set_if_status(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(13, value);
+    }
+    #[inline]
+    pub fn get_if_in_octets(self) -> u64 {
+      self.builder.get_data_field::<u64>(7)
+    }
+    #[inline]
+    pub fn set_if_in_octets(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(7, value);
+    }
+    #[inline]
+    pub fn get_if_in_ucast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn set_if_in_ucast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(16, value);
+    }
+    #[inline]
+    pub fn get_if_in_multicast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn set_if_in_multicast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(17, value);
+    }
+    #[inline]
+    pub fn get_if_in_broadcast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(18)
+    }
+    #[inline]
+    pub fn set_if_in_broadcast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(18, value);
+    }
+    #[inline]
+    pub fn get_if_in_discards(self) -> u32 {
+      self.builder.get_data_field::<u32>(19)
+    }
+    #[inline]
+    pub fn set_if_in_discards(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(19, value);
+    }
+    #[inline]
+    pub fn get_if_in_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(20)
+    }
+    #[inline]
+    pub fn set_if_in_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(20, value);
+    }
+    #[inline]
+    pub fn get_if_in_unknown_protos(self) -> u32 {
+      self.builder.get_data_field::<u32>(21)
+    }
+    #[inline]
+    pub fn set_if_in_unknown_protos(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(21, value);
+    }
+    #[inline]
+    pub fn get_if_out_octets(self) -> u64 {
+      self.builder.get_data_field::<u64>(11)
+    }
+    #[inline]
+    pub fn set_if_out_octets(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(11, value);
+    }
+    #[inline]
+    pub fn get_if_out_ucast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(24)
+    }
+    #[inline]
+    pub fn set_if_out_ucast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(24, value);
+    }
+    #[inline]
+    pub fn get_if_out_multicast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(25)
+    }
+    #[inline]
+    pub fn set_if_out_multicast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(25, value);
+    }
+    #[inline]
+    pub fn get_if_out_broadcast_pkts(self) -> u32 {
+      self.builder.get_data_field::<u32>(26)
+    }
+    #[inline]
+    pub fn set_if_out_broadcast_pkts(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(26, value);
+    }
+    #[inline]
+    pub fn get_if_out_discards(self) -> u32 {
+      self.builder.get_data_field::<u32>(27)
+    }
+    #[inline]
+    pub fn set_if_out_discards(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(27, value);
+    }
+    #[inline]
+    pub fn get_if_out_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(28)
+    }
+    #[inline]
+    pub fn set_if_out_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(28, value);
+    }
+    #[inline]
+    pub fn get_if_promiscuous_mode(self) -> u32 {
+      self.builder.get_data_field::<u32>(29)
+    }
+    #[inline]
+    pub fn set_if_promiscuous_mode(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(29, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_alignment_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(30)
+    }
+    #[inline]
+    pub fn set_dot3_stats_alignment_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(30, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_fcs_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(31)
+    }
+    #[inline]
+    pub fn set_dot3_stats_fcs_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(31, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_single_collision_frames(self) -> u32 {
+      self.builder.get_data_field::<u32>(32)
+    }
+    #[inline]
+    pub fn set_dot3_stats_single_collision_frames(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(32, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_multiple_collision_frames(self) -> u32 {
+      self.builder.get_data_field::<u32>(33)
+    }
+    #[inline]
+    pub fn set_dot3_stats_multiple_collision_frames(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(33, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_sqe_test_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(34)
+    }
+    #[inline]
+    pub fn set_dot3_stats_sqe_test_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(34, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_deferred_transmissions(self) -> u32 {
+      self.builder.get_data_field::<u32>(35)
+    }
+    #[inline]
+    pub fn set_dot3_stats_deferred_transmissions(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(35, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_late_collisions(self) -> u32 {
+      self.builder.get_data_field::<u32>(36)
+    }
+    #[inline]
+    pub fn set_dot3_stats_late_collisions(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(36, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_excessive_collisions(self) -> u32 {
+      self.builder.get_data_field::<u32>(37)
+    }
+    #[inline]
+    pub fn set_dot3_stats_excessive_collisions(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(37, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_internal_mac_transmit_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(38)
+    }
+    #[inline]
+    pub fn set_dot3_stats_internal_mac_transmit_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(38, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_carrier_sense_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(39)
+    }
+    #[inline]
+    pub fn set_dot3_stats_carrier_sense_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(39, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_frame_too_longs(self) -> u32 {
+      self.builder.get_data_field::<u32>(40)
+    }
+    #[inline]
+    pub fn set_dot3_stats_frame_too_longs(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(40, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_internal_mac_receive_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(41)
+    }
+    #[inline]
+    pub fn set_dot3_stats_internal_mac_receive_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(41, value);
+    }
+    #[inline]
+    pub fn get_dot3_stats_symbol_errors(self) -> u32 {
+      self.builder.get_data_field::<u32>(42)
+    }
+    #[inline]
+    pub fn set_dot3_stats_symbol_errors(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(42, value);
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_listener_tag(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(1), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_listener_tag(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(1).init_text(size)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl Pipeline  {
+  }
+  mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 703] = [
+      ::capnp::word(0, 0, 0, 0, 6, 0, 6, 0),
+      ::capnp::word(192, 126, 90, 246, 100, 95, 117, 133),
+      ::capnp::word(12, 0, 0, 0, 1, 0, 22, 0),
+      ::capnp::word(201, 184, 167, 246, 229, 212, 195, 184),
+      ::capnp::word(2, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(103, 11, 0, 0, 151, 17, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 255, 8, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 102, 108, 111, 119, 46, 99, 97),
+      ::capnp::word(112, 110, 112, 58, 83, 70, 108, 111),
+      ::capnp::word(119, 67, 111, 117, 110, 116, 101, 114),
+      ::capnp::word(82, 101, 99, 111, 114, 100, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(164, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(109, 4, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(120, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(117, 4, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(128, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(125, 4, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(124, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(136, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(133, 4, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(132, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(144, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(141, 4, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(144, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(156, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 4, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(148, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(160, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(157, 4, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(172, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(169, 4, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(168, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(180, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(177, 4, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(172, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(184, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(9, 0, 0, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(181, 4, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(176, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(188, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(10, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(185, 4, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(180, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(11, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(189, 4, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(188, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(200, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(197, 4, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(196, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(208, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(13, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(205, 4, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(204, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(216, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(14, 0, 0, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(213, 4, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(212, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(224, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(15, 0, 0, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(221, 4, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(224, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(236, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(233, 4, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(236, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(248, 4, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(17, 0, 0, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(245, 4, 0, 0, 106, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(244, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(0, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(18, 0, 0, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(253, 4, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(252, 4, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(8, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(19, 0, 0, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(5, 5, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 5, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(16, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(28, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(21, 0, 0, 0, 24, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(25, 5, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(24, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(36, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(22, 0, 0, 0, 25, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 22, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(33, 5, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(36, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(48, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(23, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 23, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(45, 5, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(48, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(60, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(24, 0, 0, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 24, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(57, 5, 0, 0, 114, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(56, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(68, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(25, 0, 0, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 25, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(65, 5, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(64, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(26, 0, 0, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(73, 5, 0, 0, 146, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(27, 0, 0, 0, 30, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(85, 5, 0, 0, 202, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(92, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(104, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(28, 0, 0, 0, 31, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 5, 0, 0, 154, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(104, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(116, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(29, 0, 0, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(113, 5, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(120, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(132, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(30, 0, 0, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 30, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(129, 5, 0, 0, 10, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(140, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(31, 0, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 31, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(149, 5, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(152, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(164, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(32, 0, 0, 0, 35, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 32, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(161, 5, 0, 0, 250, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(168, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(180, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(33, 0, 0, 0, 36, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 33, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(177, 5, 0, 0, 194, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(180, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(34, 0, 0, 0, 37, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(189, 5, 0, 0, 234, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(196, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(208, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(35, 0, 0, 0, 38, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 35, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(205, 5, 0, 0, 26, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(216, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(228, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(36, 0, 0, 0, 39, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 36, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(225, 5, 0, 0, 226, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(232, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(244, 5, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(37, 0, 0, 0, 40, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 37, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(241, 5, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(244, 5, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(0, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(38, 0, 0, 0, 41, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 38, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(253, 5, 0, 0, 18, 1, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(20, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(39, 0, 0, 0, 42, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 39, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(17, 6, 0, 0, 178, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(20, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(32, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(40, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 40, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 6, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(28, 6, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(40, 6, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(116, 105, 109, 101, 82, 101, 99, 101),
+      ::capnp::word(105, 118, 101, 100, 78, 115, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 65, 100, 100),
+      ::capnp::word(114, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 80, 111, 114),
+      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 83, 117, 98),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 97, 116, 97, 103, 114, 97, 109),
+      ::capnp::word(83, 101, 113, 117, 101, 110, 99, 101),
+      ::capnp::word(78, 117, 109, 98, 101, 114, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(117, 112, 116, 105, 109, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 101, 83, 101),
+      ::capnp::word(113, 117, 101, 110, 99, 101, 78, 117),
+      ::capnp::word(109, 98, 101, 114, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 111, 117, 114, 99, 101, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 100, 101, 120, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 84, 121, 112, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 83, 112, 101, 101, 100, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 68, 105, 114, 101, 99, 116),
+      ::capnp::word(105, 111, 110, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 83, 116, 97, 116, 117, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 79, 99, 116, 101),
+      ::capnp::word(116, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 85, 99, 97, 115),
+      ::capnp::word(116, 80, 107, 116, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 77, 117, 108, 116),
+      ::capnp::word(105, 99, 97, 115, 116, 80, 107, 116),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 66, 114, 111, 97),
+      ::capnp::word(100, 99, 97, 115, 116, 80, 107, 116),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 68, 105, 115, 99),
+      ::capnp::word(97, 114, 100, 115, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 69, 114, 114, 111),
+      ::capnp::word(114, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 73, 110, 85, 110, 107, 110),
+      ::capnp::word(111, 119, 110, 80, 114, 111, 116, 111),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 79, 99, 116),
+      ::capnp::word(101, 116, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 85, 99, 97),
+      ::capnp::word(115, 116, 80, 107, 116, 115, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 77, 117, 108),
+      ::capnp::word(116, 105, 99, 97, 115, 116, 80, 107),
+      ::capnp::word(116, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 66, 114, 111),
+      ::capnp::word(97, 100, 99, 97, 115, 116, 80, 107),
+      ::capnp::word(116, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 68, 105, 115),
+      ::capnp::word(99, 97, 114, 100, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 79, 117, 116, 69, 114, 114),
+      ::capnp::word(111, 114, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 102, 80, 114, 111, 109, 105, 115),
+      ::capnp::word(99, 117, 111, 117, 115, 77, 111, 100),
+      ::capnp::word(101, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 65, 108, 105, 103, 110, 109, 101),
+      ::capnp::word(110, 116, 69, 114, 114, 111, 114, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 70, 99, 115, 69, 114, 114, 111),
+      ::capnp::word(114, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 83, 105, 110, 103, 108, 101, 67),
+      ::capnp::word(111, 108, 108, 105, 115, 105, 111, 110),
+      ::capnp::word(70, 114, 97, 109, 101, 115, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 77, 117, 108, 116, 105, 112, 108),
+      ::capnp::word(101, 67, 111, 108, 108, 105, 115, 105),
+      ::capnp::word(111, 110, 70, 114, 97, 109, 101, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 83, 113, 101, 84, 101, 115, 116),
+      ::capnp::word(69, 114, 114, 111, 114, 115, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 68, 101, 102, 101, 114, 114, 101),
+      ::capnp::word(100, 84, 114, 97, 110, 115, 109, 105),
+      ::capnp::word(115, 115, 105, 111, 110, 115, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 76, 97, 116, 101, 67, 111, 108),
+      ::capnp::word(108, 105, 115, 105, 111, 110, 115, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 69, 120, 99, 101, 115, 115, 105),
+      ::capnp::word(118, 101, 67, 111, 108, 108, 105, 115),
+      ::capnp::word(105, 111, 110, 115, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 73, 110, 116, 101, 114, 110, 97),
+      ::capnp::word(108, 77, 97, 99, 84, 114, 97, 110),
+      ::capnp::word(115, 109, 105, 116, 69, 114, 114, 111),
+      ::capnp::word(114, 115, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 67, 97, 114, 114, 105, 101, 114),
+      ::capnp::word(83, 101, 110, 115, 101, 69, 114, 114),
+      ::capnp::word(111, 114, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 70, 114, 97, 109, 101, 84, 111),
+      ::capnp::word(111, 76, 111, 110, 103, 115, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 73, 110, 116, 101, 114, 110, 97),
+      ::capnp::word(108, 77, 97, 99, 82, 101, 99, 101),
+      ::capnp::word(105, 118, 101, 69, 114, 114, 111, 114),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 111, 116, 51, 83, 116, 97, 116),
+      ::capnp::word(115, 83, 121, 109, 98, 111, 108, 69),
+      ::capnp::word(114, 114, 111, 114, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 105, 115, 116, 101, 110, 101, 114),
+      ::capnp::word(84, 97, 103, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        4 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        5 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        6 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        7 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        8 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        9 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        10 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        11 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        12 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        13 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        14 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        15 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        16 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        17 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        18 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        19 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        20 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        21 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        22 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        23 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        24 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        25 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        26 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        27 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        28 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        29 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        30 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        31 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        32 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        33 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        34 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        35 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        36 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        37 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        38 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        39 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        40 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => ::capnp::introspect::panic_invalid_field_index(index),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      ::capnp::introspect::panic_invalid_annotation_indices(child_index, index)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      members_by_name: MEMBERS_BY_NAME,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29,30,31,32,33,34,35,36,37,38,39,40];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub static MEMBERS_BY_NAME : &[u16] = &[1,2,3,4,27,36,32,34,28,37,38,35,33,30,29,31,39,11,16,17,18,15,13,14,19,8,23,24,25,22,20,21,26,10,12,9,40,6,7,0,5];
+    pub const TYPE_ID: u64 = 0x8575_5f64_f65a_7ec0;
+  }
+}
+
+pub mod s_flow_drop_record {
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <> ::core::marker::Copy for Reader<'_,>  {}
+  impl <> ::core::clone::Clone for Reader<'_,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <> ::capnp::traits::HasTypeId for Reader<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <> ::core::fmt::Debug for Reader<'_,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_agent_addr(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_agent_addr(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_agent_port(self) -> u16 {
+      self.reader.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn get_agent_sub_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn get_datagram_sequence_number(self) -> u32 {
+      self.reader.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn get_uptime(self) -> u32 {
+      self.reader.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn get_sample_sequence_number(self) -> u32 {
+      self.reader.get_data_field::<u32>(6)
+    }
+    #[inline]
+    pub fn get_source_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(7)
+    }
+    #[inline]
+    pub fn get_drops(self) -> u32 {
+      self.reader.get_data_field::<u32>(8)
+    }
+    #[inline]
+    pub fn get_input_interface(self) -> u32 {
+      self.reader.get_data_field::<u32>(9)
+    }
+    #[inline]
+    pub fn get_output_interface(self) -> u32 {
+      self.reader.get_data_field::<u32>(10)
+    }
+    #[inline]
+    pub fn get_reason(self) -> u32 {
+      self.reader.get_data_field::<u32>(11)
+    }
+    #[inline]
+    pub fn get_length(self) -> u32 {
+      self.reader.get_data_field::<u32>(12)
+    }
+    #[inline]
+    pub fn get_protocol(self) -> u32 {
+      self.reader.get_data_field::<u32>(13)
+    }
+    #[inline]
+    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_src_ip(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_dst_ip(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_src_port(self) -> u32 {
+      self.reader.get_data_field::<u32>(14)
+    }
+    #[inline]
+    pub fn get_dst_port(self) -> u32 {
+      self.reader.get_data_field::<u32>(15)
+    }
+    #[inline]
+    pub fn get_tcp_flags(self) -> u32 {
+      self.reader.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn get_tos(self) -> u32 {
+      self.reader.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <> ::capnp::traits::HasStructSize for Builder<'_,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 9, pointers: 4 };
+  }
+  impl <> ::capnp::traits::HasTypeId for Builder<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <> ::capnp::traits::SetterInput<Owned<>> for Reader<'_,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_time_received_ns(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn get_agent_addr(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_agent_addr(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_data(value);
+    }
+    #[inline]
+    pub fn init_agent_addr(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(0).init_data(size)
+    }
+    #[inline]
+    pub fn has_agent_addr(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_agent_port(self) -> u16 {
+      self.builder.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn set_agent_port(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(4, value);
+    }
+    #[inline]
+    pub fn get_agent_sub_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn set_agent_sub_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(3, value);
+    }
+    #[inline]
+    pub fn get_datagram_sequence_number(self) -> u32 {
+      self.builder.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn set_datagram_sequence_number(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(4, value);
+    }
+    #[inline]
+    pub fn get_uptime(self) -> u32 {
+      self.builder.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn set_uptime(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(5, value);
+    }
+    #[inline]
+    pub fn get_sample_sequence_number(self) -> u32 {
+      self.builder.get_data_field::<u32>(6)
+    }
+    #[inline]
+    pub fn set_sample_sequence_number(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(6, value);
+    }
+    #[inline]
+    pub fn get_source_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(7)
+    }
+    #[inline]
+    pub fn set_source_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(7, value);
+    }
+    #[inline]
+    pub fn get_drops(self) -> u32 {
+      self.builder.get_data_field::<u32>(8)
+    }
+    #[inline]
+    pub fn set_drops(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(8, value);
+    }
+    #[inline]
+    pub fn get_input_interface(self) -> u32 {
+      self.builder.get_data_field::<u32>(9)
+    }
+    #[inline]
+    pub fn set_input_interface(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(9, value);
+    }
+    #[inline]
+    pub fn get_output_interface(self) -> u32 {
+      self.builder.get_data_field::<u32>(10)
+    }
+    #[inline]
+    pub fn set_output_interface(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(10, value);
+    }
+    #[inline]
+    pub fn get_reason(self) -> u32 {
+      self.builder.get_data_field::<u32>(11)
+    }
+    #[inline]
+    pub fn set_reason(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(11, value);
+    }
+    #[inline]
+    pub fn get_length(self) -> u32 {
+      self.builder.get_data_field::<u32>(12)
+    }
+    #[inline]
+    pub fn set_length(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(12, value);
+    }
+    #[inline]
+    pub fn get_protocol(self) -> u32 {
+      self.builder.get_data_field::<u32>(13)
+    }
+    #[inline]
+    pub fn set_protocol(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(13, value);
+    }
+    #[inline]
+    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(1).set_data(value);
+    }
+    #[inline]
+    pub fn init_src_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(1).init_data(size)
+    }
+    #[inline]
+    pub fn has_src_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(2).set_data(value);
+    }
+    #[inline]
+    pub fn init_dst_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(2).init_data(size)
+    }
+    #[inline]
+    pub fn has_dst_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_src_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(14)
+    }
+    #[inline]
+    pub fn set_src_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(14, value);
+    }
+    #[inline]
+    pub fn get_dst_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(15)
+    }
+    #[inline]
+    pub fn set_dst_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(15, value);
+    }
+    #[inline]
+    pub fn get_tcp_flags(self) -> u32 {
+      self.builder.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn set_tcp_flags(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(16, value);
+    }
+    #[inline]
+    pub fn get_tos(self) -> u32 {
+      self.builder.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn set_tos(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(17, value);
+    }
+    #[inline]
+    pub fn get_listener_tag(self) -> ::capnp::Result<::capnp::text::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_listener_tag(&mut self, value: impl ::capnp::traits::SetterInput<::capnp::text::Owned>)  {
+      ::capnp::traits::SetterInput::set_pointer_builder(self.builder.reborrow().get_pointer_field(3), value, false).unwrap()
+    }
+    #[inline]
+    pub fn init_listener_tag(self, size: u32) -> ::capnp::text::Builder<'a> {
+      self.builder.get_pointer_field(3).init_text(size)
+    }
+    #[inline]
+    pub fn has_listener_tag(&self) -> bool {
+      !self.builder.is_pointer_field_null(3)
+    }
+  }
+
+  pub struct Pipeline { _typeless: ::capnp::any_pointer::Pipeline }
+  impl ::capnp::capability::FromTypelessPipeline for Pipeline {
+    fn new(typeless: ::capnp::any_pointer::Pipeline) -> Self {
+      Self { _typeless: typeless,  }
+    }
+  }
+  impl Pipeline  {
+  }
+  mod _private {
+    pub static ENCODED_NODE: [::capnp::Word; 348] = [
+      ::capnp::word(0, 0, 0, 0, 6, 0, 6, 0),
+      ::capnp::word(107, 225, 82, 180, 163, 5, 14, 147),
+      ::capnp::word(12, 0, 0, 0, 1, 0, 9, 0),
+      ::capnp::word(201, 184, 167, 246, 229, 212, 195, 184),
+      ::capnp::word(4, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(52, 18, 0, 0, 139, 21, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 226, 0, 0, 0),
+      ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 159, 4, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 102, 108, 111, 119, 46, 99, 97),
+      ::capnp::word(112, 110, 112, 58, 83, 70, 108, 111),
+      ::capnp::word(119, 68, 114, 111, 112, 82, 101, 99),
+      ::capnp::word(111, 114, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
+      ::capnp::word(84, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(61, 2, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(60, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(72, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(69, 2, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(68, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(80, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(2, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(77, 2, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(76, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(85, 2, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(84, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(96, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(93, 2, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(96, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(108, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 2, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(112, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(109, 2, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(124, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(121, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(120, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(132, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(129, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(124, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(136, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(9, 0, 0, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(133, 2, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(132, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(144, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(10, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(141, 2, 0, 0, 130, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(140, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(11, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(149, 2, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(144, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(156, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(153, 2, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(148, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(160, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(13, 0, 0, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 13, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(157, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(156, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(168, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(14, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(165, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(160, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(172, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(15, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(169, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(164, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(176, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 14, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(173, 2, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(168, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(180, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(17, 0, 0, 0, 15, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(177, 2, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(172, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(184, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(18, 0, 0, 0, 16, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 18, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(181, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(180, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(19, 0, 0, 0, 17, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 19, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(189, 2, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(184, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(196, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 20, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(193, 2, 0, 0, 98, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(192, 2, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(204, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(116, 105, 109, 101, 82, 101, 99, 101),
+      ::capnp::word(105, 118, 101, 100, 78, 115, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 65, 100, 100),
+      ::capnp::word(114, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 80, 111, 114),
+      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(97, 103, 101, 110, 116, 83, 117, 98),
+      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 97, 116, 97, 103, 114, 97, 109),
+      ::capnp::word(83, 101, 113, 117, 101, 110, 99, 101),
+      ::capnp::word(78, 117, 109, 98, 101, 114, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(117, 112, 116, 105, 109, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 101, 83, 101),
+      ::capnp::word(113, 117, 101, 110, 99, 101, 78, 117),
+      ::capnp::word(109, 98, 101, 114, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 111, 117, 114, 99, 101, 73, 100),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 114, 111, 112, 115, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(105, 110, 112, 117, 116, 73, 110, 116),
+      ::capnp::word(101, 114, 102, 97, 99, 101, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(111, 117, 116, 112, 117, 116, 73, 110),
+      ::capnp::word(116, 101, 114, 102, 97, 99, 101, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(114, 101, 97, 115, 111, 110, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 101, 110, 103, 116, 104, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 116, 111, 99, 111, 108),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 80, 111, 114, 116, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 80, 111, 114, 116, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 99, 112, 70, 108, 97, 103, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 111, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(108, 105, 115, 116, 101, 110, 101, 114),
+      ::capnp::word(84, 97, 103, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(12, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+    ];
+    pub fn get_field_types(index: u16) -> ::capnp::introspect::Type {
+      match index {
+        0 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        1 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        4 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        5 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        6 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        7 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        8 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        9 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        10 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        11 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        12 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        13 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        14 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        15 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        16 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        17 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        18 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        19 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        20 => <::capnp::text::Owned as ::capnp::introspect::Introspect>::introspect(),
+        _ => ::capnp::introspect::panic_invalid_field_index(index),
+      }
+    }
+    pub fn get_annotation_types(child_index: Option<u16>, index: u32) -> ::capnp::introspect::Type {
+      ::capnp::introspect::panic_invalid_annotation_indices(child_index, index)
+    }
+    pub static RAW_SCHEMA: ::capnp::introspect::RawStructSchema = ::capnp::introspect::RawStructSchema {
+      encoded_node: &ENCODED_NODE,
+      nonunion_members: NONUNION_MEMBERS,
+      members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
+      members_by_name: MEMBERS_BY_NAME,
+    };
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20];
+    pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
+    pub static MEMBERS_BY_NAME : &[u16] = &[1,2,3,4,8,15,17,9,12,20,10,13,11,6,7,14,16,18,0,19,5];
+    pub const TYPE_ID: u64 = 0x930e_05a3_b452_e16b;
+  }
+}
+
+pub mod net_flow_record {
+  #[derive(Copy, Clone)]
+  pub struct Owned(());
+  impl ::capnp::introspect::Introspect for Owned { fn introspect() -> ::capnp::introspect::Type { ::capnp::introspect::TypeVariant::Struct(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types, annotation_types: _private::get_annotation_types }).into() } }
+  impl ::capnp::traits::Owned for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::OwnedStruct for Owned { type Reader<'a> = Reader<'a>; type Builder<'a> = Builder<'a>; }
+  impl ::capnp::traits::Pipelined for Owned { type Pipeline = Pipeline; }
+
+  pub struct Reader<'a> { reader: ::capnp::private::layout::StructReader<'a> }
+  impl <> ::core::marker::Copy for Reader<'_,>  {}
+  impl <> ::core::clone::Clone for Reader<'_,>  {
+    fn clone(&self) -> Self { *self }
+  }
+
+  impl <> ::capnp::traits::HasTypeId for Reader<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructReader<'a>> for Reader<'a,>  {
+    fn from(reader: ::capnp::private::layout::StructReader<'a>) -> Self {
+      Self { reader,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Reader<'a,>> for ::capnp::dynamic_value::Reader<'a>  {
+    fn from(reader: Reader<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Reader::new(reader.reader, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <> ::core::fmt::Debug for Reader<'_,>  {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::result::Result<(), ::core::fmt::Error> {
+      core::fmt::Debug::fmt(&::core::convert::Into::<::capnp::dynamic_value::Reader<'_>>::into(*self), f)
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerReader<'a> for Reader<'a,>  {
+    fn get_from_pointer(reader: &::capnp::private::layout::PointerReader<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(reader.get_struct(default)?.into())
+    }
+  }
+
+  impl <'a,> ::capnp::traits::IntoInternalStructReader<'a> for Reader<'a,>  {
+    fn into_internal_struct_reader(self) -> ::capnp::private::layout::StructReader<'a> {
+      self.reader
+    }
+  }
+
+  impl <'a,> ::capnp::traits::Imbue<'a> for Reader<'a,>  {
+    fn imbue(&mut self, cap_table: &'a ::capnp::private::layout::CapTable) {
+      self.reader.imbue(::capnp::private::layout::CapTableReader::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> Reader<'a,>  {
+    pub fn reborrow(&self) -> Reader<'_,> {
+      Self { .. *self }
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.reader.total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.reader.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn get_exporter_addr(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_exporter_addr(&self) -> bool {
+      !self.reader.get_pointer_field(0).is_null()
+    }
+    #[inline]
+    pub fn get_exporter_port(self) -> u16 {
+      self.reader.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn get_version(self) -> u16 {
+      self.reader.get_data_field::<u16>(5)
+    }
+    #[inline]
+    pub fn get_sequence_number(self) -> u32 {
+      self.reader.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn get_observation_domain_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn get_export_time(self) -> u32 {
+      self.reader.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn get_flow_start_ms(self) -> u64 {
+      self.reader.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn get_flow_end_ms(self) -> u64 {
+      self.reader.get_data_field::<u64>(4)
+    }
+    #[inline]
+    pub fn get_bytes(self) -> u64 {
+      self.reader.get_data_field::<u64>(5)
+    }
+    #[inline]
+    pub fn get_packets(self) -> u64 {
+      self.reader.get_data_field::<u64>(6)
+    }
+    #[inline]
+    pub fn get_sampling_rate(self) -> u32 {
+      self.reader.get_data_field::<u32>(14)
+    }
+    #[inline]
+    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_src_ip(&self) -> bool {
+      !self.reader.get_pointer_field(1).is_null()
+    }
+    #[inline]
+    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_dst_ip(&self) -> bool {
+      !self.reader.get_pointer_field(2).is_null()
+    }
+    #[inline]
+    pub fn get_src_port(self) -> u32 {
+      self.reader.get_data_field::<u32>(15)
+    }
+    #[inline]
+    pub fn get_dst_port(self) -> u32 {
+      self.reader.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn get_protocol(self) -> u32 {
+      self.reader.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn get_tos(self) -> u32 {
+      self.reader.get_data_field::<u32>(18)
+    }
+    #[inline]
+    pub fn get_tcp_flags(self) -> u32 {
+      self.reader.get_data_field::<u32>(19)
+    }
+    #[inline]
+    pub fn get_input_interface(self) -> u32 {
+      self.reader.get_data_field::<u32>(20)
+    }
+    #[inline]
+    pub fn get_output_interface(self) -> u32 {
+      self.reader.get_data_field::<u32>(21)
+    }
+    #[inline]
+    pub fn get_src_mac(self) -> u64 {
+      self.reader.get_data_field::<u64>(11)
+    }
+    #[inline]
+    pub fn get_dst_mac(self) -> u64 {
+      self.reader.get_data_field::<u64>(12)
+    }
+    #[inline]
+    pub fn get_etype(self) -> u32 {
+      self.reader.get_data_field::<u32>(26)
+    }
+    #[inline]
+    pub fn get_vlan_id(self) -> u32 {
+      self.reader.get_data_field::<u32>(27)
+    }
+    #[inline]
+    pub fn get_next_hop(self) -> ::capnp::Result<::capnp::data::Reader<'a>> {
+      ::capnp::traits::FromPointerReader::get_from_pointer(&self.reader.get_pointer_field(3), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn has_next_hop(&self) -> bool {
+      !self.reader.get_pointer_field(3).is_null()
+    }
+    #[inline]
+    pub fn get_src_mask_len(self) -> u32 {
+      self.reader.get_data_field::<u32>(28)
+    }
+    #[inline]
+    pub fn get_dst_mask_len(self) -> u32 {
+      self.reader.get_data_field::<u32>(29)
+    }
+    #[inline]
+    pub fn get_src_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(30)
+    }
+    #[inline]
+    pub fn get_dst_as(self) -> u32 {
+      self.reader.get_data_field::<u32>(31)
+    }
+  }
+
+  pub struct Builder<'a> { builder: ::capnp::private::layout::StructBuilder<'a> }
+  impl <> ::capnp::traits::HasStructSize for Builder<'_,>  {
+    const STRUCT_SIZE: ::capnp::private::layout::StructSize = ::capnp::private::layout::StructSize { data: 16, pointers: 4 };
+  }
+  impl <> ::capnp::traits::HasTypeId for Builder<'_,>  {
+    const TYPE_ID: u64 = _private::TYPE_ID;
+  }
+  impl <'a,> ::core::convert::From<::capnp::private::layout::StructBuilder<'a>> for Builder<'a,>  {
+    fn from(builder: ::capnp::private::layout::StructBuilder<'a>) -> Self {
+      Self { builder,  }
+    }
+  }
+
+  impl <'a,> ::core::convert::From<Builder<'a,>> for ::capnp::dynamic_value::Builder<'a>  {
+    fn from(builder: Builder<'a,>) -> Self {
+      Self::Struct(::capnp::dynamic_struct::Builder::new(builder.builder, ::capnp::schema::StructSchema::new(::capnp::introspect::RawBrandedStructSchema { generic: &_private::RAW_SCHEMA, field_types: _private::get_field_types::<>, annotation_types: _private::get_annotation_types::<>})))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::ImbueMut<'a> for Builder<'a,>  {
+    fn imbue_mut(&mut self, cap_table: &'a mut ::capnp::private::layout::CapTable) {
+      self.builder.imbue(::capnp::private::layout::CapTableBuilder::Plain(cap_table))
+    }
+  }
+
+  impl <'a,> ::capnp::traits::FromPointerBuilder<'a> for Builder<'a,>  {
+    fn init_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, _size: u32) -> Self {
+      builder.init_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE).into()
+    }
+    fn get_from_pointer(builder: ::capnp::private::layout::PointerBuilder<'a>, default: ::core::option::Option<&'a [::capnp::Word]>) -> ::capnp::Result<Self> {
+      ::core::result::Result::Ok(builder.get_struct(<Self as ::capnp::traits::HasStructSize>::STRUCT_SIZE, default)?.into())
+    }
+  }
+
+  impl <> ::capnp::traits::SetterInput<Owned<>> for Reader<'_,>  {
+    fn set_pointer_builder(mut pointer: ::capnp::private::layout::PointerBuilder<'_>, value: Self, canonicalize: bool) -> ::capnp::Result<()> { pointer.set_struct(&value.reader, canonicalize) }
+  }
+
+  impl <'a,> Builder<'a,>  {
+    pub fn into_reader(self) -> Reader<'a,> {
+      self.builder.into_reader().into()
+    }
+    pub fn reborrow(&mut self) -> Builder<'_,> {
+      Builder { builder: self.builder.reborrow() }
+    }
+    pub fn reborrow_as_reader(&self) -> Reader<'_,> {
+      self.builder.as_reader().into()
+    }
+
+    pub fn total_size(&self) -> ::capnp::Result<::capnp::MessageSize> {
+      self.builder.as_reader().total_size()
+    }
+    #[inline]
+    pub fn get_time_received_ns(self) -> u64 {
+      self.builder.get_data_field::<u64>(0)
+    }
+    #[inline]
+    pub fn set_time_received_ns(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(0, value);
+    }
+    #[inline]
+    pub fn get_exporter_addr(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(0), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_exporter_addr(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(0).set_data(value);
+    }
+    #[inline]
+    pub fn init_exporter_addr(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(0).init_data(size)
+    }
+    #[inline]
+    pub fn has_exporter_addr(&self) -> bool {
+      !self.builder.is_pointer_field_null(0)
+    }
+    #[inline]
+    pub fn get_exporter_port(self) -> u16 {
+      self.builder.get_data_field::<u16>(4)
+    }
+    #[inline]
+    pub fn set_exporter_port(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(4, value);
+    }
+    #[inline]
+    pub fn get_version(self) -> u16 {
+      self.builder.get_data_field::<u16>(5)
+    }
+    #[inline]
+    pub fn set_version(&mut self, value: u16)  {
+      self.builder.set_data_field::<u16>(5, value);
+    }
+    #[inline]
+    pub fn get_sequence_number(self) -> u32 {
+      self.builder.get_data_field::<u32>(3)
+    }
+    #[inline]
+    pub fn set_sequence_number(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(3, value);
+    }
+    #[inline]
+    pub fn get_observation_domain_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(4)
+    }
+    #[inline]
+    pub fn set_observation_domain_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(4, value);
+    }
+    #[inline]
+    pub fn get_export_time(self) -> u32 {
+      self.builder.get_data_field::<u32>(5)
+    }
+    #[inline]
+    pub fn set_export_time(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(5, value);
+    }
+    #[inline]
+    pub fn get_flow_start_ms(self) -> u64 {
+      self.builder.get_data_field::<u64>(3)
+    }
+    #[inline]
+    pub fn set_flow_start_ms(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(3, value);
+    }
+    #[inline]
+    pub fn get_flow_end_ms(self) -> u64 {
+      self.builder.get_data_field::<u64>(4)
+    }
+    #[inline]
+    pub fn set_flow_end_ms(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(4, value);
+    }
+    #[inline]
+    pub fn get_bytes(self) -> u64 {
+      self.builder.get_data_field::<u64>(5)
+    }
+    #[inline]
+    pub fn set_bytes(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(5, value);
+    }
+    #[inline]
+    pub fn get_packets(self) -> u64 {
+      self.builder.get_data_field::<u64>(6)
+    }
+    #[inline]
+    pub fn set_packets(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(6, value);
+    }
+    #[inline]
+    pub fn get_sampling_rate(self) -> u32 {
+      self.builder.get_data_field::<u32>(14)
+    }
+    #[inline]
+    pub fn set_sampling_rate(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(14, value);
+    }
+    #[inline]
+    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_src_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(1).set_data(value);
+    }
+    #[inline]
+    pub fn init_src_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(1).init_data(size)
+    }
+    #[inline]
+    pub fn has_src_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(1)
+    }
+    #[inline]
+    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    }
+    #[inline]
+    pub fn set_dst_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(2).set_data(value);
+    }
+    #[inline]
+    pub fn init_dst_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(2).init_data(size)
+    }
+    #[inline]
+    pub fn has_dst_ip(&self) -> bool {
+      !self.builder.is_pointer_field_null(2)
+    }
+    #[inline]
+    pub fn get_src_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(15)
+    }
+    #[inline]
+    pub fn set_src_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(15, value);
+    }
+    #[inline]
+    pub fn get_dst_port(self) -> u32 {
+      self.builder.get_data_field::<u32>(16)
+    }
+    #[inline]
+    pub fn set_dst_port(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(16, value);
+    }
+    #[inline]
+    pub fn get_protocol(self) -> u32 {
+      self.builder.get_data_field::<u32>(17)
+    }
+    #[inline]
+    pub fn set_protocol(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(17, value);
+    }
+    #[inline]
+    pub fn get_tos(self) -> u32 {
+      self.builder.get_data_field::<u32>(18)
+    }
+    #[inline]
+    pub fn set_tos(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(18, value);
+    }
+    #[inline]
+    pub fn get_tcp_flags(self) -> u32 {
+      self.builder.get_data_field::<u32>(19)
     }
     #[inline]
-    pub fn set_drops(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(10, value);
+    pub fn set_tcp_flags(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(19, value);
     }
     #[inline]
     pub fn get_input_interface(self) -> u32 {
-      self.builder.get_data_field::<u32>(11)
+      self.builder.get_data_field::<u32>(20)
     }
     #[inline]
     pub fn set_input_interface(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(11, value);
+      self.builder.set_data_field::<u32>(20, value);
     }
     #[inline]
     pub fn get_output_interface(self) -> u32 {
-      self.builder.get_data_field::<u32>(12)
+      self.builder.get_data_field::<u32>(21)
     }
     #[inline]
     pub fn set_output_interface(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(12, value);
+      self.builder.set_data_field::<u32>(21, value);
     }
     #[inline]
-    pub fn get_length(self) -> u32 {
-      self.builder.get_data_field::<u32>(13)
+    pub fn get_src_mac(self) -> u64 {
+      self.builder.get_data_field::<u64>(11)
     }
     #[inline]
-    pub fn set_length(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(13, value);
+    pub fn set_src_mac(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(11, value);
     }
     #[inline]
-    pub fn get_protocol(self) -> u32 {
-      self.builder.get_data_field::<u32>(14)
+    pub fn get_dst_mac(self) -> u64 {
+      self.builder.get_data_field::<u64>(12)
     }
     #[inline]
-    pub fn set_protocol(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(14, value);
+    pub fn set_dst_mac(&mut self, value: u64)  {
+      self.builder.set_data_field::<u64>(12, value);
     }
     #[inline]
-    pub fn get_src_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(1), ::core::option::Option::None)
+    pub fn get_etype(self) -> u32 {
+      self.builder.get_data_field::<u32>(26)
     }
     #[inline]
-    pub fn set_src_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
-      self.builder.reborrow().get_pointer_field(1).set_data(value);
+    pub fn set_etype(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(26, value);
     }
     #[inline]
-    pub fn init_src_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
-      self.builder.get_pointer_field(1).init_data(size)
+    pub fn get_vlan_id(self) -> u32 {
+      self.builder.get_data_field::<u32>(27)
     }
     #[inline]
-    pub fn has_src_ip(&self) -> bool {
-      !self.builder.is_pointer_field_null(1)
+    pub fn set_vlan_id(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(27, value);
     }
     #[inline]
-    pub fn get_dst_ip(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
-      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(2), ::core::option::Option::None)
+    pub fn get_next_hop(self) -> ::capnp::Result<::capnp::data::Builder<'a>> {
+      ::capnp::traits::FromPointerBuilder::get_from_pointer(self.builder.get_pointer_field(3), ::core::option::Option::None)
     }
     #[inline]
-    pub fn set_dst_ip(&mut self, value: ::capnp::data::Reader<'_>)  {
-      self.builder.reborrow().get_pointer_field(2).set_data(value);
+    pub fn set_next_hop(&mut self, value: ::capnp::data::Reader<'_>)  {
+      self.builder.reborrow().get_pointer_field(3).set_data(value);
     }
     #[inline]
-    pub fn init_dst_ip(self, size: u32) -> ::capnp::data::Builder<'a> {
-      self.builder.get_pointer_field(2).init_data(size)
+    pub fn init_next_hop(self, size: u32) -> ::capnp::data::Builder<'a> {
+      self.builder.get_pointer_field(3).init_data(size)
     }
     #[inline]
-    pub fn has_dst_ip(&self) -> bool {
-      !self.builder.is_pointer_field_null(2)
+    pub fn has_next_hop(&self) -> bool {
+      !self.builder.is_pointer_field_null(3)
     }
     #[inline]
-    pub fn get_src_port(self) -> u32 {
-      self.builder.get_data_field::<u32>(15)
+    pub fn get_src_mask_len(self) -> u32 {
+      self.builder.get_data_field::<u32>(28)
     }
     #[inline]
-    pub fn set_src_port(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(15, value);
+    pub fn set_src_mask_len(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(28, value);
     }
     #[inline]
-    pub fn get_dst_port(self) -> u32 {
-      self.builder.get_data_field::<u32>(16)
+    pub fn get_dst_mask_len(self) -> u32 {
+      self.builder.get_data_field::<u32>(29)
     }
     #[inline]
-    pub fn set_dst_port(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(16, value);
+    pub fn set_dst_mask_len(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(29, value);
     }
     #[inline]
-    pub fn get_tcp_flags(self) -> u32 {
-      self.builder.get_data_field::<u32>(17)
+    pub fn get_src_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(30)
     }
     #[inline]
-    pub fn set_tcp_flags(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(17, value);
+    pub fn set_src_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(30, value);
     }
     #[inline]
-    pub fn get_tos(self) -> u32 {
-      self.builder.get_data_field::<u32>(18)
+    pub fn get_dst_as(self) -> u32 {
+      self.builder.get_data_field::<u32>(31)
     }
     #[inline]
-    pub fn set_tos(&mut self, value: u32)  {
-      self.builder.set_data_field::<u32>(18, value);
+    pub fn set_dst_as(&mut self, value: u32)  {
+      self.builder.set_data_field::<u32>(31, value);
     }
   }
 
@@ -417,173 +4992,236 @@ pub mod s_flow_flow_record {
   impl Pipeline  {
   }
   mod _private {
-    pub static ENCODED_NODE: [::capnp::Word; 349] = [
+    pub static ENCODED_NODE: [::capnp::Word; 485] = [
       ::capnp::word(0, 0, 0, 0, 6, 0, 6, 0),
-      ::capnp::word(26, 85, 235, 151, 144, 216, 39, 207),
-      ::capnp::word(12, 0, 0, 0, 1, 0, 10, 0),
+      ::capnp::word(109, 254, 16, 202, 162, 89, 246, 243),
+      ::capnp::word(12, 0, 0, 0, 1, 0, 16, 0),
       ::capnp::word(201, 184, 167, 246, 229, 212, 195, 184),
-      ::capnp::word(3, 0, 7, 0, 0, 0, 0, 0),
+      ::capnp::word(4, 0, 7, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(153, 0, 0, 0, 141, 3, 0, 0),
-      ::capnp::word(21, 0, 0, 0, 226, 0, 0, 0),
+      ::capnp::word(214, 21, 0, 0, 87, 26, 0, 0),
+      ::capnp::word(21, 0, 0, 0, 210, 0, 0, 0),
       ::capnp::word(33, 0, 0, 0, 7, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(29, 0, 0, 0, 159, 4, 0, 0),
+      ::capnp::word(29, 0, 0, 0, 151, 6, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(115, 102, 108, 111, 119, 46, 99, 97),
-      ::capnp::word(112, 110, 112, 58, 83, 70, 108, 111),
-      ::capnp::word(119, 70, 108, 111, 119, 82, 101, 99),
-      ::capnp::word(111, 114, 100, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 110, 112, 58, 78, 101, 116, 70),
+      ::capnp::word(108, 111, 119, 82, 101, 99, 111, 114),
+      ::capnp::word(100, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 1, 0, 1, 0),
-      ::capnp::word(84, 0, 0, 0, 3, 0, 4, 0),
+      ::capnp::word(120, 0, 0, 0, 3, 0, 4, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(61, 2, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(57, 3, 0, 0, 122, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(60, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(72, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(56, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(68, 3, 0, 0, 2, 0, 1, 0),
       ::capnp::word(1, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 1, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(69, 2, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(65, 3, 0, 0, 106, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(68, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(80, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(64, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(76, 3, 0, 0, 2, 0, 1, 0),
       ::capnp::word(2, 0, 0, 0, 4, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 2, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(77, 2, 0, 0, 82, 0, 0, 0),
+      ::capnp::word(73, 3, 0, 0, 106, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(76, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(88, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(3, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(72, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(84, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(3, 0, 0, 0, 5, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 3, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(85, 2, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(81, 3, 0, 0, 66, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(84, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(96, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(4, 0, 0, 0, 4, 0, 0, 0),
+      ::capnp::word(76, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(88, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(4, 0, 0, 0, 3, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 4, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(93, 2, 0, 0, 186, 0, 0, 0),
+      ::capnp::word(85, 3, 0, 0, 122, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(96, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(108, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(5, 0, 0, 0, 5, 0, 0, 0),
+      ::capnp::word(84, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(96, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(5, 0, 0, 0, 4, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 5, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(105, 2, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(93, 3, 0, 0, 162, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(100, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(112, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(6, 0, 0, 0, 6, 0, 0, 0),
+      ::capnp::word(96, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(108, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(6, 0, 0, 0, 5, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 6, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(109, 2, 0, 0, 170, 0, 0, 0),
+      ::capnp::word(105, 3, 0, 0, 90, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(112, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(124, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(7, 0, 0, 0, 7, 0, 0, 0),
+      ::capnp::word(104, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(116, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(7, 0, 0, 0, 3, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 7, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(121, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(113, 3, 0, 0, 98, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(120, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(132, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(8, 0, 0, 0, 8, 0, 0, 0),
+      ::capnp::word(112, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(124, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(8, 0, 0, 0, 4, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 8, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(129, 2, 0, 0, 106, 0, 0, 0),
+      ::capnp::word(121, 3, 0, 0, 82, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(128, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(140, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(9, 0, 0, 0, 9, 0, 0, 0),
+      ::capnp::word(120, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(132, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(9, 0, 0, 0, 5, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 9, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(137, 2, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(129, 3, 0, 0, 50, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(136, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(148, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(10, 0, 0, 0, 10, 0, 0, 0),
+      ::capnp::word(124, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(136, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(10, 0, 0, 0, 6, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 10, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(145, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(133, 3, 0, 0, 66, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(140, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(152, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(11, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(128, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(140, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(11, 0, 0, 0, 14, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 11, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(149, 2, 0, 0, 122, 0, 0, 0),
+      ::capnp::word(137, 3, 0, 0, 106, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(148, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(160, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(12, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(136, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(148, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(12, 0, 0, 0, 1, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 12, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(157, 2, 0, 0, 130, 0, 0, 0),
+      ::capnp::word(145, 3, 0, 0, 50, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(156, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(168, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(13, 0, 0, 0, 13, 0, 0, 0),
+      ::capnp::word(140, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(152, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(13, 0, 0, 0, 2, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 13, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(165, 2, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(149, 3, 0, 0, 50, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(160, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(172, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(14, 0, 0, 0, 14, 0, 0, 0),
+      ::capnp::word(144, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(156, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(14, 0, 0, 0, 15, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 14, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(169, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(153, 3, 0, 0, 66, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(168, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(180, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(15, 0, 0, 0, 1, 0, 0, 0),
+      ::capnp::word(148, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(160, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(15, 0, 0, 0, 16, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 15, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(177, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(157, 3, 0, 0, 66, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(172, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(184, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(16, 0, 0, 0, 2, 0, 0, 0),
+      ::capnp::word(152, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(164, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(16, 0, 0, 0, 17, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 16, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(181, 2, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(161, 3, 0, 0, 74, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(176, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(188, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(17, 0, 0, 0, 15, 0, 0, 0),
+      ::capnp::word(160, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(172, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(17, 0, 0, 0, 18, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 17, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(185, 2, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(169, 3, 0, 0, 34, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(180, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(192, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(18, 0, 0, 0, 16, 0, 0, 0),
+      ::capnp::word(164, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(176, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(18, 0, 0, 0, 19, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 18, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(189, 2, 0, 0, 66, 0, 0, 0),
+      ::capnp::word(173, 3, 0, 0, 74, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(184, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(196, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(19, 0, 0, 0, 17, 0, 0, 0),
+      ::capnp::word(172, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(184, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(19, 0, 0, 0, 20, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 19, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(193, 2, 0, 0, 74, 0, 0, 0),
+      ::capnp::word(181, 3, 0, 0, 122, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(192, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(204, 2, 0, 0, 2, 0, 1, 0),
-      ::capnp::word(20, 0, 0, 0, 18, 0, 0, 0),
+      ::capnp::word(180, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(192, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(20, 0, 0, 0, 21, 0, 0, 0),
       ::capnp::word(0, 0, 1, 0, 20, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(201, 2, 0, 0, 34, 0, 0, 0),
+      ::capnp::word(189, 3, 0, 0, 130, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(188, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(200, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(21, 0, 0, 0, 11, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 21, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(197, 3, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(192, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(204, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(22, 0, 0, 0, 12, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 22, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(201, 3, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(196, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(208, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(23, 0, 0, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 23, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(205, 3, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(200, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(212, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(24, 0, 0, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 24, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(209, 3, 0, 0, 58, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(204, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(216, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(25, 0, 0, 0, 3, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 25, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(213, 3, 0, 0, 66, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(196, 2, 0, 0, 3, 0, 1, 0),
-      ::capnp::word(208, 2, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(208, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(220, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(26, 0, 0, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 26, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(217, 3, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(216, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(228, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(27, 0, 0, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 27, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(225, 3, 0, 0, 90, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(224, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(236, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(28, 0, 0, 0, 30, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 28, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(233, 3, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(228, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(240, 3, 0, 0, 2, 0, 1, 0),
+      ::capnp::word(29, 0, 0, 0, 31, 0, 0, 0),
+      ::capnp::word(0, 0, 1, 0, 29, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(237, 3, 0, 0, 50, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(232, 3, 0, 0, 3, 0, 1, 0),
+      ::capnp::word(244, 3, 0, 0, 2, 0, 1, 0),
       ::capnp::word(116, 105, 109, 101, 82, 101, 99, 101),
       ::capnp::word(105, 118, 101, 100, 78, 115, 0, 0),
       ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
@@ -593,8 +5231,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(97, 103, 101, 110, 116, 65, 100, 100),
-      ::capnp::word(114, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 120, 112, 111, 114, 116, 101, 114),
+      ::capnp::word(65, 100, 100, 114, 0, 0, 0, 0),
       ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -602,8 +5240,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(97, 103, 101, 110, 116, 80, 111, 114),
-      ::capnp::word(116, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 120, 112, 111, 114, 116, 101, 114),
+      ::capnp::word(80, 111, 114, 116, 0, 0, 0, 0),
       ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -611,8 +5249,16 @@ pub mod s_flow_flow_record {
       ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(97, 103, 101, 110, 116, 83, 117, 98),
-      ::capnp::word(73, 100, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(118, 101, 114, 115, 105, 111, 110, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(7, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 101, 113, 117, 101, 110, 99, 101),
+      ::capnp::word(78, 117, 109, 98, 101, 114, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -620,9 +5266,9 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(100, 97, 116, 97, 103, 114, 97, 109),
-      ::capnp::word(83, 101, 113, 117, 101, 110, 99, 101),
-      ::capnp::word(78, 117, 109, 98, 101, 114, 0, 0),
+      ::capnp::word(111, 98, 115, 101, 114, 118, 97, 116),
+      ::capnp::word(105, 111, 110, 68, 111, 109, 97, 105),
+      ::capnp::word(110, 73, 100, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -630,7 +5276,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(117, 112, 116, 105, 109, 101, 0, 0),
+      ::capnp::word(101, 120, 112, 111, 114, 116, 84, 105),
+      ::capnp::word(109, 101, 0, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -638,9 +5285,42 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 97, 109, 112, 108, 101, 83, 101),
-      ::capnp::word(113, 117, 101, 110, 99, 101, 78, 117),
-      ::capnp::word(109, 98, 101, 114, 0, 0, 0, 0),
+      ::capnp::word(102, 108, 111, 119, 83, 116, 97, 114),
+      ::capnp::word(116, 77, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(102, 108, 111, 119, 69, 110, 100, 77),
+      ::capnp::word(115, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(98, 121, 116, 101, 115, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 97, 99, 107, 101, 116, 115, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 97, 109, 112, 108, 105, 110, 103),
+      ::capnp::word(82, 97, 116, 101, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -648,8 +5328,23 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 111, 117, 114, 99, 101, 73, 100),
+      ::capnp::word(115, 114, 99, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 73, 112, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 80, 111, 114, 116, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -657,8 +5352,7 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 97, 109, 112, 108, 105, 110, 103),
-      ::capnp::word(82, 97, 116, 101, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 80, 111, 114, 116, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -666,8 +5360,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 97, 109, 112, 108, 101, 80, 111),
-      ::capnp::word(111, 108, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(112, 114, 111, 116, 111, 99, 111, 108),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -675,7 +5369,16 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(100, 114, 111, 112, 115, 0, 0, 0),
+      ::capnp::word(116, 111, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(116, 99, 112, 70, 108, 97, 103, 115),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -701,16 +5404,23 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(108, 101, 110, 103, 116, 104, 0, 0),
-      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 77, 97, 99, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(112, 114, 111, 116, 111, 99, 111, 108),
+      ::capnp::word(100, 115, 116, 77, 97, 99, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(9, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(101, 116, 121, 112, 101, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -718,15 +5428,15 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 114, 99, 73, 112, 0, 0, 0),
-      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(118, 108, 97, 110, 73, 100, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(100, 115, 116, 73, 112, 0, 0, 0),
+      ::capnp::word(110, 101, 120, 116, 72, 111, 112, 0),
       ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -734,7 +5444,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(13, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(115, 114, 99, 80, 111, 114, 116, 0),
+      ::capnp::word(115, 114, 99, 77, 97, 115, 107, 76),
+      ::capnp::word(101, 110, 0, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -742,7 +5453,8 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(100, 115, 116, 80, 111, 114, 116, 0),
+      ::capnp::word(100, 115, 116, 77, 97, 115, 107, 76),
+      ::capnp::word(101, 110, 0, 0, 0, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -750,8 +5462,7 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(116, 99, 112, 70, 108, 97, 103, 115),
-      ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
+      ::capnp::word(115, 114, 99, 65, 115, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -759,7 +5470,7 @@ pub mod s_flow_flow_record {
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
-      ::capnp::word(116, 111, 115, 0, 0, 0, 0, 0),
+      ::capnp::word(100, 115, 116, 65, 115, 0, 0, 0),
       ::capnp::word(8, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
       ::capnp::word(0, 0, 0, 0, 0, 0, 0, 0),
@@ -773,24 +5484,33 @@ pub mod s_flow_flow_record {
         0 => <u64 as ::capnp::introspect::Introspect>::introspect(),
         1 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
         2 => <u16 as ::capnp::introspect::Introspect>::introspect(),
-        3 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        3 => <u16 as ::capnp::introspect::Introspect>::introspect(),
         4 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         5 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         6 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        7 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        8 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        9 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        10 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        7 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        8 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        9 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        10 => <u64 as ::capnp::introspect::Introspect>::introspect(),
         11 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        12 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        13 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        12 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        13 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
         14 => <u32 as ::capnp::introspect::Introspect>::introspect(),
-        15 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
-        16 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        15 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        16 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         17 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         18 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         19 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         20 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        21 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        22 => <u64 as ::capnp::introspect::Introspect>::introspect(),
+        23 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        24 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        25 => <::capnp::data::Owned as ::capnp::introspect::Introspect>::introspect(),
+        26 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        27 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        28 => <u32 as ::capnp::introspect::Introspect>::introspect(),
+        29 => <u32 as ::capnp::introspect::Introspect>::introspect(),
         _ => ::capnp::introspect::panic_invalid_field_index(index),
       }
     }
@@ -803,9 +5523,9 @@ pub mod s_flow_flow_record {
       members_by_discriminant: MEMBERS_BY_DISCRIMINANT,
       members_by_name: MEMBERS_BY_NAME,
     };
-    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20];
+    pub static NONUNION_MEMBERS : &[u16] = &[0,1,2,3,4,5,6,7,8,9,10,11,12,13,14,15,16,17,18,19,20,21,22,23,24,25,26,27,28,29];
     pub static MEMBERS_BY_DISCRIMINANT : &[u16] = &[];
-    pub static MEMBERS_BY_NAME : &[u16] = &[1,2,3,4,10,16,18,11,13,12,14,9,6,8,7,15,17,19,0,20,5];
-    pub const TYPE_ID: u64 = 0xcf27_d890_97eb_551a;
+    pub static MEMBERS_BY_NAME : &[u16] = &[9,29,13,22,27,15,23,6,1,2,8,7,19,25,5,20,10,16,11,4,28,12,21,26,14,18,0,17,3,24];
+    pub const TYPE_ID: u64 = 0xf3f6_59a2_ca10_fe6d;
   }
 }
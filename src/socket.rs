@@ -0,0 +1,366 @@
+use anyhow::Result;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::io;
+use std::net::{Ipv4Addr, SocketAddr};
+use tokio::net::UdpSocket;
+use tracing::warn;
+
+/// Largest UDP payload
+pub const MAX_DATAGRAM_SIZE: usize = 65535;
+
+// Control message space of a datagram, enough for the socket drop counter and
+// the receive timestamp
+#[cfg(target_os = "linux")]
+const CONTROL_LEN: usize = 64;
+
+/// Options of a listener socket
+#[derive(Debug, Clone, Copy)]
+pub struct SocketOptions {
+    // SO_REUSEPORT, to share the address between workers
+    pub reuse_port: bool,
+    // SO_RCVBUF, the system default if unset
+    pub recv_buffer_bytes: Option<usize>,
+    // SO_TIMESTAMPNS, kernel receive timestamps (Linux only)
+    pub kernel_timestamps: bool,
+}
+
+/// Bind a UDP socket
+///
+/// With SO_REUSEPORT, the kernel spreads the datagrams over the sockets of a
+/// port by hash of the source and destination, so an agent always lands on the
+/// same worker. On Linux, SO_RXQ_OVFL is enabled to count the datagrams dropped
+/// by the kernel on a full receive buffer.
+pub fn bind_udp(address: SocketAddr, options: SocketOptions) -> Result<UdpSocket> {
+    let socket = Socket::new(
+        Domain::for_address(address),
+        Type::DGRAM,
+        Some(Protocol::UDP),
+    )?;
+    if options.reuse_port {
+        socket.set_reuse_port(true)?;
+    }
+    if let Some(bytes) = options.recv_buffer_bytes {
+        socket.set_recv_buffer_size(bytes)?;
+        // Linux doubles the value for its bookkeeping, after capping it at net.core.rmem_max
+        let actual = socket.recv_buffer_size()?;
+        #[cfg(target_os = "linux")]
+        let actual = actual / 2;
+        if actual < bytes {
+            warn!(
+                "receive buffer of {} capped to {} bytes on {}, raise net.core.rmem_max",
+                bytes, actual, address
+            );
+        }
+    }
+    #[cfg(target_os = "linux")]
+    {
+        enable_option(&socket, libc::SO_RXQ_OVFL)?;
+        if options.kernel_timestamps {
+            enable_option(&socket, libc::SO_TIMESTAMPNS)?;
+        }
+    }
+    #[cfg(not(target_os = "linux"))]
+    if options.kernel_timestamps {
+        warn!("kernel receive timestamps are only supported on Linux");
+    }
+    socket.set_nonblocking(true)?;
+    socket.bind(&address.into())?;
+    Ok(UdpSocket::from_std(socket.into())?)
+}
+
+#[cfg(target_os = "linux")]
+fn enable_option(socket: &Socket, option: libc::c_int) -> io::Result<()> {
+    use std::os::fd::AsRawFd;
+
+    let enable: libc::c_int = 1;
+    // SAFETY: the option value is a c_int that outlives the call
+    let ret = unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            option,
+            &enable as *const libc::c_int as *const libc::c_void,
+            std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+        )
+    };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+/// Datagram received by a `Receiver`, borrowed from its buffers
+pub struct Received<'a> {
+    pub data: &'a [u8],
+    pub peer_addr: SocketAddr,
+    // Kernel receive timestamp (ns since UNIX epoch), with SO_TIMESTAMPNS
+    pub kernel_time_ns: Option<i64>,
+}
+
+// recvmmsg headers of the slots, pointing into the heap buffers of the arena,
+// names and controls of a `Receiver`, which are never reallocated
+#[cfg(target_os = "linux")]
+struct Headers {
+    // Only read by recvmmsg, through the messages
+    #[allow(dead_code)]
+    iovecs: Box<[libc::iovec]>,
+    msgs: Vec<libc::mmsghdr>,
+}
+
+// SAFETY: the pointers only reference buffers owned by the same `Receiver`,
+// which only recvmmsg writes to, through `&mut Receiver`
+#[cfg(target_os = "linux")]
+unsafe impl Send for Headers {}
+
+#[cfg(target_os = "linux")]
+impl Headers {
+    fn new(
+        arena: &mut [u8],
+        names: &mut [libc::sockaddr_storage],
+        controls: &mut [[u64; CONTROL_LEN / 8]],
+    ) -> Self {
+        let mut iovecs: Box<[libc::iovec]> = arena
+            .chunks_mut(MAX_DATAGRAM_SIZE)
+            .map(|slot| libc::iovec {
+                iov_base: slot.as_mut_ptr() as *mut libc::c_void,
+                iov_len: slot.len(),
+            })
+            .collect();
+        let msgs = iovecs
+            .iter_mut()
+            .zip(names.iter_mut())
+            .zip(controls.iter_mut())
+            .map(|((iovec, name), control)| {
+                // SAFETY: all-zero is a valid msghdr
+                let mut hdr: libc::msghdr = unsafe { std::mem::zeroed() };
+                hdr.msg_name = name as *mut libc::sockaddr_storage as *mut libc::c_void;
+                hdr.msg_iov = iovec;
+                hdr.msg_iovlen = 1;
+                hdr.msg_control = control.as_mut_ptr() as *mut libc::c_void;
+                libc::mmsghdr {
+                    msg_hdr: hdr,
+                    msg_len: 0,
+                }
+            })
+            .collect();
+        let mut headers = Headers { iovecs, msgs };
+        headers.reset();
+        headers
+    }
+
+    // Restore the lengths and flags updated by the last recvmmsg
+    fn reset(&mut self) {
+        for msg in &mut self.msgs {
+            msg.msg_hdr.msg_namelen =
+                std::mem::size_of::<libc::sockaddr_storage>() as libc::socklen_t;
+            msg.msg_hdr.msg_controllen = CONTROL_LEN as _;
+            msg.msg_hdr.msg_flags = 0;
+            msg.msg_len = 0;
+        }
+    }
+}
+
+/// Receives the datagrams of a socket in batches
+///
+/// On Linux, a single recvmmsg call fills up to `batch_size` slots of a buffer
+/// arena allocated once, along with the headers of the call, and the kernel
+/// drop counter is read from the control messages. Elsewhere, datagrams are
+/// received one at a time.
+pub struct Receiver {
+    socket: UdpSocket,
+    arena: Vec<u8>,
+    lens: Vec<usize>,
+    addrs: Vec<SocketAddr>,
+    times: Vec<Option<i64>>,
+    #[cfg(target_os = "linux")]
+    names: Vec<libc::sockaddr_storage>,
+    // u64 elements keep the control messages aligned, only read through the
+    // headers
+    #[cfg(target_os = "linux")]
+    #[allow(dead_code)]
+    controls: Vec<[u64; CONTROL_LEN / 8]>,
+    #[cfg(target_os = "linux")]
+    headers: Headers,
+    // Cumulative drop counter of the socket, as last reported by the kernel
+    socket_drops: u32,
+    new_drops: u64,
+}
+
+impl Receiver {
+    pub fn new(socket: UdpSocket, batch_size: usize) -> Self {
+        let batch_size = if cfg!(target_os = "linux") {
+            batch_size.max(1)
+        } else {
+            1
+        };
+        let mut arena = vec![0u8; batch_size * MAX_DATAGRAM_SIZE];
+        #[cfg(target_os = "linux")]
+        // SAFETY: all-zero is a valid sockaddr_storage
+        let mut names = vec![unsafe { std::mem::zeroed() }; batch_size];
+        #[cfg(target_os = "linux")]
+        let mut controls = vec![[0; CONTROL_LEN / 8]; batch_size];
+        // Moving the vectors into the receiver keeps their heap buffers in place
+        #[cfg(target_os = "linux")]
+        let headers = Headers::new(&mut arena, &mut names, &mut controls);
+        Receiver {
+            socket,
+            arena,
+            lens: vec![0; batch_size],
+            addrs: vec![SocketAddr::from((Ipv4Addr::UNSPECIFIED, 0)); batch_size],
+            times: vec![None; batch_size],
+            #[cfg(target_os = "linux")]
+            names,
+            #[cfg(target_os = "linux")]
+            controls,
+            #[cfg(target_os = "linux")]
+            headers,
+            socket_drops: 0,
+            new_drops: 0,
+        }
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.socket.local_addr()
+    }
+
+    /// Wait for the next datagrams, returns how many were received
+    #[cfg(target_os = "linux")]
+    pub async fn recv(&mut self) -> io::Result<usize> {
+        use std::os::fd::AsRawFd;
+        use tokio::io::Interest;
+
+        let Receiver {
+            socket,
+            lens,
+            addrs,
+            times,
+            names,
+            headers,
+            ..
+        } = self;
+        let fd = socket.as_raw_fd();
+
+        let (count, socket_drops) = socket
+            .async_io(Interest::READABLE, || {
+                headers.reset();
+                let msgs = &mut headers.msgs;
+
+                // SAFETY: the messages point to buffers that outlive the call
+                let ret = unsafe {
+                    libc::recvmmsg(
+                        fd,
+                        msgs.as_mut_ptr(),
+                        msgs.len() as libc::c_uint,
+                        libc::MSG_DONTWAIT,
+                        std::ptr::null_mut(),
+                    )
+                };
+                if ret < 0 {
+                    return Err(io::Error::last_os_error());
+                }
+
+                let count = ret as usize;
+                let mut socket_drops = None;
+                for (i, msg) in msgs[..count].iter().enumerate() {
+                    lens[i] = msg.msg_len as usize;
+                    addrs[i] = to_socket_addr(&names[i]).unwrap_or(addrs[i]);
+                    // SAFETY: the header was filled by recvmmsg
+                    let control = unsafe { parse_control(&msg.msg_hdr) };
+                    times[i] = control.time_ns;
+                    // The counter wraps, the last datagram has the latest value
+                    socket_drops = control.socket_drops.or(socket_drops);
+                }
+                Ok((count, socket_drops))
+            })
+            .await?;
+
+        if let Some(drops) = socket_drops {
+            self.new_drops += drops.wrapping_sub(self.socket_drops) as u64;
+            self.socket_drops = drops;
+        }
+        Ok(count)
+    }
+
+    /// Wait for the next datagram
+    #[cfg(not(target_os = "linux"))]
+    pub async fn recv(&mut self) -> io::Result<usize> {
+        let (n_bytes, peer_addr) = self.socket.recv_from(&mut self.arena).await?;
+        self.lens[0] = n_bytes;
+        self.addrs[0] = peer_addr;
+        Ok(1)
+    }
+
+    /// Datagram `i` of the last `recv`
+    pub fn get(&self, i: usize) -> Received<'_> {
+        let start = i * MAX_DATAGRAM_SIZE;
+        Received {
+            data: &self.arena[start..start + self.lens[i]],
+            peer_addr: self.addrs[i],
+            kernel_time_ns: self.times[i],
+        }
+    }
+
+    /// Datagrams dropped by the kernel since the last call
+    pub fn take_drops(&mut self) -> u64 {
+        std::mem::take(&mut self.new_drops)
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn to_socket_addr(storage: &libc::sockaddr_storage) -> Option<SocketAddr> {
+    use std::net::{Ipv6Addr, SocketAddrV6};
+
+    match storage.ss_family as libc::c_int {
+        libc::AF_INET => {
+            // SAFETY: the family says the storage holds a sockaddr_in
+            let addr = unsafe { &*(storage as *const _ as *const libc::sockaddr_in) };
+            Some(SocketAddr::from((
+                Ipv4Addr::from(u32::from_be(addr.sin_addr.s_addr)),
+                u16::from_be(addr.sin_port),
+            )))
+        }
+        libc::AF_INET6 => {
+            // SAFETY: the family says the storage holds a sockaddr_in6
+            let addr = unsafe { &*(storage as *const _ as *const libc::sockaddr_in6) };
+            Some(SocketAddr::V6(SocketAddrV6::new(
+                Ipv6Addr::from(addr.sin6_addr.s6_addr),
+                u16::from_be(addr.sin6_port),
+                addr.sin6_flowinfo,
+                addr.sin6_scope_id,
+            )))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(target_os = "linux")]
+#[derive(Default)]
+struct Control {
+    // Cumulative drop counter of the socket (SO_RXQ_OVFL), as of the datagram
+    // enqueue, so drops are reported with the next datagram queued after them
+    socket_drops: Option<u32>,
+    // Receive timestamp (SO_TIMESTAMPNS)
+    time_ns: Option<i64>,
+}
+
+// SAFETY: the header and its control buffer must come from a successful recvmsg
+#[cfg(target_os = "linux")]
+unsafe fn parse_control(hdr: &libc::msghdr) -> Control {
+    let mut control = Control::default();
+    let mut cmsg = libc::CMSG_FIRSTHDR(hdr);
+    while !cmsg.is_null() {
+        let data = libc::CMSG_DATA(cmsg);
+        match ((*cmsg).cmsg_level, (*cmsg).cmsg_type) {
+            (libc::SOL_SOCKET, libc::SO_RXQ_OVFL) => {
+                control.socket_drops = Some(std::ptr::read_unaligned(data as *const u32));
+            }
+            (libc::SOL_SOCKET, libc::SCM_TIMESTAMPNS) => {
+                let time = std::ptr::read_unaligned(data as *const libc::timespec);
+                control.time_ns = Some(time.tv_sec * 1_000_000_000 + time.tv_nsec);
+            }
+            _ => {}
+        }
+        cmsg = libc::CMSG_NXTHDR(hdr, cmsg);
+    }
+    control
+}
@@ -0,0 +1,686 @@
+use anyhow::Result;
+use metrics::{counter, gauge};
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::time::{Duration, Instant};
+use tracing::{debug, error, info, warn};
+
+use crate::batch::{BatchMessage, RecordType};
+use crate::config::KafkaConfig;
+
+// Entry header: magic and format version, payload length (u32), record count
+// (u64), min and max receive timestamps (i64), record type code (u8), topic
+// length (u16), key length (u16) and agent address length (u8, 0, 4 or 16), all
+// little-endian
+const ENTRY_MAGIC: &[u8; 3] = b"PSP";
+const ENTRY_VERSION: u8 = 2;
+const ENTRY_HEADER_LEN: usize = 4 + 4 + 8 + 8 + 8 + 1 + 2 + 2 + 1;
+// Largest payload of an entry, librdkafka's upper bound of message.max.bytes
+const MAX_PAYLOAD_BYTES: usize = 1_000_000_000;
+const SEGMENT_EXTENSION: &str = "spool";
+// Extension of the segments set aside after a read error, for inspection
+const QUARANTINE_EXTENSION: &str = "quarantine";
+
+struct Segment {
+    id: u64,
+    size: u64,
+    // Unreadable from `size` on, moved aside rather than deleted once replayed
+    quarantined: bool,
+}
+
+// Batch handed to Kafka by the replay, waiting for its delivery report
+struct InFlight {
+    seq: u64,
+    len: u64,
+    delivered: bool,
+}
+
+/// On-disk FIFO of Kafka batches, used while Kafka is unreachable
+///
+/// Batches are appended to segment files of at most `spool_segment_bytes`,
+/// named after a monotonic id so that they are replayed in order, including
+/// after a restart. A segment is deleted once all of its batches are replayed.
+/// Replay progress within a segment is kept in memory only, so a restart may
+/// send some batches twice.
+///
+/// Batches are replayed in order, at most `spool_replay_window` at a time: a
+/// batch is removed from the spool once it and all the older ones are
+/// delivered. A failed delivery rewinds the replay to the oldest batch still
+/// in the spool, so the batches delivered after it are sent twice. Batches in
+/// flight when Kafka becomes unreachable are spooled as their delivery fails,
+/// after newer ones may have been delivered.
+///
+/// A segment with an unreadable entry is replayed up to that entry, then moved
+/// aside with the `quarantine` extension instead of being deleted.
+pub struct Spool {
+    dir: PathBuf,
+    max_bytes: u64,
+    segment_bytes: u64,
+    segments: VecDeque<Segment>,
+    writer: Option<tokio::fs::File>,
+    read_offset: u64,
+    bytes: u64,
+    replay_backoff: Duration,
+    replay_at: Instant,
+    replay_window: usize,
+    // Segment id and offset of the next batch to replay, the oldest one if unset
+    replay_position: Option<(u64, u64)>,
+    in_flight: VecDeque<InFlight>,
+    next_seq: u64,
+}
+
+impl Spool {
+    pub async fn open(dir: &Path, config: &KafkaConfig) -> Result<Self> {
+        tokio::fs::create_dir_all(dir).await?;
+
+        // Pick up the segments left over by a previous run
+        let mut segments = Vec::new();
+        let mut entries = tokio::fs::read_dir(dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let path = entry.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(SEGMENT_EXTENSION) {
+                continue;
+            }
+            let Some(id) = path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .and_then(|stem| stem.parse::<u64>().ok())
+            else {
+                continue;
+            };
+            let size = entry.metadata().await?.len();
+            if size > 0 {
+                check_segment(&path).await?;
+            }
+            segments.push(Segment {
+                id,
+                size,
+                quarantined: false,
+            });
+        }
+        segments.sort_by_key(|segment| segment.id);
+
+        let bytes = segments.iter().map(|segment| segment.size).sum();
+        if !segments.is_empty() {
+            info!(
+                "found {} spool segments ({} bytes) to replay in {}",
+                segments.len(),
+                bytes,
+                dir.display()
+            );
+        }
+
+        let spool = Spool {
+            dir: dir.to_path_buf(),
+            max_bytes: config.spool_max_bytes,
+            segment_bytes: config.spool_segment_bytes,
+            segments: segments.into(),
+            writer: None,
+            read_offset: 0,
+            bytes,
+            replay_backoff: Duration::from_millis(config.retry_backoff_max_ms),
+            replay_at: Instant::now(),
+            replay_window: config.spool_replay_window.max(1),
+            replay_position: None,
+            in_flight: VecDeque::new(),
+            next_seq: 0,
+        };
+        spool.update_gauges();
+        Ok(spool)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Append a batch at the end of the spool
+    ///
+    /// The batch is dropped if the spool is full or cannot be written.
+    pub async fn append(&mut self, batch: &BatchMessage) {
+        let metric_name = "pesto_kafka_spool_batches_total";
+        let entry = encode_entry(batch);
+        let len = entry.len() as u64;
+
+        if self.bytes + len > self.max_bytes {
+            counter!(metric_name, "status" => "dropped").increment(1);
+            error!(
+                "spool full ({} bytes), dropping {} sFlow {} records",
+                self.bytes, batch.n_records, batch.record_type
+            );
+            return;
+        }
+
+        match self.write_entry(&entry).await {
+            Ok(()) => {
+                counter!(metric_name, "status" => "spooled").increment(1);
+                debug!(
+                    "spooled {} sFlow {} records ({} bytes)",
+                    batch.n_records, batch.record_type, len
+                );
+            }
+            Err(err) => {
+                counter!(metric_name, "status" => "failure").increment(1);
+                error!(
+                    "failed to spool {} sFlow {} records: {}",
+                    batch.n_records, batch.record_type, err
+                );
+            }
+        }
+        self.update_gauges();
+    }
+
+    /// Whether a batch can be replayed: the replay window is not full, the
+    /// replay backoff has elapsed and some batches were not sent yet
+    pub fn replay_due(&self) -> bool {
+        self.in_flight.len() < self.replay_window
+            && Instant::now() >= self.replay_at
+            && self.has_unsent()
+    }
+
+    /// Delay the next replay attempt, after Kafka failed again
+    pub fn defer_replay(&mut self) {
+        self.replay_at = Instant::now() + self.replay_backoff;
+    }
+
+    /// Next batch to replay, with the sequence number of its delivery report
+    ///
+    /// The batch stays in the spool until it is replayed, along with all the
+    /// older ones, or the replay is aborted.
+    pub async fn next_replay(&mut self) -> Option<(BatchMessage, u64)> {
+        loop {
+            let (index, offset) = self.position()?;
+            let segment = &self.segments[index];
+            if offset >= segment.size {
+                // End of the segment, go on with the next one if any
+                let next = self.segments.get(index + 1)?;
+                self.replay_position = Some((next.id, 0));
+                continue;
+            }
+
+            let path = self.segment_path(segment.id);
+            match read_entry(&path, offset, segment.size - offset).await {
+                Ok((batch, len)) => {
+                    self.replay_position = Some((segment.id, offset + len));
+                    let seq = self.next_seq;
+                    self.next_seq += 1;
+                    self.in_flight.push_back(InFlight {
+                        seq,
+                        len,
+                        delivered: false,
+                    });
+                    return Some((batch, seq));
+                }
+                Err(err) => self.quarantine(index, offset, err).await,
+            }
+        }
+    }
+
+    /// Mark a replayed batch as delivered, it is removed from the spool along
+    /// with the older ones once they are all delivered
+    pub async fn replayed(&mut self, seq: u64) {
+        let Some(entry) = self.in_flight.iter_mut().find(|entry| entry.seq == seq) else {
+            // Sent before the replay was aborted, it is replayed again
+            return;
+        };
+        entry.delivered = true;
+
+        while self.in_flight.front().is_some_and(|entry| entry.delivered) {
+            if let Some(entry) = self.in_flight.pop_front() {
+                self.commit(entry.len).await;
+            }
+        }
+    }
+
+    /// Give up on the batches being replayed after the delivery of one of them
+    /// failed, the replay starts again from the oldest batch after the backoff
+    pub fn abort_replay(&mut self, seq: u64) {
+        if !self.in_flight.iter().any(|entry| entry.seq == seq) {
+            // Already aborted
+            return;
+        }
+        self.in_flight.clear();
+        self.replay_position = None;
+        self.defer_replay();
+    }
+
+    // Remove the oldest batch from the spool, after it was sent to Kafka
+    async fn commit(&mut self, len: u64) {
+        counter!("pesto_kafka_spool_batches_total", "status" => "replayed").increment(1);
+        counter!("pesto_kafka_spool_replayed_bytes_total").increment(len);
+        self.read_offset += len;
+        self.bytes -= len;
+        self.release_consumed().await;
+        self.update_gauges();
+    }
+
+    // Index in `segments` and offset of the next batch to replay
+    fn position(&self) -> Option<(usize, u64)> {
+        let front = self.segments.front()?;
+        let position = self.replay_position.and_then(|(id, offset)| {
+            let index = self.segments.iter().position(|segment| segment.id == id)?;
+            Some((index, offset))
+        });
+        Some(position.unwrap_or((0, self.read_offset.min(front.size))))
+    }
+
+    fn has_unsent(&self) -> bool {
+        self.position().is_some_and(|(index, offset)| {
+            offset < self.segments[index].size
+                || self
+                    .segments
+                    .iter()
+                    .skip(index + 1)
+                    .any(|segment| segment.size > 0)
+        })
+    }
+
+    // Stop reading a segment at an entry that cannot be read, the next ones
+    // cannot be located: the segment ends there and is moved aside once the
+    // entries before are replayed
+    async fn quarantine(&mut self, index: usize, offset: u64, err: std::io::Error) {
+        let is_last = index + 1 == self.segments.len();
+        let segment = &mut self.segments[index];
+        error!(
+            "quarantining spool segment {} from offset {}: {}",
+            segment.id, offset, err
+        );
+        counter!("pesto_kafka_spool_quarantined_segments_total").increment(1);
+        self.bytes -= segment.size - offset;
+        segment.size = offset;
+        segment.quarantined = true;
+        if is_last {
+            // Append the next batches to a new segment
+            self.writer = None;
+        }
+        self.release_consumed().await;
+        self.update_gauges();
+    }
+
+    async fn write_entry(&mut self, entry: &[u8]) -> std::io::Result<()> {
+        let len = entry.len() as u64;
+
+        // Rotate to a new segment if there is none open or the current one is full
+        let rotate = match (&self.writer, self.segments.back()) {
+            (Some(_), Some(segment)) => segment.size > 0 && segment.size + len > self.segment_bytes,
+            _ => true,
+        };
+        if rotate {
+            let id = self.segments.back().map_or(0, |segment| segment.id + 1);
+            let file = tokio::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(self.segment_path(id))
+                .await?;
+            self.writer = Some(file);
+            self.segments.push_back(Segment {
+                id,
+                size: 0,
+                quarantined: false,
+            });
+            debug!("opened spool segment {}", id);
+        }
+
+        let size = self.segments.back().map_or(0, |segment| segment.size);
+        let writer = self.writer.as_mut().expect("spool segment is open");
+        if let Err(err) = write_all(writer, entry).await {
+            // Drop the partial entry, or seal the segment if that fails too so
+            // that its garbage tail is never written after nor read
+            if let Err(truncate_err) = writer.set_len(size).await {
+                warn!("failed to truncate spool segment: {}", truncate_err);
+                self.writer = None;
+            }
+            return Err(err);
+        }
+
+        if let Some(segment) = self.segments.back_mut() {
+            segment.size += len;
+        }
+        self.bytes += len;
+        Ok(())
+    }
+
+    // Delete the fully replayed segments
+    async fn release_consumed(&mut self) {
+        while let Some(segment) = self.segments.front() {
+            if self.read_offset < segment.size {
+                break;
+            }
+
+            let path = self.segment_path(segment.id);
+            let segment_id = segment.id;
+            let quarantined = segment.quarantined;
+            if self.segments.len() == 1 {
+                // Last segment, a new one is opened on the next append
+                self.writer = None;
+            }
+            self.segments.pop_front();
+            self.read_offset = 0;
+            if self.replay_position.is_some_and(|(id, _)| id == segment_id) {
+                self.replay_position = None;
+            }
+
+            if quarantined {
+                let quarantine_path = path.with_extension(QUARANTINE_EXTENSION);
+                match tokio::fs::rename(&path, &quarantine_path).await {
+                    Ok(()) => warn!(
+                        "moved quarantined spool segment to {}",
+                        quarantine_path.display()
+                    ),
+                    Err(err) => error!(
+                        "failed to move quarantined spool segment {}: {}",
+                        path.display(),
+                        err
+                    ),
+                }
+            } else if let Err(err) = tokio::fs::remove_file(&path).await {
+                error!("failed to remove spool segment {}: {}", path.display(), err);
+            }
+        }
+    }
+
+    fn segment_path(&self, id: u64) -> PathBuf {
+        self.dir.join(format!("{:020}.{}", id, SEGMENT_EXTENSION))
+    }
+
+    fn update_gauges(&self) {
+        gauge!("pesto_kafka_spool_bytes").set(self.bytes as f64);
+        gauge!("pesto_kafka_spool_segments").set(self.segments.len() as f64);
+    }
+}
+
+async fn write_all(writer: &mut tokio::fs::File, entry: &[u8]) -> std::io::Result<()> {
+    writer.write_all(entry).await?;
+    writer.flush().await
+}
+
+// Reject the segments written by another version of pesto, rather than
+// misreading them
+async fn check_segment(path: &Path) -> Result<()> {
+    let mut file = tokio::fs::File::open(path).await?;
+    let mut magic = [0u8; 4];
+    file.read_exact(&mut magic).await?;
+    if let Err(err) = check_magic(&magic) {
+        anyhow::bail!(
+            "unsupported spool segment {}: {}, move it out of the spool directory",
+            path.display(),
+            err
+        );
+    }
+    Ok(())
+}
+
+fn check_magic(magic: &[u8; 4]) -> std::io::Result<()> {
+    if &magic[..3] != ENTRY_MAGIC {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            "not a spool entry",
+        ));
+    }
+    if magic[3] != ENTRY_VERSION {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("unsupported spool entry version {}", magic[3]),
+        ));
+    }
+    Ok(())
+}
+
+fn encode_entry(batch: &BatchMessage) -> Vec<u8> {
+    let topic = batch.topic.as_bytes();
+    let agent_addr = match batch.agent_addr {
+        Some(IpAddr::V4(ipv4)) => ipv4.octets().to_vec(),
+        Some(IpAddr::V6(ipv6)) => ipv6.octets().to_vec(),
+        None => Vec::new(),
+    };
+
+    let mut entry = Vec::with_capacity(
+        ENTRY_HEADER_LEN + topic.len() + batch.key.len() + agent_addr.len() + batch.payload.len(),
+    );
+    entry.extend_from_slice(ENTRY_MAGIC);
+    entry.push(ENTRY_VERSION);
+    entry.extend_from_slice(&(batch.payload.len() as u32).to_le_bytes());
+    entry.extend_from_slice(&batch.n_records.to_le_bytes());
+    entry.extend_from_slice(&batch.min_time_received_ns.to_le_bytes());
+    entry.extend_from_slice(&batch.max_time_received_ns.to_le_bytes());
+    entry.push(batch.record_type.code());
+    entry.extend_from_slice(&(topic.len() as u16).to_le_bytes());
+    entry.extend_from_slice(&(batch.key.len() as u16).to_le_bytes());
+    entry.push(agent_addr.len() as u8);
+    entry.extend_from_slice(topic);
+    entry.extend_from_slice(&batch.key);
+    entry.extend_from_slice(&agent_addr);
+    entry.extend_from_slice(&batch.payload);
+    entry
+}
+
+// Read the entry at `offset`, `remaining` bytes before the end of the segment
+async fn read_entry(
+    path: &Path,
+    offset: u64,
+    remaining: u64,
+) -> std::io::Result<(BatchMessage, u64)> {
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(std::io::SeekFrom::Start(offset)).await?;
+
+    let mut header = [0u8; ENTRY_HEADER_LEN];
+    file.read_exact(&mut header).await?;
+    check_magic(header[0..4].try_into().unwrap())?;
+    let payload_len = u32::from_le_bytes(header[4..8].try_into().unwrap()) as usize;
+    let n_records = u64::from_le_bytes(header[8..16].try_into().unwrap());
+    let min_time_received_ns = i64::from_le_bytes(header[16..24].try_into().unwrap());
+    let max_time_received_ns = i64::from_le_bytes(header[24..32].try_into().unwrap());
+    let record_type = RecordType::from_code(header[32]).ok_or_else(|| {
+        std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!("unknown record type {}", header[32]),
+        )
+    })?;
+    let topic_len = u16::from_le_bytes(header[33..35].try_into().unwrap()) as usize;
+    let key_len = u16::from_le_bytes(header[35..37].try_into().unwrap()) as usize;
+    let agent_addr_len = header[37] as usize;
+
+    // Check the lengths before allocating, a corrupted header may claim gigabytes
+    let body_len = topic_len + key_len + agent_addr_len + payload_len;
+    if payload_len > MAX_PAYLOAD_BYTES || (ENTRY_HEADER_LEN + body_len) as u64 > remaining {
+        return Err(std::io::Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "entry of {} bytes overruns the segment ({} bytes left)",
+                ENTRY_HEADER_LEN + body_len,
+                remaining
+            ),
+        ));
+    }
+
+    let mut body = vec![0u8; body_len];
+    file.read_exact(&mut body).await?;
+    let len = (ENTRY_HEADER_LEN + body.len()) as u64;
+
+    let payload = body.split_off(topic_len + key_len + agent_addr_len);
+    let agent_addr = body.split_off(topic_len + key_len);
+    let agent_addr = match agent_addr.len() {
+        4 => <[u8; 4]>::try_from(agent_addr).ok().map(IpAddr::from),
+        16 => <[u8; 16]>::try_from(agent_addr).ok().map(IpAddr::from),
+        _ => None,
+    };
+    let key = body.split_off(topic_len);
+    let topic = String::from_utf8_lossy(&body).into_owned();
+
+    Ok((
+        BatchMessage {
+            record_type,
+            topic,
+            key,
+            payload,
+            n_records,
+            min_time_received_ns,
+            max_time_received_ns,
+            agent_addr,
+        },
+        len,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config;
+
+    async fn open(name: &str, args: &[&str]) -> (Spool, PathBuf) {
+        let dir =
+            std::env::temp_dir().join(format!("pesto-spool-test-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let config = config::from_args(args).await.unwrap();
+        (Spool::open(&dir, &config.kafka).await.unwrap(), dir)
+    }
+
+    fn batch(payload: &[u8]) -> BatchMessage {
+        BatchMessage {
+            record_type: RecordType::Flow,
+            topic: "pesto-sflow".to_string(),
+            key: b"key".to_vec(),
+            payload: payload.to_vec(),
+            n_records: 2,
+            min_time_received_ns: 1,
+            max_time_received_ns: 2,
+            agent_addr: Some("192.0.2.1".parse().unwrap()),
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_in_order() {
+        let (mut spool, dir) = open("order", &["--kafka-spool-replay-window", "2"]).await;
+        spool.append(&batch(b"first")).await;
+        spool.append(&batch(b"second")).await;
+        spool.append(&batch(b"third")).await;
+
+        let (first, first_seq) = spool.next_replay().await.unwrap();
+        assert_eq!(first.payload, b"first");
+        assert_eq!(first.record_type, RecordType::Flow);
+        assert_eq!(first.agent_addr, Some("192.0.2.1".parse().unwrap()));
+        let (second, second_seq) = spool.next_replay().await.unwrap();
+        assert_eq!(second.payload, b"second");
+        assert!(!spool.replay_due());
+
+        // Delivered out of order, removed from the spool in order
+        spool.replayed(second_seq).await;
+        assert!(!spool.replay_due());
+        spool.replayed(first_seq).await;
+        assert!(spool.replay_due());
+
+        let (third, third_seq) = spool.next_replay().await.unwrap();
+        assert_eq!(third.payload, b"third");
+        spool.replayed(third_seq).await;
+        assert!(spool.is_empty());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn rewinds_aborted_replays() {
+        let (mut spool, dir) = open("abort", &["--kafka-retry-backoff-max-ms", "0"]).await;
+        spool.append(&batch(b"first")).await;
+        spool.append(&batch(b"second")).await;
+
+        let (_, first_seq) = spool.next_replay().await.unwrap();
+        let (_, second_seq) = spool.next_replay().await.unwrap();
+        spool.abort_replay(second_seq);
+        // Reported after the abort, replayed again
+        spool.replayed(first_seq).await;
+
+        let (first, _) = spool.next_replay().await.unwrap();
+        assert_eq!(first.payload, b"first");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn drains_while_appending() {
+        let args = [
+            "--kafka-spool-replay-window",
+            "4",
+            "--kafka-spool-segment-bytes",
+            "500",
+        ];
+        let (mut spool, dir) = open("drain", &args).await;
+        let mut appended = 0;
+        for _ in 0..20 {
+            spool
+                .append(&batch(format!("{}", appended).as_bytes()))
+                .await;
+            appended += 1;
+        }
+
+        // A batch keeps being spooled while Kafka is caught up, the replay
+        // window delivers more than that each round
+        let mut replayed = Vec::new();
+        for _ in 0..20 {
+            spool
+                .append(&batch(format!("{}", appended).as_bytes()))
+                .await;
+            appended += 1;
+
+            let mut seqs = Vec::new();
+            while spool.replay_due() {
+                let (batch, seq) = spool.next_replay().await.unwrap();
+                replayed.push(String::from_utf8(batch.payload).unwrap());
+                seqs.push(seq);
+            }
+            for seq in seqs.into_iter().rev() {
+                spool.replayed(seq).await;
+            }
+            if spool.is_empty() {
+                break;
+            }
+        }
+
+        assert!(spool.is_empty());
+        let expected: Vec<String> = (0..appended).map(|i| i.to_string()).collect();
+        assert_eq!(replayed, expected);
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn quarantines_unreadable_entries() {
+        let (mut spool, dir) = open("quarantine", &[]).await;
+        spool.append(&batch(b"first")).await;
+        drop(spool);
+
+        // Entry header claiming a 4 GiB payload
+        let path = dir.join(format!("{:020}.{}", 0, SEGMENT_EXTENSION));
+        let mut header = vec![0u8; ENTRY_HEADER_LEN];
+        header[..3].copy_from_slice(ENTRY_MAGIC);
+        header[3] = ENTRY_VERSION;
+        header[4..8].copy_from_slice(&u32::MAX.to_le_bytes());
+        let mut segment = std::fs::read(&path).unwrap();
+        segment.extend_from_slice(&header);
+        std::fs::write(&path, segment).unwrap();
+
+        let config = config::from_args(&[]).await.unwrap();
+        let mut spool = Spool::open(&dir, &config.kafka).await.unwrap();
+        let (first, seq) = spool.next_replay().await.unwrap();
+        assert_eq!(first.payload, b"first");
+        assert!(spool.next_replay().await.is_none());
+        assert!(!spool.replay_due());
+
+        spool.replayed(seq).await;
+        assert!(spool.is_empty());
+        assert!(path.with_extension(QUARANTINE_EXTENSION).exists());
+
+        // The next batches go to a new segment
+        spool.append(&batch(b"second")).await;
+        let (second, _) = spool.next_replay().await.unwrap();
+        assert_eq!(second.payload, b"second");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[tokio::test]
+    async fn rejects_unknown_segments() {
+        let (spool, dir) = open("format", &[]).await;
+        std::fs::write(spool.segment_path(0), b"not a spool").unwrap();
+        let config = config::from_args(&[]).await.unwrap();
+        assert!(Spool::open(&dir, &config.kafka).await.is_err());
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}